@@ -0,0 +1,113 @@
+use anyhow::{bail, Context, Result};
+use omg_core::data::map::attribute::Attribute;
+use omg_core::data::map::statistics::Histogram;
+use omg_core::data::map::Map2d;
+use omg_core::data::math::predicate::Predicate;
+use omg_core::data::math::size2d::Size2d;
+use omg_core::generation::MapGenerator;
+use omg_core::interface::map::MapStorage;
+use omg_core::logging::init_logging;
+use omg_serde::data::statistics::AttributeStatisticsSerde;
+use omg_serde::interface::map::MapStorageWithSerde;
+use std::env;
+
+const USAGE: &str = "Usage:
+  omg_cli export <recipe> <directory> [<width> <height>]
+  omg_cli statistics <recipe> <attribute> [<mask> <min> <max>]
+  omg_cli tiles <recipe> <directory> <tile width> <tile height> [<width> <height>]";
+
+fn main() -> Result<()> {
+    init_logging();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|command| command.as_str()) {
+        Some("export") => export(&args[1..]),
+        Some("statistics") => print_statistics(&args[1..]),
+        Some("tiles") => export_tiles(&args[1..]),
+        _ => bail!(USAGE),
+    }
+}
+
+/// Generates the map & exports it with the presets of the recipe.
+/// An optional size renders the same map with another resolution.
+fn export(args: &[String]) -> Result<()> {
+    if args.len() != 2 && args.len() != 4 {
+        bail!(USAGE);
+    }
+
+    let map_generation = read_recipe_with_size(&args[0], &args[2..])?;
+    let map = map_generation.generate();
+
+    map_generation.export(&map, &args[1])
+}
+
+/// Generates the map tile by tile & exports each tile with the presets of the recipe.
+fn export_tiles(args: &[String]) -> Result<()> {
+    if args.len() != 4 && args.len() != 6 {
+        bail!(USAGE);
+    }
+
+    let map_generation = read_recipe_with_size(&args[0], &args[4..])?;
+    let tile_size = parse_size(&args[2], &args[3])?;
+
+    map_generation.generate_tiles(tile_size, &args[1])
+}
+
+/// Prints the statistics of an attribute as JSON.
+/// Only cells, where the mask is inside the range [min, max], are counted.
+fn print_statistics(args: &[String]) -> Result<()> {
+    if args.len() != 2 && args.len() != 5 {
+        bail!(USAGE);
+    }
+
+    let map = generate_map(&args[0])?;
+    let attribute = get_attribute_by_name(&map, &args[1])?;
+
+    let histogram = if args.len() == 5 {
+        let mask = get_attribute_by_name(&map, &args[2])?;
+        let min = args[3].parse().context("Invalid minimum of the mask!")?;
+        let max = args[4].parse().context("Invalid maximum of the mask!")?;
+        let predicate = Predicate::new_in_range(min, max)?;
+        Histogram::with_mask(attribute, mask, &predicate)?
+    } else {
+        Histogram::new(attribute)
+    };
+
+    let serde = AttributeStatisticsSerde::new(attribute.name(), &histogram);
+    println!("{}", serde_json::to_string_pretty(&serde)?);
+
+    Ok(())
+}
+
+fn read_recipe(path: &str) -> Result<MapGenerator> {
+    let map_storage = MapStorageWithSerde {};
+    map_storage.read(path)
+}
+
+/// Reads a recipe & optionally changes the size of the map.
+fn read_recipe_with_size(path: &str, size: &[String]) -> Result<MapGenerator> {
+    let map_generation = read_recipe(path)?;
+
+    if let [width, height] = size {
+        return Ok(map_generation.with_size(parse_size(width, height)?));
+    }
+
+    Ok(map_generation)
+}
+
+fn parse_size(width: &str, height: &str) -> Result<Size2d> {
+    let width = width.parse().context("Invalid width!")?;
+    let height = height.parse().context("Invalid height!")?;
+    Size2d::new(width, height)
+}
+
+fn generate_map(path: &str) -> Result<Map2d> {
+    Ok(read_recipe(path)?.generate())
+}
+
+fn get_attribute_by_name<'a>(map: &'a Map2d, name: &str) -> Result<&'a Attribute> {
+    map.get_attribute_id(name)
+        .and_then(|id| map.get_attribute(id))
+        .with_context(|| format!("Unknown attribute '{}'!", name))
+}
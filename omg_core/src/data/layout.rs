@@ -0,0 +1,109 @@
+use crate::data::name::validate_name;
+use anyhow::{bail, Result};
+
+/// Defines how the panels of a [`ViewLayout`] are arranged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutType {
+    /// Arranges the attributes in a grid with a fixed number of columns.
+    Grid { columns: u32 },
+    /// Arranges all attributes in a single row.
+    SideBySide,
+    /// Draws the second attribute on top of the first one.
+    /// The opacity of the second attribute is a percentage.
+    Overlay { opacity: u8 },
+}
+
+/// Defines which attributes are visualized together & how.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViewLayout {
+    name: String,
+    layout_type: LayoutType,
+    attributes: Vec<String>,
+}
+
+impl ViewLayout {
+    /// Creates a layout, if valid:
+    ///
+    /// ```
+    ///# use omg_core::data::layout::{LayoutType, ViewLayout};
+    /// let attributes = vec!["elevation".to_string(), "rainfall".to_string()];
+    ///
+    /// assert!(ViewLayout::new("", LayoutType::SideBySide, attributes.clone()).is_err());
+    /// assert!(ViewLayout::new("view", LayoutType::SideBySide, Vec::new()).is_err());
+    /// assert!(ViewLayout::new("view", LayoutType::Grid { columns: 0 }, attributes.clone()).is_err());
+    /// assert!(ViewLayout::new("view", LayoutType::Overlay { opacity: 101 }, attributes).is_err());
+    /// ```
+    ///
+    /// An overlay needs exactly 2 attributes:
+    ///
+    /// ```
+    ///# use omg_core::data::layout::{LayoutType, ViewLayout};
+    /// let attributes = vec!["elevation".to_string()];
+    ///
+    /// assert!(ViewLayout::new("view", LayoutType::Overlay { opacity: 50 }, attributes).is_err());
+    /// ```
+    pub fn new<S: Into<String>>(
+        name: S,
+        layout_type: LayoutType,
+        attributes: Vec<String>,
+    ) -> Result<ViewLayout> {
+        let name = validate_name(name)?;
+
+        if attributes.is_empty() {
+            bail!("Layout '{}' has no attributes!", name);
+        }
+
+        match layout_type {
+            LayoutType::Grid { columns } => {
+                if columns == 0 {
+                    bail!("Layout '{}' has 0 columns!", name);
+                }
+            }
+            LayoutType::SideBySide => {}
+            LayoutType::Overlay { opacity } => {
+                if attributes.len() != 2 {
+                    bail!("Overlay '{}' needs exactly 2 attributes!", name);
+                } else if opacity > 100 {
+                    bail!("Overlay '{}' has an opacity above 100%!", name);
+                }
+            }
+        }
+
+        Ok(ViewLayout {
+            name,
+            layout_type,
+            attributes,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn layout_type(&self) -> LayoutType {
+        self.layout_type
+    }
+
+    pub fn attributes(&self) -> &[String] {
+        &self.attributes
+    }
+
+    /// Returns the number of columns used to display the attributes.
+    ///
+    /// ```
+    ///# use omg_core::data::layout::{LayoutType, ViewLayout};
+    /// let attributes: Vec<String> = vec!["a", "b", "c"].into_iter().map(|s| s.to_string()).collect();
+    /// let grid = ViewLayout::new("grid", LayoutType::Grid { columns: 2 }, attributes.clone()).unwrap();
+    /// let row = ViewLayout::new("row", LayoutType::SideBySide, attributes).unwrap();
+    ///
+    /// assert_eq!(grid.columns(), 2);
+    /// assert_eq!(row.columns(), 3);
+    /// ```
+    pub fn columns(&self) -> u32 {
+        match self.layout_type {
+            LayoutType::Grid { columns } => columns,
+            LayoutType::SideBySide => self.attributes.len() as u32,
+            LayoutType::Overlay { .. } => 1,
+        }
+    }
+}
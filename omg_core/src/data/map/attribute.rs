@@ -1,3 +1,4 @@
+use crate::data::math::resampling::{resample, ResamplingFilter};
 use crate::data::math::size2d::Size2d;
 use crate::data::name::validate_name;
 use anyhow::{bail, Result};
@@ -155,6 +156,68 @@ impl Attribute {
             self.values[*index] = value;
         }
     }
+
+    /// Returns a copy of the attribute resized to another map size.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::math::resampling::ResamplingFilter::Bilinear;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("elevation", Size2d::unchecked(2, 1), vec![0, 100]).unwrap();
+    /// let size = Size2d::unchecked(4, 1);
+    ///
+    /// let resized = attribute.resample(size, Bilinear);
+    ///
+    /// assert_eq!(resized.name(), "elevation");
+    /// assert_eq!(resized.size(), &size);
+    /// assert_eq!(resized.get_all(), &vec![0, 25, 75, 100]);
+    /// ```
+    pub fn resample(&self, size: Size2d, filter: ResamplingFilter) -> Attribute {
+        Attribute {
+            name: self.name.clone(),
+            size,
+            values: resample(&self.values, self.size, size, filter),
+        }
+    }
+
+    /// Returns a copy of a rectangular part of the attribute, which starts at (x,y).
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let values = vec![0, 1, 2, 3, 4, 5];
+    /// let attribute = Attribute::new("elevation", Size2d::unchecked(3, 2), values).unwrap();
+    /// let size = Size2d::unchecked(2, 1);
+    ///
+    /// let part = attribute.crop(1, 1, size);
+    ///
+    /// assert_eq!(part.name(), "elevation");
+    /// assert_eq!(part.size(), &size);
+    /// assert_eq!(part.get_all(), &vec![4, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the part is outside the attribute.
+    pub fn crop(&self, x: u32, y: u32, size: Size2d) -> Attribute {
+        assert!(
+            x + size.width() <= self.size.width() && y + size.height() <= self.size.height(),
+            "The part is outside the attribute!"
+        );
+
+        let mut values = Vec::with_capacity(size.get_area());
+
+        for row in y..(y + size.height()) {
+            let start = self.size.to_index_risky(x, row);
+            values.extend_from_slice(&self.values[start..(start + size.width() as usize)]);
+        }
+
+        Attribute {
+            name: self.name.clone(),
+            size,
+            values,
+        }
+    }
 }
 
 /// Returns the value at the index.
@@ -0,0 +1,297 @@
+use crate::data::map::attribute::Attribute;
+use crate::data::math::predicate::Predicate;
+use crate::data::math::region::Region;
+use crate::data::math::size2d::Size2d;
+use Connectivity::*;
+
+/// The highest id of a component, because ids are stored in an [`Attribute`].
+pub const MAX_COMPONENTS: usize = u8::MAX as usize;
+
+/// Which neighbours of a cell are connected to it?
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Connectivity {
+    /// Only the 4 orthogonal neighbours.
+    Four,
+    /// The 4 orthogonal & the 4 diagonal neighbours.
+    Eight,
+}
+
+impl Connectivity {
+    fn get_neighbours(&self) -> &'static [(i64, i64)] {
+        match self {
+            Four => &[(0, -1), (-1, 0), (1, 0), (0, 1)],
+            Eight => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// A connected group of cells, e.g. a continent, an island or a lake.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Component {
+    id: u8,
+    area: usize,
+    bounding_box: Region,
+    centroid: [f32; 2],
+}
+
+impl Component {
+    /// Returns the id, which is written into the labels. Starts at 1.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns the number of cells.
+    pub fn area(&self) -> usize {
+        self.area
+    }
+
+    /// Returns the smallest region containing all cells.
+    pub fn bounding_box(&self) -> Region {
+        self.bounding_box
+    }
+
+    /// Returns the average position of all cells.
+    pub fn centroid(&self) -> [f32; 2] {
+        self.centroid
+    }
+}
+
+/// Labels the connected groups of cells of an [`Attribute`], whose values fulfill a [`Predicate`].
+///
+/// Components are sorted by area, so the largest component has the id 1.
+/// Cells without a component, e.g. because they are too small, get the label 0.
+/// Only the largest 255 components get an id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Components {
+    labels: Vec<u8>,
+    components: Vec<Component>,
+}
+
+impl Components {
+    /// Labels the components, but ignores all with an area below a minimum.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::components::{Components, Connectivity};
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(5, 3);
+    /// let values = vec![
+    ///     9, 0, 0, 9, 9,
+    ///     0, 9, 0, 9, 9,
+    ///     0, 0, 0, 0, 9,
+    /// ];
+    /// let attribute = Attribute::new("elevation", size, values).unwrap();
+    /// let land = Predicate::Above(5);
+    ///
+    /// let components = Components::new(&attribute, &land, Connectivity::Four, 0);
+    ///
+    /// assert_eq!(components.labels(), &vec![
+    ///     2, 0, 0, 1, 1,
+    ///     0, 3, 0, 1, 1,
+    ///     0, 0, 0, 0, 1,
+    /// ]);
+    /// assert_eq!(components.len(), 3);
+    /// let island = components.get(1).unwrap();
+    /// assert_eq!(island.area(), 5);
+    /// assert_eq!(island.bounding_box(), Region::new(3, 0, Size2d::unchecked(2, 3)));
+    /// assert_eq!(island.centroid(), [3.6, 0.8]);
+    ///
+    /// let components = Components::new(&attribute, &land, Connectivity::Eight, 0);
+    ///
+    /// assert_eq!(components.len(), 2);
+    /// assert_eq!(components.get(2).unwrap().area(), 2);
+    ///
+    /// let components = Components::new(&attribute, &land, Connectivity::Four, 2);
+    ///
+    /// assert_eq!(components.labels(), &vec![
+    ///     0, 0, 0, 1, 1,
+    ///     0, 0, 0, 1, 1,
+    ///     0, 0, 0, 0, 1,
+    /// ]);
+    /// assert_eq!(components.len(), 1);
+    /// ```
+    pub fn new(
+        attribute: &Attribute,
+        predicate: &Predicate,
+        connectivity: Connectivity,
+        min_area: usize,
+    ) -> Components {
+        let size = *attribute.size();
+        let values = attribute.get_all();
+        let mut visited = vec![false; values.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+
+        for start in 0..values.len() {
+            if visited[start] || !predicate.check(values[start]) {
+                continue;
+            }
+
+            let group = fill(values, size, start, predicate, connectivity, &mut visited);
+
+            if group.len() >= min_area {
+                groups.push(group);
+            }
+        }
+
+        // The sort is stable, so components with the same area keep their order.
+        groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
+
+        if groups.len() > MAX_COMPONENTS {
+            warn!(
+                "Found {} components of '{}', but only the largest {} get an id!",
+                groups.len(),
+                attribute.name(),
+                MAX_COMPONENTS
+            );
+            groups.truncate(MAX_COMPONENTS);
+        }
+
+        let mut labels = vec![0; values.len()];
+        let components = groups
+            .iter()
+            .enumerate()
+            .map(|(index, group)| {
+                let id = (index + 1) as u8;
+
+                for cell in group {
+                    labels[*cell] = id;
+                }
+
+                create_component(id, group, size)
+            })
+            .collect();
+
+        Components { labels, components }
+    }
+
+    /// Returns the id of the component of each cell or 0.
+    pub fn labels(&self) -> &Vec<u8> {
+        &self.labels
+    }
+
+    /// Returns all components sorted by id.
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+
+    /// Returns the component with a specific id.
+    pub fn get(&self, id: u8) -> Option<&Component> {
+        if id == 0 {
+            return None;
+        }
+
+        self.components.get(id as usize - 1)
+    }
+
+    /// Returns the number of components.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Are there no components?
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+/// Returns all cells connected to the start cell.
+fn fill(
+    values: &[u8],
+    size: Size2d,
+    start: usize,
+    predicate: &Predicate,
+    connectivity: Connectivity,
+    visited: &mut [bool],
+) -> Vec<usize> {
+    let mut group = Vec::new();
+    let mut stack = vec![start];
+    visited[start] = true;
+
+    while let Some(index) = stack.pop() {
+        group.push(index);
+        let [x, y] = size.to_x_and_y(index);
+
+        for (dx, dy) in connectivity.get_neighbours() {
+            let nx = x as i64 + dx;
+            let ny = y as i64 + dy;
+
+            if nx < 0 || ny < 0 || !size.is_inside(nx as u32, ny as u32) {
+                continue;
+            }
+
+            let neighbour = size.to_index_risky(nx as u32, ny as u32);
+
+            if !visited[neighbour] && predicate.check(values[neighbour]) {
+                visited[neighbour] = true;
+                stack.push(neighbour);
+            }
+        }
+    }
+
+    group
+}
+
+fn create_component(id: u8, group: &[usize], size: Size2d) -> Component {
+    let mut min = [u32::MAX; 2];
+    let mut max = [0; 2];
+    let mut sum = [0u64; 2];
+
+    for index in group {
+        let [x, y] = size.to_x_and_y(*index);
+        min = [min[0].min(x), min[1].min(y)];
+        max = [max[0].max(x), max[1].max(y)];
+        sum = [sum[0] + x as u64, sum[1] + y as u64];
+    }
+
+    let area = group.len();
+    let box_size = Size2d::unchecked(max[0] - min[0] + 1, max[1] - min[1] + 1);
+
+    Component {
+        id,
+        area,
+        bounding_box: Region::new(min[0], min[1], box_size),
+        centroid: [sum[0] as f32 / area as f32, sum[1] as f32 / area as f32],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_largest_components_get_an_id() {
+        let size = Size2d::unchecked(40, 40);
+        let values = (0..1600)
+            .map(|i| {
+                let [x, y] = size.to_x_and_y(i);
+                let is_speck = x % 2 == 0 && y % 2 == 0 && (x > 4 || y > 4);
+                let is_island = x < 4 && y < 4;
+                if is_speck || is_island {
+                    1
+                } else {
+                    0
+                }
+            })
+            .collect();
+        let attribute = Attribute::new("test", size, values).unwrap();
+
+        let components = Components::new(&attribute, &Predicate::Equal(1), Four, 0);
+
+        assert_eq!(components.len(), MAX_COMPONENTS);
+        assert_eq!(components.get(1).unwrap().area(), 16);
+        assert_eq!(components.labels()[0], 1);
+        assert!(components.get(0).is_none());
+        assert!(components.labels().contains(&0));
+    }
+}
@@ -1,15 +1,20 @@
 use crate::data::map::attribute::Attribute;
+use crate::data::math::region::Region;
+use crate::data::math::resampling::ResamplingFilter;
 use crate::data::math::size2d::Size2d;
 use anyhow::{bail, Context, Result};
 use std::collections::HashMap;
 
 pub mod attribute;
+pub mod components;
+pub mod statistics;
 
 /// Represents a 2d region or world map.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Map2d {
     name: String,
-    size: Size2d,
+    world_size: Size2d,
+    region: Region,
     attribute_lookup: HashMap<String, usize>,
     attributes: Vec<Attribute>,
 }
@@ -34,18 +39,87 @@ impl Map2d {
     pub fn with_name<S: Into<String>>(name: S, size: Size2d) -> Map2d {
         Map2d {
             name: name.into(),
-            size,
+            world_size: size,
+            region: Region::full(size),
             attribute_lookup: HashMap::new(),
             attributes: Vec::new(),
         }
     }
 
+    /// Returns a map, that only contains a region of a larger world. E.g. a tile.
+    /// Steps use the coordinates of the world instead of the local ones.
+    ///
+    /// ```
+    ///# use omg_core::data::map::Map2d;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let world_size = Size2d::unchecked(100, 80);
+    /// let region = Region::new(10, 20, Size2d::unchecked(2, 3));
+    /// let map = Map2d::with_region("tile", world_size, region).unwrap();
+    ///
+    /// assert_eq!(map.size(), region.size());
+    /// assert_eq!(map.world_size(), world_size);
+    /// assert_eq!(map.region(), region);
+    /// ```
+    ///
+    /// Returns an error, if the region is outside of the world:
+    ///
+    /// ```
+    ///# use omg_core::data::map::Map2d;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(99, 20, Size2d::unchecked(2, 3));
+    ///
+    /// assert!(Map2d::with_region("tile", Size2d::unchecked(100, 80), region).is_err());
+    /// ```
+    pub fn with_region<S: Into<String>>(
+        name: S,
+        world_size: Size2d,
+        region: Region,
+    ) -> Result<Map2d> {
+        region.validate(world_size)?;
+
+        Ok(Map2d {
+            name: name.into(),
+            world_size,
+            region,
+            attribute_lookup: HashMap::new(),
+            attributes: Vec::new(),
+        })
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// Returns the size of the map or region.
     pub fn size(&self) -> Size2d {
-        self.size
+        self.region.size()
+    }
+
+    /// Returns the size of the whole world, which can be larger than the map.
+    pub fn world_size(&self) -> Size2d {
+        self.world_size
+    }
+
+    /// Returns the part of the world covered by the map.
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    /// Converts local coordinates to the coordinates of the world.
+    ///
+    /// ```
+    ///# use omg_core::data::map::Map2d;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(10, 20, Size2d::unchecked(2, 3));
+    /// let map = Map2d::with_region("tile", Size2d::unchecked(100, 80), region).unwrap();
+    ///
+    /// assert_eq!(map.to_world(1, 2), [11, 22]);
+    /// ```
+    pub fn to_world(&self, x: u32, y: u32) -> [u32; 2] {
+        [self.region.x() + x, self.region.y() + y]
     }
 
     pub fn get_all(&self) -> &[Attribute] {
@@ -74,7 +148,7 @@ impl Map2d {
     /// assert!(map.create_attribute("elevation", 100).is_err());
     /// ```
     pub fn create_attribute<S: Into<String>>(&mut self, name: S, default: u8) -> Result<usize> {
-        self.add_attribute(Attribute::default_value(name, self.size, default))
+        self.add_attribute(Attribute::default_value(name, self.size(), default))
     }
 
     /// Adds a new [`Attribute`] with the supplied values to the map and returns its id.
@@ -83,7 +157,30 @@ impl Map2d {
         name: S,
         values: Vec<u8>,
     ) -> Result<usize> {
-        self.add_attribute(Attribute::new(name, self.size, values))
+        self.add_attribute(Attribute::new(name, self.size(), values))
+    }
+
+    /// Replaces the values of the [`Attribute`] with the same name or adds a new one.
+    /// Returns its id.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::unchecked(2, 1));
+    /// map.create_attribute("elevation", 42).unwrap();
+    ///
+    /// assert_eq!(map.replace_or_create_attribute("rainfall", vec![1, 2]).unwrap(), 1);
+    /// assert_eq!(map.replace_or_create_attribute("elevation", vec![3, 4]).unwrap(), 0);
+    /// assert_eq!(get_attribute(&map, 0).get_all(), &vec![3, 4]);
+    /// ```
+    pub fn replace_or_create_attribute(&mut self, name: &str, values: Vec<u8>) -> Result<usize> {
+        match self.get_attribute_id(name) {
+            Some(id) => {
+                get_attribute_mut(self, id).replace_all(values);
+                Ok(id)
+            }
+            None => self.create_attribute_from(name, values),
+        }
     }
 
     fn add_attribute(&mut self, attribute: Result<Attribute>) -> Result<usize> {
@@ -152,6 +249,81 @@ impl Map2d {
     pub fn get_attribute_mut(&mut self, id: usize) -> Option<&mut Attribute> {
         self.attributes.get_mut(id)
     }
+
+    /// Returns a copy of the map with all its [`Attribute`]s resized.
+    /// The copy is a whole world, even if the original map was only a region.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::resampling::ResamplingFilter::Area;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::with_name("world", Size2d::unchecked(4, 2));
+    /// map.create_attribute_from("elevation", vec![0, 10, 20, 30, 40, 50, 60, 70]).unwrap();
+    /// let size = Size2d::unchecked(2, 1);
+    ///
+    /// let preview = map.resample(size, Area);
+    ///
+    /// assert_eq!(preview.name(), "world");
+    /// assert_eq!(preview.size(), size);
+    /// assert_eq!(preview.get_attribute_id("elevation"), Some(0));
+    /// assert_eq!(get_attribute(&preview, 0).get_all(), &vec![25, 45]);
+    /// ```
+    pub fn resample(&self, size: Size2d, filter: ResamplingFilter) -> Map2d {
+        Map2d {
+            name: self.name.clone(),
+            world_size: size,
+            region: Region::full(size),
+            attribute_lookup: self.attribute_lookup.clone(),
+            attributes: self
+                .attributes
+                .iter()
+                .map(|attribute| attribute.resample(size, filter))
+                .collect(),
+        }
+    }
+
+    /// Returns a copy of a part of the map. The region uses the coordinates of the world.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::with_name("world", Size2d::unchecked(3, 3));
+    /// map.create_attribute_from("elevation", vec![0, 1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+    /// let region = Region::new(1, 1, Size2d::unchecked(2, 2));
+    ///
+    /// let tile = map.crop(region).unwrap();
+    ///
+    /// assert_eq!(tile.region(), region);
+    /// assert_eq!(tile.world_size(), map.world_size());
+    /// assert_eq!(get_attribute(&tile, 0).get_all(), &vec![4, 5, 7, 8]);
+    /// assert!(map.crop(Region::new(2, 2, Size2d::unchecked(2, 2))).is_err());
+    /// ```
+    pub fn crop(&self, region: Region) -> Result<Map2d> {
+        if !region.is_inside(&self.region) {
+            bail!(
+                "{:?} is outside the {:?} of map '{}'!",
+                region,
+                self.region,
+                self.name
+            );
+        }
+
+        let x = region.x() - self.region.x();
+        let y = region.y() - self.region.y();
+
+        Ok(Map2d {
+            name: self.name.clone(),
+            world_size: self.world_size,
+            region,
+            attribute_lookup: self.attribute_lookup.clone(),
+            attributes: self
+                .attributes
+                .iter()
+                .map(|attribute| attribute.crop(x, y, region.size()))
+                .collect(),
+        })
+    }
 }
 
 /// Returns the [`Attribute`] with the matching id.
@@ -0,0 +1,402 @@
+use crate::data::map::attribute::Attribute;
+use crate::data::math::predicate::Predicate;
+use anyhow::{bail, Result};
+
+/// Counts how often each value of an [`Attribute`] occurs.
+/// All statistics are derived from it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Histogram {
+    counts: Vec<usize>,
+    total: usize,
+}
+
+impl Histogram {
+    /// Counts the values of the whole attribute.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 5, 9]).unwrap();
+    /// let histogram = Histogram::new(&attribute);
+    ///
+    /// assert_eq!(histogram.total(), 4);
+    /// assert_eq!(histogram.count(0), 0);
+    /// assert_eq!(histogram.count(1), 1);
+    /// assert_eq!(histogram.count(5), 2);
+    /// assert_eq!(histogram.count(9), 1);
+    /// ```
+    pub fn new(attribute: &Attribute) -> Histogram {
+        Self::from_values(attribute.get_all().iter().copied())
+    }
+
+    /// Only counts the cells, where the mask fulfills the predicate.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(2, 2);
+    /// let attribute = Attribute::new("test", size, vec![1, 5, 5, 9]).unwrap();
+    /// let mask = Attribute::new("mask", size, vec![0, 100, 0, 100]).unwrap();
+    /// let histogram = Histogram::with_mask(&attribute, &mask, &Predicate::Above(50)).unwrap();
+    ///
+    /// assert_eq!(histogram.total(), 2);
+    /// assert_eq!(histogram.count(1), 0);
+    /// assert_eq!(histogram.count(5), 1);
+    /// assert_eq!(histogram.count(9), 1);
+    /// ```
+    ///
+    /// Fails if the sizes are different.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::default_value("test", Size2d::unchecked(2, 2), 0).unwrap();
+    /// let mask = Attribute::default_value("mask", Size2d::unchecked(1, 2), 0).unwrap();
+    ///
+    /// assert!(Histogram::with_mask(&attribute, &mask, &Predicate::Above(50)).is_err());
+    /// ```
+    pub fn with_mask(
+        attribute: &Attribute,
+        mask: &Attribute,
+        predicate: &Predicate,
+    ) -> Result<Histogram> {
+        if attribute.size() != mask.size() {
+            bail!(
+                "The size of attribute '{}' doesn't match the size of the mask '{}'!",
+                attribute.name(),
+                mask.name()
+            );
+        }
+
+        Ok(Self::from_values(
+            attribute
+                .get_all()
+                .iter()
+                .zip(mask.get_all().iter())
+                .filter(|(_, mask)| predicate.check(**mask))
+                .map(|(value, _)| *value),
+        ))
+    }
+
+    fn from_values<I: Iterator<Item = u8>>(values: I) -> Histogram {
+        let mut counts = vec![0; 256];
+        let mut total = 0;
+
+        for value in values {
+            counts[value as usize] += 1;
+            total += 1;
+        }
+
+        Histogram { counts, total }
+    }
+
+    /// Returns the number of counted cells.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns how often a value occurs.
+    pub fn count(&self, value: u8) -> usize {
+        self.counts[value as usize]
+    }
+
+    /// Returns the counts of all 256 values.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Returns the fraction of the counted cells with the value.
+    /// E.g. the area of a biome.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 5, 9]).unwrap();
+    /// let histogram = Histogram::new(&attribute);
+    ///
+    /// assert_eq!(histogram.share(0), 0.0);
+    /// assert_eq!(histogram.share(1), 0.25);
+    /// assert_eq!(histogram.share(5), 0.5);
+    /// ```
+    pub fn share(&self, value: u8) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        self.count(value) as f32 / self.total as f32
+    }
+
+    /// Returns the fraction of the counted cells for each value that occurs.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 5, 9]).unwrap();
+    /// let histogram = Histogram::new(&attribute);
+    ///
+    /// assert_eq!(histogram.shares(), vec![(1, 0.25), (5, 0.5), (9, 0.25)]);
+    /// ```
+    pub fn shares(&self) -> Vec<(u8, f32)> {
+        (0..=u8::MAX)
+            .filter(|value| self.count(*value) > 0)
+            .map(|value| (value, self.share(value)))
+            .collect()
+    }
+
+    /// Returns the fraction of the counted cells fulfilling the predicate.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 5, 9]).unwrap();
+    /// let histogram = Histogram::new(&attribute);
+    ///
+    /// assert_eq!(histogram.share_of(&Predicate::Below(6)), 0.75);
+    /// ```
+    pub fn share_of(&self, predicate: &Predicate) -> f32 {
+        (0..=u8::MAX)
+            .filter(|value| predicate.check(*value))
+            .map(|value| self.share(value))
+            .sum()
+    }
+
+    /// Sums the counts of neighbouring values into a number of bins. Useful for charts.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 130, 255]).unwrap();
+    /// let histogram = Histogram::new(&attribute);
+    ///
+    /// assert_eq!(histogram.bins(2), vec![2, 2]);
+    /// assert_eq!(histogram.bins(4), vec![2, 0, 1, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bins is 0.
+    pub fn bins(&self, number: usize) -> Vec<usize> {
+        assert!(number > 0, "The number of bins is 0!");
+        let mut bins = vec![0; number];
+
+        for (value, count) in self.counts.iter().enumerate() {
+            bins[value * number / 256] += count;
+        }
+
+        bins
+    }
+
+    /// Returns the smallest value, unless no cell was counted.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![5, 1, 5, 9]).unwrap();
+    ///
+    /// assert_eq!(Histogram::new(&attribute).min(), Some(1));
+    /// ```
+    pub fn min(&self) -> Option<u8> {
+        self.counts
+            .iter()
+            .position(|count| *count > 0)
+            .map(|value| value as u8)
+    }
+
+    /// Returns the largest value, unless no cell was counted.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![5, 1, 9, 5]).unwrap();
+    ///
+    /// assert_eq!(Histogram::new(&attribute).max(), Some(9));
+    /// ```
+    pub fn max(&self) -> Option<u8> {
+        self.counts
+            .iter()
+            .rposition(|count| *count > 0)
+            .map(|value| value as u8)
+    }
+
+    /// Returns the arithmetic mean, unless no cell was counted.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 5, 9]).unwrap();
+    ///
+    /// assert_eq!(Histogram::new(&attribute).mean(), Some(5.0));
+    /// ```
+    pub fn mean(&self) -> Option<f32> {
+        if self.total == 0 {
+            return None;
+        }
+
+        let sum: usize = self
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(value, count)| value * count)
+            .sum();
+
+        Some(sum as f32 / self.total as f32)
+    }
+
+    /// Returns the standard deviation, unless no cell was counted.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 5, 9]).unwrap();
+    ///
+    /// assert_eq!(Histogram::new(&attribute).stddev(), Some(8.0f32.sqrt()));
+    /// ```
+    pub fn stddev(&self) -> Option<f32> {
+        let mean = self.mean()?;
+        let sum: f32 = self
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(value, count)| (value as f32 - mean).powi(2) * *count as f32)
+            .sum();
+
+        Some((sum / self.total as f32).sqrt())
+    }
+
+    /// Returns the smallest value, which is equal or greater than the percentage of counted cells.
+    /// Returns None, if no cell was counted.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 7, 9]).unwrap();
+    /// let histogram = Histogram::new(&attribute);
+    ///
+    /// assert_eq!(histogram.percentile(0.0), Some(1));
+    /// assert_eq!(histogram.percentile(25.0), Some(1));
+    /// assert_eq!(histogram.percentile(26.0), Some(5));
+    /// assert_eq!(histogram.percentile(50.0), Some(5));
+    /// assert_eq!(histogram.percentile(75.0), Some(7));
+    /// assert_eq!(histogram.percentile(100.0), Some(9));
+    /// ```
+    pub fn percentile(&self, percentage: f32) -> Option<u8> {
+        if self.total == 0 {
+            return None;
+        }
+
+        let rank = (percentage.clamp(0.0, 100.0) / 100.0 * self.total as f32).ceil() as usize;
+        let rank = rank.max(1);
+        let mut sum = 0;
+
+        for (value, count) in self.counts.iter().enumerate() {
+            sum += count;
+
+            if sum >= rank {
+                return Some(value as u8);
+            }
+        }
+
+        self.max()
+    }
+
+    /// Calculates the most common statistics, unless no cell was counted.
+    pub fn statistics(&self) -> Option<Statistics> {
+        Some(Statistics {
+            count: self.total,
+            min: self.min()?,
+            max: self.max()?,
+            mean: self.mean()?,
+            stddev: self.stddev()?,
+            median: self.percentile(50.0)?,
+        })
+    }
+}
+
+/// The most common statistics of an [`Attribute`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Statistics {
+    count: usize,
+    min: u8,
+    max: u8,
+    mean: f32,
+    stddev: f32,
+    median: u8,
+}
+
+impl Statistics {
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn min(&self) -> u8 {
+        self.min
+    }
+
+    pub fn max(&self) -> u8 {
+        self.max
+    }
+
+    pub fn mean(&self) -> f32 {
+        self.mean
+    }
+
+    pub fn stddev(&self) -> f32 {
+        self.stddev
+    }
+
+    pub fn median(&self) -> u8 {
+        self.median
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::size2d::Size2d;
+
+    #[test]
+    fn test_empty_histogram() {
+        let size = Size2d::unchecked(2, 2);
+        let attribute = Attribute::new("test", size, vec![1, 5, 5, 9]).unwrap();
+        let mask = Attribute::default_value("mask", size, 0).unwrap();
+        let histogram = Histogram::with_mask(&attribute, &mask, &Predicate::Equal(1)).unwrap();
+
+        assert_eq!(histogram.total(), 0);
+        assert_eq!(histogram.share(5), 0.0);
+        assert!(histogram.shares().is_empty());
+        assert_eq!(histogram.min(), None);
+        assert_eq!(histogram.max(), None);
+        assert_eq!(histogram.mean(), None);
+        assert_eq!(histogram.stddev(), None);
+        assert_eq!(histogram.percentile(50.0), None);
+        assert_eq!(histogram.statistics(), None);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let attribute = Attribute::new("test", Size2d::unchecked(2, 2), vec![1, 5, 5, 9]).unwrap();
+        let statistics = Histogram::new(&attribute).statistics().unwrap();
+
+        assert_eq!(statistics.count(), 4);
+        assert_eq!(statistics.min(), 1);
+        assert_eq!(statistics.max(), 9);
+        assert_eq!(statistics.mean(), 5.0);
+        assert_eq!(statistics.stddev(), 8.0f32.sqrt());
+        assert_eq!(statistics.median(), 5);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::data::math::interpolation::lerp;
+use BlendMode::*;
+
+/// Defines how a new value is combined with an existing one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BlendMode {
+    /// Adds both values, but saturates at 255.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Add;
+    /// assert_eq!(Add.blend(100, 50), 150);
+    /// assert_eq!(Add.blend(200, 100), 255);
+    /// ```
+    Add,
+    /// Subtracts the new value, but saturates at 0.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Sub;
+    /// assert_eq!(Sub.blend(100, 50), 50);
+    /// assert_eq!(Sub.blend(100, 200), 0);
+    /// ```
+    Sub,
+    /// Multiplies both values as if 255 was 1. Only darkens.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Multiply;
+    /// assert_eq!(Multiply.blend(100, 0), 0);
+    /// assert_eq!(Multiply.blend(100, 51), 20);
+    /// assert_eq!(Multiply.blend(100, 255), 100);
+    /// ```
+    Multiply,
+    /// Inverts both values, multiplies them & inverts the result. Only brightens.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Screen;
+    /// assert_eq!(Screen.blend(100, 0), 100);
+    /// assert_eq!(Screen.blend(100, 51), 131);
+    /// assert_eq!(Screen.blend(100, 255), 255);
+    /// ```
+    Screen,
+    /// Takes the smaller value.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Min;
+    /// assert_eq!(Min.blend(100, 50), 50);
+    /// assert_eq!(Min.blend(100, 200), 100);
+    /// ```
+    Min,
+    /// Takes the larger value.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Max;
+    /// assert_eq!(Max.blend(100, 50), 100);
+    /// assert_eq!(Max.blend(100, 200), 200);
+    /// ```
+    Max,
+    /// Replaces the existing value. With a weight below 1 it interpolates linearly between both.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Overwrite;
+    /// assert_eq!(Overwrite.blend(100, 50), 50);
+    /// assert_eq!(Overwrite.blend(100, 200), 200);
+    /// assert_eq!(Overwrite.blend_weighted(100, 200, 0.25), 125);
+    /// ```
+    Overwrite,
+    /// Takes the mean of both values.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Average;
+    /// assert_eq!(Average.blend(100, 50), 75);
+    /// assert_eq!(Average.blend(100, 201), 151);
+    /// ```
+    Average,
+}
+
+impl BlendMode {
+    /// Combines the existing value with the new value.
+    pub fn blend(&self, value: u8, new_value: u8) -> u8 {
+        let a = value as u32;
+        let b = new_value as u32;
+
+        match self {
+            Add => value.saturating_add(new_value),
+            Sub => value.saturating_sub(new_value),
+            Multiply => div_round(a * b, 255) as u8,
+            Screen => 255 - div_round((255 - a) * (255 - b), 255) as u8,
+            Min => value.min(new_value),
+            Max => value.max(new_value),
+            Overwrite => new_value,
+            Average => div_round(a + b, 2) as u8,
+        }
+    }
+
+    /// Combines both values & interpolates between the existing & the combined value with a weight.
+    /// Like [`lerp`] & masked steps, the interpolated change is truncated.
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Add;
+    /// assert_eq!(Add.blend_weighted(100, 50, 0.0), 100);
+    /// assert_eq!(Add.blend_weighted(100, 50, 0.5), 125);
+    /// assert_eq!(Add.blend_weighted(100, 51, 0.5), 125);
+    /// assert_eq!(Add.blend_weighted(100, 50, 1.0), 150);
+    /// ```
+    pub fn blend_weighted(&self, value: u8, new_value: u8, weight: f32) -> u8 {
+        lerp(value, self.blend(value, new_value), weight)
+    }
+}
+
+fn div_round(numerator: u32, denominator: u32) -> u32 {
+    (numerator + denominator / 2) / denominator
+}
@@ -0,0 +1,231 @@
+use crate::data::math::size2d::Size2d;
+use std::f32::consts::SQRT_2;
+use DistanceMetric::*;
+
+/// Defines how the distance to the nearest selected cell is calculated.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DistanceMetric {
+    /// The exact straight-line distance.
+    ///
+    /// ```
+    ///# use omg_core::data::math::distance_transform::DistanceMetric::Euclidean;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(4, 3);
+    /// let mut selected = vec![false; 12];
+    /// selected[0] = true;
+    ///
+    /// let distances = Euclidean.calculate(&selected, size);
+    ///
+    /// assert_eq!(distances[0], 0.0);
+    /// assert_eq!(distances[3], 3.0);
+    /// assert_eq!(distances[11], 13.0f32.sqrt());
+    /// ```
+    Euclidean,
+    /// Approximates the distance with steps to the 8 neighbours. Faster, but less accurate.
+    ///
+    /// ```
+    ///# use omg_core::data::math::distance_transform::DistanceMetric::Chamfer;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(4, 3);
+    /// let mut selected = vec![false; 12];
+    /// selected[0] = true;
+    ///
+    /// let distances = Chamfer.calculate(&selected, size);
+    ///
+    /// assert_eq!(distances[0], 0.0);
+    /// assert_eq!(distances[3], 3.0);
+    /// assert_eq!(distances[11], 1.0 + 2.0 * 2.0f32.sqrt());
+    /// ```
+    Chamfer,
+}
+
+impl DistanceMetric {
+    /// Calculates the distance of each cell to the nearest selected cell.
+    /// Is [`f32::INFINITY`] for all cells, if no cell is selected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells doesn't match the size.
+    pub fn calculate(&self, selected: &[bool], size: Size2d) -> Vec<f32> {
+        assert_eq!(
+            selected.len(),
+            size.get_area(),
+            "The number of cells doesn't match the size!"
+        );
+
+        match self {
+            Euclidean => calculate_euclidean(selected, size),
+            Chamfer => calculate_chamfer(selected, size),
+        }
+    }
+}
+
+/// Calculates the squared distances along the columns & then along the rows.
+/// See "Distance Transforms of Sampled Functions" by Felzenszwalb & Huttenlocher.
+fn calculate_euclidean(selected: &[bool], size: Size2d) -> Vec<f32> {
+    let width = size.width() as usize;
+    let height = size.height() as usize;
+    let mut squared: Vec<f32> = selected
+        .iter()
+        .map(|s| if *s { 0.0 } else { f32::INFINITY })
+        .collect();
+    let mut line = Vec::with_capacity(width.max(height));
+
+    for x in 0..width {
+        line.clear();
+        line.extend((0..height).map(|y| squared[y * width + x]));
+        let result = transform_line(&line);
+
+        for (y, value) in result.into_iter().enumerate() {
+            squared[y * width + x] = value;
+        }
+    }
+
+    for y in 0..height {
+        let row = &mut squared[y * width..(y + 1) * width];
+        let result = transform_line(row);
+        row.copy_from_slice(&result);
+    }
+
+    squared.into_iter().map(f32::sqrt).collect()
+}
+
+/// Calculates the lower envelope of the parabolas rooted at each cell.
+fn transform_line(values: &[f32]) -> Vec<f32> {
+    let length = values.len();
+    let mut result = vec![f32::INFINITY; length];
+    let mut vertices: Vec<usize> = Vec::with_capacity(length);
+    let mut boundaries: Vec<f32> = Vec::with_capacity(length + 1);
+    let intersect = |a: usize, b: usize| {
+        let (a_f, b_f) = (a as f32, b as f32);
+        ((values[b] + b_f * b_f) - (values[a] + a_f * a_f)) / (2.0 * (b_f - a_f))
+    };
+
+    for (index, value) in values.iter().enumerate() {
+        if value.is_infinite() {
+            continue;
+        }
+
+        while let Some(&last) = vertices.last() {
+            if intersect(last, index) <= boundaries[boundaries.len() - 1] {
+                vertices.pop();
+                boundaries.pop();
+            } else {
+                break;
+            }
+        }
+
+        let boundary = match vertices.last() {
+            Some(&last) => intersect(last, index),
+            None => f32::NEG_INFINITY,
+        };
+        vertices.push(index);
+        boundaries.push(boundary);
+    }
+
+    if vertices.is_empty() {
+        return result;
+    }
+
+    let mut current = 0;
+
+    for (index, distance) in result.iter_mut().enumerate() {
+        while current + 1 < vertices.len() && boundaries[current + 1] < index as f32 {
+            current += 1;
+        }
+
+        let vertex = vertices[current];
+        let diff = index as f32 - vertex as f32;
+        *distance = diff * diff + values[vertex];
+    }
+
+    result
+}
+
+/// Propagates the distances with 2 passes over the map.
+fn calculate_chamfer(selected: &[bool], size: Size2d) -> Vec<f32> {
+    let width = size.width() as i64;
+    let height = size.height() as i64;
+    let mut distances: Vec<f32> = selected
+        .iter()
+        .map(|s| if *s { 0.0 } else { f32::INFINITY })
+        .collect();
+    let forward = [
+        (-1, 0, 1.0),
+        (-1, -1, SQRT_2),
+        (0, -1, 1.0),
+        (1, -1, SQRT_2),
+    ];
+    let backward = [(1, 0, 1.0), (1, 1, SQRT_2), (0, 1, 1.0), (-1, 1, SQRT_2)];
+    let mut update = |x: i64, y: i64, neighbours: &[(i64, i64, f32)]| {
+        let index = (y * width + x) as usize;
+
+        for (dx, dy, weight) in neighbours {
+            let (nx, ny) = (x + dx, y + dy);
+
+            if nx >= 0 && nx < width && ny >= 0 && ny < height {
+                let candidate = distances[(ny * width + nx) as usize] + weight;
+
+                if candidate < distances[index] {
+                    distances[index] = candidate;
+                }
+            }
+        }
+    };
+
+    for y in 0..height {
+        for x in 0..width {
+            update(x, y, &forward);
+        }
+    }
+
+    for y in (0..height).rev() {
+        for x in (0..width).rev() {
+            update(x, y, &backward);
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_euclidean_matches_brute_force() {
+        let size = Size2d::unchecked(9, 7);
+        let selected: Vec<bool> = (0..63).map(|i| i % 17 == 3 || i == 40).collect();
+        let distances = Euclidean.calculate(&selected, size);
+
+        for y in 0..7i32 {
+            for x in 0..9i32 {
+                let mut min = f32::INFINITY;
+
+                for (index, _) in selected.iter().enumerate().filter(|(_, s)| **s) {
+                    let dx = (index as i32 % 9 - x) as f32;
+                    let dy = (index as i32 / 9 - y) as f32;
+                    min = min.min((dx * dx + dy * dy).sqrt());
+                }
+
+                let distance = distances[(y * 9 + x) as usize];
+                assert!((distance - min).abs() < 0.001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nothing_selected() {
+        let size = Size2d::unchecked(3, 2);
+        let selected = vec![false; 6];
+
+        assert!(Euclidean
+            .calculate(&selected, size)
+            .iter()
+            .all(|d| d.is_infinite()));
+        assert!(Chamfer
+            .calculate(&selected, size)
+            .iter()
+            .all(|d| d.is_infinite()));
+    }
+}
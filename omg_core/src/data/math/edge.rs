@@ -0,0 +1,110 @@
+use crate::data::math::interpolation::lerp;
+use crate::data::math::size2d::Size2d;
+use EdgeMode::*;
+
+/// Defines how coordinates outside a grid of values are handled.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EdgeMode {
+    /// Uses the nearest cell at the edge.
+    ///
+    /// ```
+    ///# use omg_core::data::math::edge::EdgeMode::Clamp;
+    /// assert_eq!(Clamp.apply(-2, 4), 0);
+    /// assert_eq!(Clamp.apply(2, 4), 2);
+    /// assert_eq!(Clamp.apply(5, 4), 3);
+    /// ```
+    Clamp,
+    /// Continues at the opposite edge.
+    ///
+    /// ```
+    ///# use omg_core::data::math::edge::EdgeMode::Wrap;
+    /// assert_eq!(Wrap.apply(-1, 4), 3);
+    /// assert_eq!(Wrap.apply(2, 4), 2);
+    /// assert_eq!(Wrap.apply(5, 4), 1);
+    /// ```
+    Wrap,
+    /// Mirrors the grid at the edge.
+    ///
+    /// ```
+    ///# use omg_core::data::math::edge::EdgeMode::Mirror;
+    /// assert_eq!(Mirror.apply(-1, 4), 0);
+    /// assert_eq!(Mirror.apply(-2, 4), 1);
+    /// assert_eq!(Mirror.apply(2, 4), 2);
+    /// assert_eq!(Mirror.apply(4, 4), 3);
+    /// assert_eq!(Mirror.apply(5, 4), 2);
+    /// assert_eq!(Mirror.apply(9, 4), 1);
+    /// ```
+    Mirror,
+}
+
+impl EdgeMode {
+    /// Maps a coordinate to a cell of a row or column with a specific length.
+    pub fn apply(&self, coordinate: i64, length: u32) -> u32 {
+        let length = length as i64;
+
+        let result = match self {
+            Clamp => coordinate.clamp(0, length - 1),
+            Wrap => coordinate.rem_euclid(length),
+            Mirror => {
+                let period = 2 * length;
+                let coordinate = coordinate.rem_euclid(period);
+
+                if coordinate < length {
+                    coordinate
+                } else {
+                    period - 1 - coordinate
+                }
+            }
+        };
+
+        result as u32
+    }
+}
+
+/// Samples a grid of values at a cell shifted by an offset, which can be outside the grid or between cells.
+/// Bilinear sampling interpolates the 4 nearest cells, otherwise the nearest cell is used.
+///
+/// The fraction only depends on the offset, so the result doesn't depend on the position of the grid.
+///
+/// ```
+///# use omg_core::data::math::edge::EdgeMode::*;
+///# use omg_core::data::math::edge::sample;
+///# use omg_core::data::math::size2d::Size2d;
+/// let size = Size2d::unchecked(2, 2);
+/// let values = vec![0, 100, 100, 200];
+///
+/// assert_eq!(sample(&values, size, [0, 0], [0.5, 0.0], Clamp, true), 50);
+/// assert_eq!(sample(&values, size, [0, 0], [0.5, 0.0], Clamp, false), 100);
+/// assert_eq!(sample(&values, size, [1, 0], [0.5, 0.0], Clamp, true), 100);
+/// assert_eq!(sample(&values, size, [1, 0], [0.5, 0.0], Wrap, true), 50);
+/// assert_eq!(sample(&values, size, [0, 0], [-1.0, 0.0], Mirror, true), 0);
+/// ```
+pub fn sample(
+    values: &[u8],
+    size: Size2d,
+    cell: [u32; 2],
+    offset: [f32; 2],
+    mode: EdgeMode,
+    bilinear: bool,
+) -> u8 {
+    let get = |x: i64, y: i64| {
+        let x = mode.apply(cell[0] as i64 + x, size.width());
+        let y = mode.apply(cell[1] as i64 + y, size.height());
+        values[size.to_index_risky(x, y)]
+    };
+
+    if !bilinear {
+        return get(offset[0].round() as i64, offset[1].round() as i64);
+    }
+
+    let x0 = offset[0].floor();
+    let y0 = offset[1].floor();
+    let factor_x = offset[0] - x0;
+    let factor_y = offset[1] - y0;
+    let (x0, y0) = (x0 as i64, y0 as i64);
+
+    let top = lerp(get(x0, y0), get(x0 + 1, y0), factor_x);
+    let bottom = lerp(get(x0, y0 + 1), get(x0 + 1, y0 + 1), factor_x);
+
+    lerp(top, bottom, factor_y)
+}
@@ -0,0 +1,201 @@
+use crate::data::map::{get_attribute, Map2d};
+use crate::data::math::generator::noise::Noise;
+use anyhow::Result;
+use Expression::*;
+
+pub mod parser;
+
+/// An operator with 2 operands.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    /// Applies the operator.
+    ///
+    /// ```
+    ///# use omg_core::data::math::expression::Operator::*;
+    /// assert_eq!(Add.apply(6.0, 3.0), 9.0);
+    /// assert_eq!(Sub.apply(6.0, 3.0), 3.0);
+    /// assert_eq!(Mul.apply(6.0, 3.0), 18.0);
+    /// assert_eq!(Div.apply(6.0, 3.0), 2.0);
+    /// ```
+    pub fn apply(&self, left: f32, right: f32) -> f32 {
+        match self {
+            Operator::Add => left + right,
+            Operator::Sub => left - right,
+            Operator::Mul => left * right,
+            Operator::Div => left / right,
+        }
+    }
+}
+
+/// A built-in function of an [`Expression`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Function {
+    Abs,
+    Clamp,
+    Floor,
+    Max,
+    Min,
+    Pow,
+    Round,
+    Sqrt,
+}
+
+impl Function {
+    /// Returns the function with this name.
+    ///
+    /// ```
+    ///# use omg_core::data::math::expression::Function;
+    /// assert_eq!(Function::get("clamp"), Some(Function::Clamp));
+    /// assert_eq!(Function::get("unknown"), None);
+    /// ```
+    pub fn get(name: &str) -> Option<Function> {
+        match name {
+            "abs" => Some(Function::Abs),
+            "clamp" => Some(Function::Clamp),
+            "floor" => Some(Function::Floor),
+            "max" => Some(Function::Max),
+            "min" => Some(Function::Min),
+            "pow" => Some(Function::Pow),
+            "round" => Some(Function::Round),
+            "sqrt" => Some(Function::Sqrt),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of arguments.
+    pub fn get_number_of_arguments(&self) -> usize {
+        match self {
+            Function::Abs | Function::Floor | Function::Round | Function::Sqrt => 1,
+            Function::Max | Function::Min | Function::Pow => 2,
+            Function::Clamp => 3,
+        }
+    }
+
+    /// Calls the function.
+    ///
+    /// ```
+    ///# use omg_core::data::math::expression::Function::*;
+    /// assert_eq!(Abs.call(&[-2.0]), 2.0);
+    /// assert_eq!(Clamp.call(&[300.0, 0.0, 255.0]), 255.0);
+    /// assert_eq!(Floor.call(&[2.7]), 2.0);
+    /// assert_eq!(Max.call(&[2.0, 3.0]), 3.0);
+    /// assert_eq!(Min.call(&[2.0, 3.0]), 2.0);
+    /// assert_eq!(Pow.call(&[2.0, 3.0]), 8.0);
+    /// assert_eq!(Round.call(&[2.5]), 3.0);
+    /// assert_eq!(Sqrt.call(&[9.0]), 3.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are too few arguments.
+    pub fn call(&self, arguments: &[f32]) -> f32 {
+        match self {
+            Function::Abs => arguments[0].abs(),
+            Function::Clamp => arguments[0].max(arguments[1]).min(arguments[2]),
+            Function::Floor => arguments[0].floor(),
+            Function::Max => arguments[0].max(arguments[1]),
+            Function::Min => arguments[0].min(arguments[1]),
+            Function::Pow => arguments[0].powf(arguments[1]),
+            Function::Round => arguments[0].round(),
+            Function::Sqrt => arguments[0].sqrt(),
+        }
+    }
+}
+
+/// A mathematical formula, which calculates a value per cell with floating point arithmetic.
+/// See [`parser`] for its syntax.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Expression {
+    Constant(f32),
+    /// The value of an [`Attribute`](crate::data::map::attribute::Attribute).
+    Attribute(usize),
+    /// The x-coordinate of the cell in the whole map.
+    X,
+    /// The y-coordinate of the cell in the whole map.
+    Y,
+    Negate(Box<Expression>),
+    Binary {
+        operator: Operator,
+        left: Box<Expression>,
+        right: Box<Expression>,
+    },
+    Call {
+        function: Function,
+        arguments: Vec<Expression>,
+    },
+    /// Noise between 0 & 1 at a point.
+    Noise {
+        noise: Noise,
+        x: Box<Expression>,
+        y: Box<Expression>,
+    },
+}
+
+impl Expression {
+    /// Parses a formula & resolves the names of attributes to their index in the list.
+    ///
+    /// ```
+    ///# use omg_core::data::math::expression::Expression;
+    ///# use omg_core::data::math::expression::Expression::*;
+    ///# use omg_core::data::math::expression::Operator::*;
+    /// let expression = Expression::parse("-rainfall / 4", &["elevation", "rainfall"]).unwrap();
+    /// let rainfall = Box::new(Negate(Box::new(Attribute(1))));
+    ///
+    /// assert_eq!(expression, Binary { operator: Div, left: rainfall, right: Box::new(Constant(4.0)) });
+    /// ```
+    pub fn parse<S: AsRef<str>>(formula: &str, attributes: &[S]) -> Result<Expression> {
+        parser::parse(formula, attributes)
+    }
+
+    /// Evaluates the expression for a cell.
+    ///
+    /// ```
+    ///# use omg_core::data::map::Map2d;
+    ///# use omg_core::data::math::expression::Expression;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::unchecked(2, 1));
+    /// map.create_attribute_from("elevation", vec![100, 200]);
+    /// let expression = Expression::parse("clamp(elevation * 0.8 + x * 3 - y, 0, 150)", &["elevation"]).unwrap();
+    ///
+    /// assert_eq!(expression.evaluate(&map, 0, [4.0, 1.0]), 91.0);
+    /// assert_eq!(expression.evaluate(&map, 1, [5.0, 1.0]), 150.0);
+    /// ```
+    pub fn evaluate(&self, map: &Map2d, index: usize, position: [f32; 2]) -> f32 {
+        match self {
+            Constant(value) => *value,
+            Attribute(id) => get_attribute(map, *id)[index] as f32,
+            X => position[0],
+            Y => position[1],
+            Negate(expression) => -expression.evaluate(map, index, position),
+            Binary {
+                operator,
+                left,
+                right,
+            } => operator.apply(
+                left.evaluate(map, index, position),
+                right.evaluate(map, index, position),
+            ),
+            Call {
+                function,
+                arguments,
+            } => {
+                let values: Vec<f32> = arguments
+                    .iter()
+                    .map(|argument| argument.evaluate(map, index, position))
+                    .collect();
+                function.call(&values)
+            }
+            Expression::Noise { noise, x, y } => noise.generate_normalized(
+                x.evaluate(map, index, position),
+                y.evaluate(map, index, position),
+            ),
+        }
+    }
+}
@@ -0,0 +1,481 @@
+//! Parses the formula of an [`Expression`]:
+//!
+//! * Numbers like `3` or `0.8`.
+//! * The names of attributes like `elevation`.
+//! * The coordinates `x` & `y` of the cell, unless an attribute has the same name.
+//! * The operators `+`, `-`, `*` & `/` with the usual precedence & parentheses.
+//! * The functions `abs`, `clamp`, `floor`, `max`, `min`, `pow`, `round` & `sqrt`.
+//! * The function `noise(x, y, seed=3, scale=20)`, which returns values between 0 & 1.
+//!   The optional seed & scale are numbers.
+//!
+//! ```
+//!# use omg_core::data::math::expression::Expression;
+//! let attributes = ["elevation", "rainfall"];
+//! let formula = "clamp(elevation * 0.8 + noise(x, y, seed=3) * 40 - rainfall / 4, 0, 255)";
+//!
+//! assert!(Expression::parse(formula, &attributes).is_ok());
+//! ```
+//!
+//! Errors contain the position of the problem & the formula:
+//!
+//! ```
+//!# use omg_core::data::math::expression::Expression;
+//! let attributes = ["elevation"];
+//! let error = |formula| Expression::parse(formula, &attributes).unwrap_err().to_string();
+//!
+//! assert_eq!(error("elevation +"), "Unexpected end of formula 'elevation +'!");
+//! assert_eq!(error("elevation + * 2"), "Unexpected '*' at position 12 of formula 'elevation + * 2'!");
+//! assert_eq!(error("rainfall * 2"), "Unknown attribute 'rainfall' at position 0 of formula 'rainfall * 2'!");
+//! assert_eq!(error("sin(x)"), "Unknown function 'sin' at position 0 of formula 'sin(x)'!");
+//! assert_eq!(error("min(x)"), "Function 'min' needs 2 arguments, but got 1 at position 0 of formula 'min(x)'!");
+//! assert_eq!(error("noise(x, y, seed=y)"), "Expected a number at position 17 of formula 'noise(x, y, seed=y)'!");
+//! assert_eq!(error("2 $ 3"), "Invalid character '$' at position 2 of formula '2 $ 3'!");
+//! ```
+//!
+//! Formulas are limited to a nesting depth of 64 parentheses, function calls & unary operators,
+//! because the parser & the evaluation are recursive. Chains of binary operators are not nested:
+//!
+//! ```
+//!# use omg_core::data::math::expression::Expression;
+//! let nested = format!("{}1{}", "(".repeat(100), ")".repeat(100));
+//! let chain = vec!["elevation"; 100].join(" + ");
+//!
+//! assert!(Expression::parse(&nested, &["elevation"]).is_err());
+//! assert!(Expression::parse(&chain, &["elevation"]).is_ok());
+//! ```
+use crate::data::math::expression::{Expression, Function, Operator};
+use crate::data::math::generator::noise::Noise;
+use anyhow::{bail, Context, Result};
+use Token::*;
+
+/// The maximum nesting depth of a formula.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Number(f32),
+    Name(String),
+    Symbol(char),
+}
+
+/// Parses a formula & resolves the names of attributes to their index in the list.
+pub fn parse<S: AsRef<str>>(formula: &str, attributes: &[S]) -> Result<Expression> {
+    let tokens = tokenize(formula)?;
+    let mut parser = Parser {
+        formula,
+        tokens,
+        index: 0,
+        depth: 0,
+        attributes,
+    };
+    let expression = parser.parse_sum()?;
+
+    if parser.index < parser.tokens.len() {
+        return parser.unexpected();
+    }
+
+    Ok(expression)
+}
+
+/// Splits the formula into tokens & their positions.
+fn tokenize(formula: &str) -> Result<Vec<(Token, usize)>> {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        } else if c.is_ascii_digit() || c == '.' {
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            let number = text.parse().with_context(|| {
+                format!(
+                    "Invalid number '{}' at position {} of formula '{}'!",
+                    text, start, formula
+                )
+            })?;
+            tokens.push((Number(number), start));
+        } else if c.is_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push((Name(chars[start..i].iter().collect()), start));
+        } else if "+-*/(),=".contains(c) {
+            i += 1;
+            tokens.push((Symbol(c), start));
+        } else {
+            bail!(
+                "Invalid character '{}' at position {} of formula '{}'!",
+                c,
+                start,
+                formula
+            );
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A recursive descent parser.
+struct Parser<'a, S: AsRef<str>> {
+    formula: &'a str,
+    tokens: Vec<(Token, usize)>,
+    index: usize,
+    depth: usize,
+    attributes: &'a [S],
+}
+
+impl<'a, S: AsRef<str>> Parser<'a, S> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Result<(Token, usize)> {
+        match self.tokens.get(self.index) {
+            Some(entry) => {
+                self.index += 1;
+                Ok(entry.clone())
+            }
+            None => bail!("Unexpected end of formula '{}'!", self.formula),
+        }
+    }
+
+    fn error<T>(&self, message: String, position: usize) -> Result<T> {
+        bail!(
+            "{} at position {} of formula '{}'!",
+            message,
+            position,
+            self.formula
+        )
+    }
+
+    fn unexpected<T>(&self) -> Result<T> {
+        match self.tokens.get(self.index) {
+            Some((token, position)) => {
+                self.error(format!("Unexpected '{}'", to_string(token)), *position)
+            }
+            None => bail!("Unexpected end of formula '{}'!", self.formula),
+        }
+    }
+
+    /// Increases the nesting depth, unless it would exceed the limit.
+    fn enter(&mut self) -> Result<()> {
+        if self.depth >= MAX_DEPTH {
+            let position = self
+                .tokens
+                .get(self.index)
+                .map(|(_, position)| *position)
+                .unwrap_or(self.formula.len());
+            return self.error(format!("Nested deeper than {}", MAX_DEPTH), position);
+        }
+
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<()> {
+        if self.peek() == Some(&Symbol(symbol)) {
+            self.index += 1;
+            Ok(())
+        } else {
+            self.unexpected()
+        }
+    }
+
+    /// Parses `product (('+' | '-') product)*`.
+    fn parse_sum(&mut self) -> Result<Expression> {
+        let mut expression = self.parse_product()?;
+
+        loop {
+            let operator = match self.peek() {
+                Some(Symbol('+')) => Operator::Add,
+                Some(Symbol('-')) => Operator::Sub,
+                _ => return Ok(expression),
+            };
+            self.index += 1;
+            let right = self.parse_product()?;
+            expression = binary(operator, expression, right);
+        }
+    }
+
+    /// Parses `unary (('*' | '/') unary)*`.
+    fn parse_product(&mut self) -> Result<Expression> {
+        let mut expression = self.parse_unary()?;
+
+        loop {
+            let operator = match self.peek() {
+                Some(Symbol('*')) => Operator::Mul,
+                Some(Symbol('/')) => Operator::Div,
+                _ => return Ok(expression),
+            };
+            self.index += 1;
+            let right = self.parse_unary()?;
+            expression = binary(operator, expression, right);
+        }
+    }
+
+    /// Parses `'-' unary | primary`.
+    fn parse_unary(&mut self) -> Result<Expression> {
+        self.enter()?;
+
+        let expression = if self.peek() == Some(&Symbol('-')) {
+            self.index += 1;
+            Expression::Negate(Box::new(self.parse_unary()?))
+        } else {
+            self.parse_primary()?
+        };
+
+        self.depth -= 1;
+        Ok(expression)
+    }
+
+    /// Parses a number, a variable, a function call or an expression in parentheses.
+    fn parse_primary(&mut self) -> Result<Expression> {
+        match self.next()? {
+            (Number(number), _) => Ok(Expression::Constant(number)),
+            (Symbol('('), _) => {
+                let expression = self.parse_sum()?;
+                self.expect(')')?;
+                Ok(expression)
+            }
+            (Name(name), position) => {
+                if self.peek() == Some(&Symbol('(')) {
+                    self.index += 1;
+                    self.parse_call(&name, position)
+                } else {
+                    self.parse_variable(&name, position)
+                }
+            }
+            _ => {
+                self.index -= 1;
+                self.unexpected()
+            }
+        }
+    }
+
+    fn parse_variable(&self, name: &str, position: usize) -> Result<Expression> {
+        if let Some(id) = self.attributes.iter().position(|a| a.as_ref() == name) {
+            return Ok(Expression::Attribute(id));
+        }
+
+        match name {
+            "x" => Ok(Expression::X),
+            "y" => Ok(Expression::Y),
+            _ => self.error(format!("Unknown attribute '{}'", name), position),
+        }
+    }
+
+    /// Parses the arguments after the opening parenthesis.
+    fn parse_call(&mut self, name: &str, position: usize) -> Result<Expression> {
+        if name == "noise" {
+            return self.parse_noise(position);
+        }
+
+        let function = match Function::get(name) {
+            Some(function) => function,
+            None => return self.error(format!("Unknown function '{}'", name), position),
+        };
+        let mut arguments = Vec::new();
+
+        if self.peek() != Some(&Symbol(')')) {
+            loop {
+                arguments.push(self.parse_sum()?);
+
+                if self.peek() != Some(&Symbol(',')) {
+                    break;
+                }
+
+                self.index += 1;
+            }
+        }
+
+        self.expect(')')?;
+
+        let number = function.get_number_of_arguments();
+
+        if arguments.len() != number {
+            return self.error(
+                format!(
+                    "Function '{}' needs {} arguments, but got {}",
+                    name,
+                    number,
+                    arguments.len()
+                ),
+                position,
+            );
+        }
+
+        Ok(Expression::Call {
+            function,
+            arguments,
+        })
+    }
+
+    /// Parses `x, y` & the optional `seed=number` & `scale=number`.
+    fn parse_noise(&mut self, position: usize) -> Result<Expression> {
+        let x = self.parse_sum()?;
+        self.expect(',')?;
+        let y = self.parse_sum()?;
+        let mut seed = 0;
+        let mut scale = 1;
+
+        while self.peek() == Some(&Symbol(',')) {
+            self.index += 1;
+
+            match self.next()? {
+                (Name(name), _) if name == "seed" => {
+                    self.expect('=')?;
+                    seed = self.parse_integer()?;
+                }
+                (Name(name), _) if name == "scale" => {
+                    self.expect('=')?;
+                    scale = self.parse_integer()?;
+                }
+                _ => {
+                    self.index -= 1;
+                    return self.unexpected();
+                }
+            }
+        }
+
+        self.expect(')')?;
+
+        let noise = Noise::new(seed, scale, 0, 255).with_context(|| {
+            format!(
+                "Invalid noise at position {} of formula '{}'!",
+                position, self.formula
+            )
+        })?;
+
+        Ok(Expression::Noise {
+            noise,
+            x: Box::new(x),
+            y: Box::new(y),
+        })
+    }
+
+    fn parse_integer(&mut self) -> Result<u32> {
+        match self.tokens.get(self.index) {
+            Some((Number(number), _)) if number.fract() == 0.0 => {
+                self.index += 1;
+                Ok(*number as u32)
+            }
+            Some((_, position)) => self.error("Expected a number".to_string(), *position),
+            None => bail!("Unexpected end of formula '{}'!", self.formula),
+        }
+    }
+}
+
+fn binary(operator: Operator, left: Expression, right: Expression) -> Expression {
+    Expression::Binary {
+        operator,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+fn to_string(token: &Token) -> String {
+    match token {
+        Number(number) => number.to_string(),
+        Name(name) => name.clone(),
+        Symbol(symbol) => symbol.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::expression::Expression::{Attribute, Constant, Y};
+
+    const ATTRIBUTES: [&str; 2] = ["elevation", "x"];
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(
+            parse("1 + 2 * elevation", &ATTRIBUTES).unwrap(),
+            binary(
+                Operator::Add,
+                Constant(1.0),
+                binary(Operator::Mul, Constant(2.0), Attribute(0))
+            )
+        );
+        assert_eq!(
+            parse("(1 + 2) * y", &ATTRIBUTES).unwrap(),
+            binary(
+                Operator::Mul,
+                binary(Operator::Add, Constant(1.0), Constant(2.0)),
+                Y
+            )
+        );
+    }
+
+    #[test]
+    fn test_left_associative() {
+        assert_eq!(
+            parse("8 - 4 - 2", &ATTRIBUTES).unwrap(),
+            binary(
+                Operator::Sub,
+                binary(Operator::Sub, Constant(8.0), Constant(4.0)),
+                Constant(2.0)
+            )
+        );
+    }
+
+    #[test]
+    fn test_attribute_shadows_coordinate() {
+        assert_eq!(parse("x", &ATTRIBUTES).unwrap(), Attribute(1));
+    }
+
+    #[test]
+    fn test_noise() {
+        assert_eq!(
+            parse("noise(y, 2, scale=20, seed=3)", &ATTRIBUTES).unwrap(),
+            Expression::Noise {
+                noise: Noise::new(3, 20, 0, 255).unwrap(),
+                x: Box::new(Y),
+                y: Box::new(Constant(2.0)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for formula in [
+            "",
+            "()",
+            "2 3",
+            "(2 + 3",
+            "abs(2))",
+            "1..2",
+            "clamp(1, 2,)",
+            "noise(x)",
+            "noise(x, y, size=3)",
+            "noise(x, y, scale=0)",
+            "noise(x, y, seed=1.5)",
+        ] {
+            assert!(parse(formula, &ATTRIBUTES).is_err(), "{}", formula);
+        }
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let nested = |open: &str, close: &str, n| format!("{}1{}", open.repeat(n), close.repeat(n));
+
+        assert!(parse(&nested("(", ")", 50), &ATTRIBUTES).is_ok());
+        assert!(parse(&nested("-", "", 50), &ATTRIBUTES).is_ok());
+        assert!(parse(&nested("abs(", ")", 50), &ATTRIBUTES).is_ok());
+        assert!(parse(&nested("(", ")", 100), &ATTRIBUTES).is_err());
+        assert!(parse(&nested("-", "", 100), &ATTRIBUTES).is_err());
+        assert!(parse(&nested("abs(", ")", 100), &ATTRIBUTES).is_err());
+        assert!(parse(&nested("", "+1", 1000), &ATTRIBUTES).is_ok());
+        assert!(parse(&nested("", "*1", 1000), &ATTRIBUTES).is_ok());
+        assert!(parse(&nested("(", "+1)", 100), &ATTRIBUTES).is_err());
+    }
+}
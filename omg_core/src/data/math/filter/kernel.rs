@@ -0,0 +1,72 @@
+use crate::data::math::size2d::Size2d;
+use anyhow::{bail, Result};
+
+/// A user-defined matrix of weights, which is centred on each cell.
+/// The weights are used as they are, so they should sum up to 1 to keep the brightness.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Kernel {
+    size: Size2d,
+    weights: Vec<f32>,
+}
+
+impl Kernel {
+    /// Creates a kernel, unless the width or height is even:
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::kernel::Kernel;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// assert!(Kernel::new(Size2d::unchecked(2, 3), vec![1.0; 6]).is_err());
+    /// assert!(Kernel::new(Size2d::unchecked(3, 2), vec![1.0; 6]).is_err());
+    /// ```
+    ///
+    /// Or the number of weights doesn't match the size:
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::kernel::Kernel;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// assert!(Kernel::new(Size2d::unchecked(3, 3), vec![1.0; 8]).is_err());
+    /// ```
+    pub fn new(size: Size2d, weights: Vec<f32>) -> Result<Kernel> {
+        if size.width().is_multiple_of(2) || size.height().is_multiple_of(2) {
+            bail!(
+                "The kernel's width {} & height {} must be odd!",
+                size.width(),
+                size.height()
+            );
+        } else if size.get_area() != weights.len() {
+            bail!(
+                "The kernel's size {} doesn't match the number of weights {}!",
+                size.get_area(),
+                weights.len()
+            );
+        }
+
+        Ok(Kernel { size, weights })
+    }
+
+    /// Creates a 3x3 kernel, which enhances the differences between neighbouring cells.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::kernel::Kernel;
+    /// let kernel = Kernel::new_sharpen();
+    ///
+    /// assert_eq!(kernel.weights().iter().sum::<f32>(), 1.0);
+    /// ```
+    pub fn new_sharpen() -> Kernel {
+        let weights = vec![0.0, -1.0, 0.0, -1.0, 5.0, -1.0, 0.0, -1.0, 0.0];
+        Kernel::new(Size2d::unchecked(3, 3), weights).unwrap()
+    }
+
+    pub fn size(&self) -> Size2d {
+        self.size
+    }
+
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+
+    /// Returns the distance from the center to the border along both axes.
+    pub fn get_radius(&self) -> [u32; 2] {
+        [self.size.width() / 2, self.size.height() / 2]
+    }
+}
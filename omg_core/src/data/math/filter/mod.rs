@@ -0,0 +1,386 @@
+use crate::data::math::filter::kernel::Kernel;
+use crate::data::math::resampling::scale_length;
+use crate::data::math::size2d::Size2d;
+use Filter::*;
+
+pub mod kernel;
+
+/// Calculates the value of a cell based on the cells around it.
+/// Cells outside the grid use the value of the nearest cell at the edge.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Filter {
+    /// Averages all cells up to a distance along both axes.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::BoxBlur;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(5, 1);
+    ///
+    /// assert_eq!(BoxBlur(1).filter(&vec![0, 0, 90, 0, 0], size), vec![0, 30, 30, 30, 0]);
+    /// ```
+    BoxBlur(u32),
+    /// Averages the cells up to a distance weighted by a gaussian function.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::GaussianBlur;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(5, 1);
+    ///
+    /// assert_eq!(GaussianBlur(1).filter(&vec![0, 0, 100, 0, 0], size), vec![0, 11, 79, 11, 0]);
+    /// ```
+    GaussianBlur(u32),
+    /// Uses the median of all cells up to a distance. Removes outliers, but keeps edges.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::Median;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(5, 1);
+    ///
+    /// assert_eq!(Median(1).filter(&vec![0, 0, 90, 0, 50], size), vec![0, 0, 0, 50, 50]);
+    /// ```
+    Median(u32),
+    /// Uses the maximum of all cells up to a distance.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::Dilate;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(5, 1);
+    ///
+    /// assert_eq!(Dilate(1).filter(&vec![0, 0, 90, 0, 0], size), vec![0, 90, 90, 90, 0]);
+    /// ```
+    Dilate(u32),
+    /// Uses the minimum of all cells up to a distance.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::Erode;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(5, 1);
+    ///
+    /// assert_eq!(Erode(1).filter(&vec![9, 9, 0, 9, 9], size), vec![9, 0, 0, 0, 9]);
+    /// ```
+    Erode(u32),
+    /// Erodes & then dilates. Removes small peaks.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::Open;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(6, 1);
+    ///
+    /// assert_eq!(Open(1).filter(&vec![0, 90, 0, 50, 50, 50], size), vec![0, 0, 0, 50, 50, 50]);
+    /// ```
+    Open(u32),
+    /// Dilates & then erodes. Fills small holes.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::Close;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(6, 1);
+    ///
+    /// assert_eq!(Close(1).filter(&vec![90, 0, 90, 50, 50, 50], size), vec![90, 90, 90, 50, 50, 50]);
+    /// ```
+    Close(u32),
+    /// Applies a user-defined [`Kernel`].
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::Convolve;
+    ///# use omg_core::data::math::filter::kernel::Kernel;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(3, 3);
+    /// let values = vec![10, 10, 10, 10, 20, 10, 10, 10, 10];
+    /// let filter = Convolve(Kernel::new_sharpen());
+    ///
+    /// assert_eq!(filter.filter(&values, size), vec![10, 0, 10, 0, 60, 0, 10, 0, 10]);
+    /// ```
+    Convolve(Kernel),
+}
+
+impl Filter {
+    /// Returns the maximum distance of the cells, which are needed to calculate a cell.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::*;
+    ///# use omg_core::data::math::filter::kernel::Kernel;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let kernel = Kernel::new(Size2d::unchecked(3, 5), vec![1.0; 15]).unwrap();
+    ///
+    /// assert_eq!(BoxBlur(3).get_radius(), 3);
+    /// assert_eq!(Open(3).get_radius(), 6);
+    /// assert_eq!(Convolve(kernel).get_radius(), 2);
+    /// ```
+    pub fn get_radius(&self) -> u32 {
+        match self {
+            BoxBlur(radius) | GaussianBlur(radius) | Median(radius) | Dilate(radius)
+            | Erode(radius) => *radius,
+            Open(radius) | Close(radius) => *radius * 2,
+            Convolve(kernel) => {
+                let [radius_x, radius_y] = kernel.get_radius();
+                radius_x.max(radius_y)
+            }
+        }
+    }
+
+    /// Returns a copy with its radius scaled by a factor. Kernels are kept as they are.
+    ///
+    /// ```
+    ///# use omg_core::data::math::filter::Filter::BoxBlur;
+    /// assert_eq!(BoxBlur(4).resize(0.5), BoxBlur(2));
+    /// ```
+    pub fn resize(&self, factor: f32) -> Filter {
+        let scale = |radius: &u32| scale_length(*radius, factor);
+
+        match self {
+            BoxBlur(radius) => BoxBlur(scale(radius)),
+            GaussianBlur(radius) => GaussianBlur(scale(radius)),
+            Median(radius) => Median(scale(radius)),
+            Dilate(radius) => Dilate(scale(radius)),
+            Erode(radius) => Erode(scale(radius)),
+            Open(radius) => Open(scale(radius)),
+            Close(radius) => Close(scale(radius)),
+            Convolve(kernel) => Convolve(kernel.clone()),
+        }
+    }
+
+    /// Filters a grid of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values doesn't match the size.
+    pub fn filter(&self, values: &[u8], size: Size2d) -> Vec<u8> {
+        assert_eq!(
+            values.len(),
+            size.get_area(),
+            "The number of values doesn't match the size!"
+        );
+
+        match self {
+            BoxBlur(radius) => {
+                let weights = vec![1.0; (*radius * 2 + 1) as usize];
+                convolve_separable(values, size, &weights)
+            }
+            GaussianBlur(radius) => convolve_separable(values, size, &get_gaussian(*radius)),
+            Median(radius) => median(values, size, *radius),
+            Dilate(radius) => morph(values, size, *radius, u8::max),
+            Erode(radius) => morph(values, size, *radius, u8::min),
+            Open(radius) => {
+                let eroded = morph(values, size, *radius, u8::min);
+                morph(&eroded, size, *radius, u8::max)
+            }
+            Close(radius) => {
+                let dilated = morph(values, size, *radius, u8::max);
+                morph(&dilated, size, *radius, u8::min)
+            }
+            Convolve(kernel) => convolve(values, size, kernel),
+        }
+    }
+}
+
+/// Returns the weights of a gaussian function with a standard deviation of half the radius.
+fn get_gaussian(radius: u32) -> Vec<f32> {
+    let sigma = (radius as f32 / 2.0).max(0.5);
+    let radius = radius as i32;
+
+    (-radius..=radius)
+        .map(|x| (-((x * x) as f32) / (2.0 * sigma * sigma)).exp())
+        .collect()
+}
+
+/// Returns the index of a cell, which is clamped to the grid.
+fn clamp(coordinate: i64, length: u32) -> u32 {
+    coordinate.clamp(0, length as i64 - 1) as u32
+}
+
+/// Applies the normalised weights first along the x-axis & then along the y-axis.
+/// The intermediate values aren't rounded.
+fn convolve_separable(values: &[u8], size: Size2d, weights: &[f32]) -> Vec<u8> {
+    let sum: f32 = weights.iter().sum();
+    let weights: Vec<f32> = weights.iter().map(|w| w / sum).collect();
+    let radius = (weights.len() / 2) as i64;
+    let mut rows = Vec::with_capacity(values.len());
+
+    for y in 0..size.height() {
+        for x in 0..size.width() {
+            let value: f32 = weights
+                .iter()
+                .enumerate()
+                .map(|(i, weight)| {
+                    let source_x = clamp(x as i64 + i as i64 - radius, size.width());
+                    values[size.to_index_risky(source_x, y)] as f32 * weight
+                })
+                .sum();
+            rows.push(value);
+        }
+    }
+
+    let mut result = Vec::with_capacity(values.len());
+
+    for y in 0..size.height() {
+        for x in 0..size.width() {
+            let value: f32 = weights
+                .iter()
+                .enumerate()
+                .map(|(i, weight)| {
+                    let source_y = clamp(y as i64 + i as i64 - radius, size.height());
+                    rows[size.to_index_risky(x, source_y)] * weight
+                })
+                .sum();
+            result.push(to_u8(value));
+        }
+    }
+
+    result
+}
+
+/// Combines all cells up to a distance first along the x-axis & then along the y-axis.
+fn morph(values: &[u8], size: Size2d, radius: u32, combine: fn(u8, u8) -> u8) -> Vec<u8> {
+    let radius = radius as i64;
+    let combine_line = |get: &dyn Fn(i64) -> u8, center: i64| {
+        (center - radius..=center + radius)
+            .map(get)
+            .reduce(combine)
+            .unwrap()
+    };
+    let mut rows = Vec::with_capacity(values.len());
+
+    for y in 0..size.height() {
+        let get = |x: i64| values[size.to_index_risky(clamp(x, size.width()), y)];
+
+        for x in 0..size.width() {
+            rows.push(combine_line(&get, x as i64));
+        }
+    }
+
+    let mut result = vec![0; values.len()];
+
+    for x in 0..size.width() {
+        let get = |y: i64| rows[size.to_index_risky(x, clamp(y, size.height()))];
+
+        for y in 0..size.height() {
+            result[size.to_index_risky(x, y)] = combine_line(&get, y as i64);
+        }
+    }
+
+    result
+}
+
+/// Uses a histogram, which is updated while the window moves along each row.
+fn median(values: &[u8], size: Size2d, radius: u32) -> Vec<u8> {
+    let radius = radius as i64;
+    let window = (radius * 2 + 1) as u32;
+    let half = window * window / 2;
+    let mut result = Vec::with_capacity(values.len());
+
+    for y in 0..size.height() as i64 {
+        let mut histogram = [0u32; 256];
+        let update_column = |histogram: &mut [u32; 256], x: i64, change: i32| {
+            for source_y in y - radius..=y + radius {
+                let source_x = clamp(x, size.width());
+                let source_y = clamp(source_y, size.height());
+                let value = values[size.to_index_risky(source_x, source_y)] as usize;
+                histogram[value] = histogram[value].wrapping_add_signed(change);
+            }
+        };
+
+        for x in -radius..=radius {
+            update_column(&mut histogram, x, 1);
+        }
+
+        for x in 0..size.width() as i64 {
+            if x > 0 {
+                update_column(&mut histogram, x - radius - 1, -1);
+                update_column(&mut histogram, x + radius, 1);
+            }
+
+            let mut count = 0;
+            let median = histogram
+                .iter()
+                .position(|n| {
+                    count += n;
+                    count > half
+                })
+                .unwrap();
+            result.push(median as u8);
+        }
+    }
+
+    result
+}
+
+/// Applies a [`Kernel`] directly, which is slower for large kernels.
+fn convolve(values: &[u8], size: Size2d, kernel: &Kernel) -> Vec<u8> {
+    let [radius_x, radius_y] = kernel.get_radius();
+    let kernel_size = kernel.size();
+    let mut result = Vec::with_capacity(values.len());
+
+    for y in 0..size.height() {
+        for x in 0..size.width() {
+            let mut sum = 0.0;
+
+            for kernel_y in 0..kernel_size.height() {
+                let source_y = clamp(y as i64 + kernel_y as i64 - radius_y as i64, size.height());
+
+                for kernel_x in 0..kernel_size.width() {
+                    let source_x =
+                        clamp(x as i64 + kernel_x as i64 - radius_x as i64, size.width());
+                    let weight = kernel.weights()[kernel_size.to_index_risky(kernel_x, kernel_y)];
+                    sum += values[size.to_index_risky(source_x, source_y)] as f32 * weight;
+                }
+            }
+
+            result.push(to_u8(sum));
+        }
+    }
+
+    result
+}
+
+fn to_u8(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blur_2d() {
+        let size = Size2d::unchecked(3, 3);
+        let values = vec![0, 0, 0, 0, 90, 0, 0, 0, 0];
+
+        assert_eq!(BoxBlur(1).filter(&values, size), vec![10; 9]);
+    }
+
+    #[test]
+    fn test_median_matches_sorting() {
+        let size = Size2d::unchecked(7, 5);
+        let values: Vec<u8> = (0..35u32).map(|i| (i * 37 % 101) as u8).collect();
+        let result = Median(2).filter(&values, size);
+
+        for y in 0..5i64 {
+            for x in 0..7i64 {
+                let mut window = Vec::new();
+
+                for source_y in y - 2..=y + 2 {
+                    for source_x in x - 2..=x + 2 {
+                        let source_x = clamp(source_x, 7);
+                        let source_y = clamp(source_y, 5);
+                        window.push(values[size.to_index_risky(source_x, source_y)]);
+                    }
+                }
+
+                window.sort_unstable();
+                let index = size.to_index_risky(x as u32, y as u32);
+                assert_eq!(result[index], window[12]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_radius_0_keeps_values() {
+        let size = Size2d::unchecked(3, 2);
+        let values = vec![1, 2, 3, 4, 5, 6];
+
+        for filter in [BoxBlur(0), GaussianBlur(0), Median(0), Dilate(0), Open(0)] {
+            assert_eq!(filter.filter(&values, size), values);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::data::math::random::random_factor;
+use crate::data::math::region::Region;
+use crate::data::math::resampling::scale_length;
+use crate::data::math::size2d::Size2d;
+use anyhow::{bail, Result};
+
+/// A number of circles with random positions & radii inside a region. E.g. an island chain.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Blobs {
+    seed: u32,
+    number: u32,
+    region: Region,
+    min_radius: u32,
+    max_radius: u32,
+    circles: Vec<[f32; 3]>,
+}
+
+impl Blobs {
+    /// Distributes the blobs, unless their number is 0:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::blobs::Blobs;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(10, 20, Size2d::unchecked(100, 50));
+    ///
+    /// assert!(Blobs::new(42, 0, region, 5, 10).is_err());
+    /// ```
+    ///
+    /// Or the minimum radius is greater than the maximum:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::blobs::Blobs;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(10, 20, Size2d::unchecked(100, 50));
+    ///
+    /// assert!(Blobs::new(42, 3, region, 11, 10).is_err());
+    /// ```
+    pub fn new(
+        seed: u32,
+        number: u32,
+        region: Region,
+        min_radius: u32,
+        max_radius: u32,
+    ) -> Result<Blobs> {
+        if number == 0 {
+            bail!("The number of blobs must be greater 0!");
+        } else if min_radius > max_radius {
+            bail!(
+                "The blobs' minimum radius {} is above its maximum {}!",
+                min_radius,
+                max_radius
+            );
+        }
+
+        let circles = (0..number)
+            .map(|index| {
+                let x = region.x() as f32
+                    + random_factor(seed, index, 0) * region.size().width() as f32;
+                let y = region.y() as f32
+                    + random_factor(seed, index, 1) * region.size().height() as f32;
+                let radius = min_radius as f32
+                    + random_factor(seed, index, 2) * (max_radius - min_radius) as f32;
+                [x, y, radius]
+            })
+            .collect();
+
+        Ok(Blobs {
+            seed,
+            number,
+            region,
+            min_radius,
+            max_radius,
+            circles,
+        })
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn region(&self) -> &Region {
+        &self.region
+    }
+
+    pub fn min_radius(&self) -> u32 {
+        self.min_radius
+    }
+
+    pub fn max_radius(&self) -> u32 {
+        self.max_radius
+    }
+
+    /// Returns the distance of a point to the nearest blob. Is 0 inside a blob.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::blobs::Blobs;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(10, 20, Size2d::unchecked(1, 1));
+    /// let blobs = Blobs::new(42, 3, region, 5, 5).unwrap();
+    ///
+    /// assert_eq!(blobs.get_distance(10, 20), 0.0);
+    /// assert!(blobs.get_distance(10, 30) > 4.0);
+    /// assert!(blobs.get_distance(10, 30) < 6.0);
+    /// ```
+    pub fn get_distance(&self, x: u32, y: u32) -> f32 {
+        self.circles
+            .iter()
+            .map(|[center_x, center_y, radius]| {
+                let diff_x = x as f32 - center_x;
+                let diff_y = y as f32 - center_y;
+                ((diff_x * diff_x + diff_y * diff_y).sqrt() - radius).max(0.0)
+            })
+            .fold(f32::MAX, f32::min)
+    }
+
+    /// Returns a copy with its region & radii scaled by a factor per axis.
+    /// The radii use the average of both factors.
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> Blobs {
+        let factor = (factor_x + factor_y) / 2.0;
+        let size = self.region.size();
+        let region = Region::new(
+            scale_length(self.region.x(), factor_x),
+            scale_length(self.region.y(), factor_y),
+            Size2d::unchecked(
+                scale_length(size.width(), factor_x).max(1),
+                scale_length(size.height(), factor_y).max(1),
+            ),
+        );
+
+        Blobs::new(
+            self.seed,
+            self.number,
+            region,
+            scale_length(self.min_radius, factor),
+            scale_length(self.max_radius, factor),
+        )
+        .unwrap()
+    }
+}
@@ -1,6 +1,7 @@
 use crate::data::math::generator::gradient::Gradient;
 use crate::data::math::generator::noise::Noise;
 use crate::data::math::interpolation::vector::VectorInterpolator;
+use crate::data::math::resampling::scale_length;
 use Generator1d::*;
 
 #[svgbobdoc::transform]
@@ -147,6 +148,42 @@ impl Generator1d {
             Noise1d(noise) => noise.generate1d(input),
         }
     }
+
+    /// Returns a copy, whose input is scaled by a factor.
+    /// Used to generate the same values for a map with a different resolution.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::gradient::Gradient;
+    /// let generator = Generator1d::InputAsOutput.resize(2.0);
+    ///
+    /// assert_eq!(generator.generate(0), 0);
+    /// assert_eq!(generator.generate(100), 50);
+    /// assert_eq!(generator.generate(510), 255);
+    /// ```
+    pub fn resize(&self, factor: f32) -> Generator1d {
+        match self {
+            AbsoluteGradient(gradient) => AbsoluteGradient(gradient.resize(factor)),
+            Gradient(gradient) => Gradient(gradient.resize(factor)),
+            InputAsOutput => {
+                if factor == 1.0 {
+                    return InputAsOutput;
+                }
+
+                let length = scale_length(u8::MAX as u32, factor).max(1);
+                Gradient(Gradient::new(0, length, 0, u8::MAX).unwrap())
+            }
+            InterpolateVector(interpolator) => {
+                let vector = interpolator
+                    .get_all()
+                    .iter()
+                    .map(|entry| (scale_length(entry.threshold(), factor), entry.value()))
+                    .collect();
+                InterpolateVector(VectorInterpolator::new(vector).unwrap())
+            }
+            Noise1d(noise) => Noise1d(noise.resize(factor)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -158,4 +195,14 @@ mod tests {
         assert_eq!(InputAsOutput.generate(300), 255);
         assert_eq!(InputAsOutput.generate(u32::MAX), 255);
     }
+
+    #[test]
+    fn test_resize_vector() {
+        let vector = vec![(100, 0), (200, 100)];
+        let generator = InterpolateVector(VectorInterpolator::new(vector).unwrap());
+        let vector = vec![(50, 0), (100, 100)];
+        let result = InterpolateVector(VectorInterpolator::new(vector).unwrap());
+
+        assert_eq!(generator.resize(0.5), result);
+    }
 }
@@ -1,7 +1,12 @@
 use crate::data::math::distance::calculate_distance;
+use crate::data::math::generator::blobs::Blobs;
 use crate::data::math::generator::generator1d::Generator1d;
 use crate::data::math::generator::noise::Noise;
+use crate::data::math::generator::shape::Shape;
+use crate::data::math::generator::voronoi::Voronoi;
+use crate::data::math::resampling::scale_length;
 use crate::data::math::size2d::Size2d;
+use anyhow::{bail, Result};
 use Generator2d::*;
 
 #[svgbobdoc::transform]
@@ -80,6 +85,231 @@ pub enum Generator2d {
     IndexGenerator(Size2d),
     /// Generates 2d noise.
     Noise2d(Noise),
+    /// Feeds the distance to the border of a [`Shape`] to a [`Generator1d`].
+    /// The distance is 0 inside the shape.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    ///# use omg_core::data::math::generator::shape::Shape;
+    /// let shape = Shape::new_rectangle(10, 20, 8, 4).unwrap();
+    /// let generator = Generator2d::new_apply_to_shape(Generator1d::InputAsOutput, shape);
+    ///
+    /// assert_eq!(generator.generate(14, 22), 0);
+    /// assert_eq!(generator.generate(14, 27), 3);
+    /// assert_eq!(generator.generate(21, 28), 5);
+    /// ```
+    ApplyToShape {
+        generator: Generator1d,
+        shape: Shape,
+    },
+    /// Generates the random id of each [`Voronoi`] cell.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator2d::Generator2d::VoronoiCells;
+    ///# use omg_core::data::math::generator::voronoi::Voronoi;
+    /// let voronoi = Voronoi::new(42, 10).unwrap();
+    /// let generator = VoronoiCells(voronoi);
+    ///
+    /// assert_eq!(generator.generate(5, 5), voronoi.get_cell_id(5, 5));
+    /// ```
+    VoronoiCells(Voronoi),
+    /// Feeds the distance to the border of the [`Voronoi`] cells to a [`Generator1d`].
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d::VoronoiBorder;
+    ///# use omg_core::data::math::generator::voronoi::Voronoi;
+    /// let voronoi = Voronoi::new(42, 10).unwrap();
+    /// let generator = VoronoiBorder { generator: Generator1d::InputAsOutput, voronoi };
+    /// let distance = voronoi.get_distance_to_border(5, 5).round() as u8;
+    ///
+    /// assert_eq!(generator.generate(5, 5), distance);
+    /// ```
+    VoronoiBorder {
+        generator: Generator1d,
+        voronoi: Voronoi,
+    },
+    /// Feeds the distance to the nearest of the [`Blobs`] to a [`Generator1d`].
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::blobs::Blobs;
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d::RandomBlobs;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(10, 20, Size2d::unchecked(1, 1));
+    /// let blobs = Blobs::new(42, 3, region, 5, 5).unwrap();
+    /// let generator = RandomBlobs { generator: Generator1d::InputAsOutput, blobs };
+    ///
+    /// assert_eq!(generator.generate(10, 20), 0);
+    /// assert!(generator.generate(10, 40) >= 14);
+    /// assert!(generator.generate(10, 40) <= 15);
+    /// ```
+    RandomBlobs {
+        generator: Generator1d,
+        blobs: Blobs,
+    },
+    /// Adds the values of other generators.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let y = Generator2d::new_apply_to_y(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_sum(vec![x, y]).unwrap();
+    ///
+    /// assert_eq!(generator.generate(3, 4), 7);
+    /// assert_eq!(generator.generate(200, 100), 255);
+    /// ```
+    Sum(Vec<Generator2d>),
+    /// Multiplies the values of other generators as factors in the range [0,1].
+    /// Useful to mask a generator with another one.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let y = Generator2d::new_apply_to_y(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_product(vec![x, y]).unwrap();
+    ///
+    /// assert_eq!(generator.generate(100, 255), 100);
+    /// assert_eq!(generator.generate(100, 51), 20);
+    /// assert_eq!(generator.generate(100, 0), 0);
+    /// ```
+    Product(Vec<Generator2d>),
+    /// Returns the minimum value of other generators.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let y = Generator2d::new_apply_to_y(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_min(vec![x, y]).unwrap();
+    ///
+    /// assert_eq!(generator.generate(3, 4), 3);
+    /// assert_eq!(generator.generate(5, 4), 4);
+    /// ```
+    Min(Vec<Generator2d>),
+    /// Returns the maximum value of other generators.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let y = Generator2d::new_apply_to_y(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_max(vec![x, y]).unwrap();
+    ///
+    /// assert_eq!(generator.generate(3, 4), 4);
+    /// assert_eq!(generator.generate(5, 4), 5);
+    /// ```
+    Max(Vec<Generator2d>),
+    /// Interpolates linearly between 2 generators.
+    /// The value of the 3.generator is the factor in the range [0,1].
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let start = Generator2d::new_offset(Generator2d::new_apply_to_x(Generator1d::InputAsOutput), 100.0);
+    /// let end = Generator2d::new_offset(Generator2d::new_apply_to_x(Generator1d::InputAsOutput), 200.0);
+    /// let factor = Generator2d::new_apply_to_y(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_lerp(start, end, factor);
+    ///
+    /// assert_eq!(generator.generate(0, 0), 100);
+    /// assert_eq!(generator.generate(0, 51), 120);
+    /// assert_eq!(generator.generate(1, 255), 201);
+    /// ```
+    Lerp {
+        start: Box<Generator2d>,
+        end: Box<Generator2d>,
+        factor: Box<Generator2d>,
+    },
+    /// Multiplies the value of another generator by a factor.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_scale(x, 1.5);
+    ///
+    /// assert_eq!(generator.generate(3, 0), 5);
+    /// assert_eq!(generator.generate(200, 0), 255);
+    /// ```
+    Scale {
+        generator: Box<Generator2d>,
+        factor: f32,
+    },
+    /// Adds an offset to the value of another generator.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_offset(x, -10.0);
+    ///
+    /// assert_eq!(generator.generate(3, 0), 0);
+    /// assert_eq!(generator.generate(30, 0), 20);
+    /// ```
+    Offset {
+        generator: Box<Generator2d>,
+        offset: f32,
+    },
+    /// Limits the value of another generator to a range.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_clamp(x, 10, 20).unwrap();
+    ///
+    /// assert_eq!(generator.generate(3, 0), 10);
+    /// assert_eq!(generator.generate(15, 0), 15);
+    /// assert_eq!(generator.generate(30, 0), 20);
+    /// ```
+    Clamp {
+        generator: Box<Generator2d>,
+        min: u8,
+        max: u8,
+    },
+    /// Inverts the value of another generator.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_invert(x);
+    ///
+    /// assert_eq!(generator.generate(0, 0), 255);
+    /// assert_eq!(generator.generate(55, 0), 200);
+    /// ```
+    Invert(Box<Generator2d>),
+    /// Offsets the input coordinates of a generator by the values of 2 other generators.
+    /// Their values are mapped to signed offsets in the range [-max_offset, max_offset] per axis,
+    /// with 0 for a value of 127.5. Coordinates below 0 are clamped to 0.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let y = Generator2d::new_apply_to_y(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_warp(x.clone(), y.clone(), x.clone(), 10, 20);
+    ///
+    /// assert_eq!(generator.generate(100, 0), 90);
+    /// assert_eq!(generator.generate(100, 255), 110);
+    /// assert_eq!(generator.generate(5, 0), 0);
+    ///
+    /// // The offsets are scaled like the coordinates of their axis.
+    /// let (x, y) = (x.resize(2.0, 0.5), y.resize(2.0, 0.5));
+    /// let resized = Generator2d::new_warp(x.clone(), y, x, 20, 10);
+    /// assert_eq!(generator.resize(2.0, 0.5), resized);
+    /// ```
+    Warp {
+        generator: Box<Generator2d>,
+        warp_x: Box<Generator2d>,
+        warp_y: Box<Generator2d>,
+        max_offset_x: u32,
+        max_offset_y: u32,
+    },
 }
 
 impl Generator2d {
@@ -99,21 +329,312 @@ impl Generator2d {
         }
     }
 
+    pub fn new_apply_to_shape(generator: Generator1d, shape: Shape) -> Generator2d {
+        ApplyToShape { generator, shape }
+    }
+
+    /// Creates a sum, unless there are no generators:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// assert!(Generator2d::new_sum(Vec::new()).is_err());
+    /// ```
+    pub fn new_sum(generators: Vec<Generator2d>) -> Result<Generator2d> {
+        validate_generators(&generators, "sum")?;
+        Ok(Sum(generators))
+    }
+
+    /// Creates a product, unless there are no generators:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// assert!(Generator2d::new_product(Vec::new()).is_err());
+    /// ```
+    pub fn new_product(generators: Vec<Generator2d>) -> Result<Generator2d> {
+        validate_generators(&generators, "product")?;
+        Ok(Product(generators))
+    }
+
+    /// Creates a minimum, unless there are no generators:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// assert!(Generator2d::new_min(Vec::new()).is_err());
+    /// ```
+    pub fn new_min(generators: Vec<Generator2d>) -> Result<Generator2d> {
+        validate_generators(&generators, "minimum")?;
+        Ok(Min(generators))
+    }
+
+    /// Creates a maximum, unless there are no generators:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// assert!(Generator2d::new_max(Vec::new()).is_err());
+    /// ```
+    pub fn new_max(generators: Vec<Generator2d>) -> Result<Generator2d> {
+        validate_generators(&generators, "maximum")?;
+        Ok(Max(generators))
+    }
+
+    pub fn new_lerp(start: Generator2d, end: Generator2d, factor: Generator2d) -> Generator2d {
+        Lerp {
+            start: Box::new(start),
+            end: Box::new(end),
+            factor: Box::new(factor),
+        }
+    }
+
+    pub fn new_scale(generator: Generator2d, factor: f32) -> Generator2d {
+        Scale {
+            generator: Box::new(generator),
+            factor,
+        }
+    }
+
+    pub fn new_offset(generator: Generator2d, offset: f32) -> Generator2d {
+        Offset {
+            generator: Box::new(generator),
+            offset,
+        }
+    }
+
+    /// Creates a clamp, unless the minimum is greater than the maximum:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    ///
+    /// assert!(Generator2d::new_clamp(x, 20, 10).is_err());
+    /// ```
+    pub fn new_clamp(generator: Generator2d, min: u8, max: u8) -> Result<Generator2d> {
+        if min > max {
+            bail!("The clamp's minimum {} is above its maximum {}!", min, max);
+        }
+
+        Ok(Clamp {
+            generator: Box::new(generator),
+            min,
+            max,
+        })
+    }
+
+    pub fn new_invert(generator: Generator2d) -> Generator2d {
+        Invert(Box::new(generator))
+    }
+
+    pub fn new_warp(
+        generator: Generator2d,
+        warp_x: Generator2d,
+        warp_y: Generator2d,
+        max_offset_x: u32,
+        max_offset_y: u32,
+    ) -> Generator2d {
+        Warp {
+            generator: Box::new(generator),
+            warp_x: Box::new(warp_x),
+            warp_y: Box::new(warp_y),
+            max_offset_x,
+            max_offset_y,
+        }
+    }
+
     /// Generates a value for a 2d point (x,y).
     pub fn generate(&self, x: u32, y: u32) -> u8 {
+        self.evaluate(x, y).clamp(0.0, 255.0).round() as u8
+    }
+
+    /// Generates an unclamped value for a 2d point (x,y).
+    /// Composite generators use it to avoid rounding & saturation between their parts.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    /// let x = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    /// let generator = Generator2d::new_offset(Generator2d::new_scale(x, 2.0), -100.0);
+    ///
+    /// assert_eq!(generator.evaluate(200, 0), 300.0);
+    /// assert_eq!(generator.evaluate(10, 0), -80.0);
+    /// ```
+    pub fn evaluate(&self, x: u32, y: u32) -> f32 {
         match self {
-            ApplyToX(generator) => generator.generate(x),
-            ApplyToY(generator) => generator.generate(y),
+            ApplyToX(generator) => generator.generate(x) as f32,
+            ApplyToY(generator) => generator.generate(y) as f32,
             ApplyToDistance {
                 generator,
                 center_x,
                 center_y,
             } => {
                 let distance = calculate_distance(*center_x, *center_y, x, y);
-                generator.generate(distance)
+                generator.generate(distance) as f32
+            }
+            IndexGenerator(size) => size.saturating_to_index(x, y) as u8 as f32,
+            Noise2d(noise) => noise.generate2d(x, y) as f32,
+            ApplyToShape { generator, shape } => {
+                let distance = shape.signed_distance(x as f32, y as f32).max(0.0);
+                generator.generate(distance.round() as u32) as f32
             }
-            IndexGenerator(size) => size.saturating_to_index(x, y) as u8,
-            Noise2d(noise) => noise.generate2d(x, y),
+            VoronoiCells(voronoi) => voronoi.get_cell_id(x, y) as f32,
+            VoronoiBorder { generator, voronoi } => {
+                let distance = voronoi.get_distance_to_border(x, y);
+                generator.generate(distance.round() as u32) as f32
+            }
+            RandomBlobs { generator, blobs } => {
+                generator.generate(blobs.get_distance(x, y).round() as u32) as f32
+            }
+            Sum(generators) => generators.iter().map(|g| g.evaluate(x, y)).sum(),
+            Product(generators) => {
+                generators
+                    .iter()
+                    .map(|g| g.evaluate(x, y) / 255.0)
+                    .product::<f32>()
+                    * 255.0
+            }
+            Min(generators) => generators
+                .iter()
+                .map(|g| g.evaluate(x, y))
+                .fold(f32::MAX, f32::min),
+            Max(generators) => generators
+                .iter()
+                .map(|g| g.evaluate(x, y))
+                .fold(f32::MIN, f32::max),
+            Lerp { start, end, factor } => {
+                let start = start.evaluate(x, y);
+                let end = end.evaluate(x, y);
+                let factor = factor.evaluate(x, y) / 255.0;
+                start + (end - start) * factor
+            }
+            Scale { generator, factor } => generator.evaluate(x, y) * factor,
+            Offset { generator, offset } => generator.evaluate(x, y) + offset,
+            Clamp {
+                generator,
+                min,
+                max,
+            } => generator.evaluate(x, y).clamp(*min as f32, *max as f32),
+            Invert(generator) => 255.0 - generator.evaluate(x, y),
+            Warp {
+                generator,
+                warp_x,
+                warp_y,
+                max_offset_x,
+                max_offset_y,
+            } => {
+                let offset_x = to_signed(warp_x.evaluate(x, y)) * *max_offset_x as f32;
+                let offset_y = to_signed(warp_y.evaluate(x, y)) * *max_offset_y as f32;
+                let x = (x as f32 + offset_x).round().max(0.0) as u32;
+                let y = (y as f32 + offset_y).round().max(0.0) as u32;
+                generator.evaluate(x, y)
+            }
+        }
+    }
+
+    /// Returns a copy, whose inputs are scaled by a factor per axis.
+    /// Used to generate the same values for a map with a different resolution.
+    ///
+    /// Distances, cells & noise use the average of both factors.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    ///# use omg_core::data::math::generator::gradient::Gradient;
+    /// let gradient = Generator1d::Gradient(Gradient::new(10, 100, 0, 200).unwrap());
+    /// let generator = Generator2d::new_apply_to_distance(gradient, 20, 30);
+    ///
+    /// let gradient = Generator1d::Gradient(Gradient::new(30, 300, 0, 200).unwrap());
+    /// let result = Generator2d::new_apply_to_distance(gradient, 40, 120);
+    /// assert_eq!(generator.resize(2.0, 4.0), result);
+    /// ```
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> Generator2d {
+        let factor = (factor_x + factor_y) / 2.0;
+
+        match self {
+            ApplyToX(generator) => ApplyToX(generator.resize(factor_x)),
+            ApplyToY(generator) => ApplyToY(generator.resize(factor_y)),
+            ApplyToDistance {
+                generator,
+                center_x,
+                center_y,
+            } => ApplyToDistance {
+                generator: generator.resize(factor),
+                center_x: scale_length(*center_x, factor_x),
+                center_y: scale_length(*center_y, factor_y),
+            },
+            IndexGenerator(size) => IndexGenerator(*size),
+            Noise2d(noise) => Noise2d(noise.resize(factor)),
+            ApplyToShape { generator, shape } => ApplyToShape {
+                generator: generator.resize(factor),
+                shape: shape.resize(factor_x, factor_y),
+            },
+            VoronoiCells(voronoi) => VoronoiCells(voronoi.resize(factor)),
+            VoronoiBorder { generator, voronoi } => VoronoiBorder {
+                generator: generator.resize(factor),
+                voronoi: voronoi.resize(factor),
+            },
+            RandomBlobs { generator, blobs } => RandomBlobs {
+                generator: generator.resize(factor),
+                blobs: blobs.resize(factor_x, factor_y),
+            },
+            Sum(generators) => Sum(resize_all(generators, factor_x, factor_y)),
+            Product(generators) => Product(resize_all(generators, factor_x, factor_y)),
+            Min(generators) => Min(resize_all(generators, factor_x, factor_y)),
+            Max(generators) => Max(resize_all(generators, factor_x, factor_y)),
+            Lerp { start, end, factor } => Lerp {
+                start: Box::new(start.resize(factor_x, factor_y)),
+                end: Box::new(end.resize(factor_x, factor_y)),
+                factor: Box::new(factor.resize(factor_x, factor_y)),
+            },
+            Scale { generator, factor } => Scale {
+                generator: Box::new(generator.resize(factor_x, factor_y)),
+                factor: *factor,
+            },
+            Offset { generator, offset } => Offset {
+                generator: Box::new(generator.resize(factor_x, factor_y)),
+                offset: *offset,
+            },
+            Clamp {
+                generator,
+                min,
+                max,
+            } => Clamp {
+                generator: Box::new(generator.resize(factor_x, factor_y)),
+                min: *min,
+                max: *max,
+            },
+            Invert(generator) => Invert(Box::new(generator.resize(factor_x, factor_y))),
+            Warp {
+                generator,
+                warp_x,
+                warp_y,
+                max_offset_x,
+                max_offset_y,
+            } => Warp {
+                generator: Box::new(generator.resize(factor_x, factor_y)),
+                warp_x: Box::new(warp_x.resize(factor_x, factor_y)),
+                warp_y: Box::new(warp_y.resize(factor_x, factor_y)),
+                max_offset_x: scale_length(*max_offset_x, factor_x),
+                max_offset_y: scale_length(*max_offset_y, factor_y),
+            },
         }
     }
 }
+
+fn validate_generators(generators: &[Generator2d], name: &str) -> Result<()> {
+    if generators.is_empty() {
+        bail!("The {} needs at least 1 generator!", name);
+    }
+
+    Ok(())
+}
+
+/// Maps a value in the range [0,255] to the range [-1,1].
+fn to_signed(value: f32) -> f32 {
+    (value / 127.5 - 1.0).clamp(-1.0, 1.0)
+}
+
+fn resize_all(generators: &[Generator2d], factor_x: f32, factor_y: f32) -> Vec<Generator2d> {
+    generators
+        .iter()
+        .map(|generator| generator.resize(factor_x, factor_y))
+        .collect()
+}
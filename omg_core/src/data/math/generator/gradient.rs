@@ -1,4 +1,5 @@
 use crate::data::math::interpolation::lerp;
+use crate::data::math::resampling::scale_length;
 use anyhow::{bail, Result};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -54,6 +55,25 @@ impl Gradient {
         self.value_end
     }
 
+    /// Returns a copy with its start & length scaled by a factor.
+    /// Used to generate the same gradient for a map with a different resolution.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::gradient::Gradient;
+    /// let gradient = Gradient::new(100, 50, 0, 200).unwrap();
+    ///
+    /// assert_eq!(gradient.resize(2.0), Gradient::new(200, 100, 0, 200).unwrap());
+    /// assert_eq!(gradient.resize(0.001), Gradient::new(0, 1, 0, 200).unwrap());
+    /// ```
+    pub fn resize(&self, factor: f32) -> Gradient {
+        Gradient {
+            start: scale_length(self.start, factor),
+            length: scale_length(self.length, factor).max(1),
+            value_start: self.value_start,
+            value_end: self.value_end,
+        }
+    }
+
     /// Generates the gradient.
     pub fn generate(&self, input: u32) -> u8 {
         if input <= self.start {
@@ -1,4 +1,7 @@
+pub mod blobs;
 pub mod generator1d;
 pub mod generator2d;
 pub mod gradient;
 pub mod noise;
+pub mod shape;
+pub mod voronoi;
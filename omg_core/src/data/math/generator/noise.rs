@@ -1,3 +1,4 @@
+use crate::data::math::resampling::scale_length;
 use anyhow::{bail, Result};
 use noise::{NoiseFn, Seedable, SuperSimplex};
 
@@ -72,6 +73,30 @@ impl Noise {
         self.max_value
     }
 
+    /// Returns a copy with its scale multiplied by a factor.
+    /// Used to generate the same noise for a map with a different resolution.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::noise::Noise;
+    /// let noise = Noise::new(1, 20, 0, 200).unwrap();
+    ///
+    /// assert_eq!(noise.resize(4.0), Noise::new(1, 80, 0, 200).unwrap());
+    /// assert_eq!(noise.resize(0.01), Noise::new(1, 1, 0, 200).unwrap());
+    /// ```
+    pub fn resize(&self, factor: f32) -> Noise {
+        let scale = scale_length(self.scale, factor).max(1);
+
+        Noise {
+            algo: self.algo.clone(),
+            scale,
+            scale_f64: scale as f64,
+            min_value: self.min_value,
+            max_value: self.max_value,
+            base: self.base,
+            factor: self.factor,
+        }
+    }
+
     /// Generates noise for an input.
     pub fn generate1d(&self, input: u32) -> u8 {
         let input = input as f64 / self.scale_f64;
@@ -85,6 +110,24 @@ impl Noise {
         self.transform(self.algo.get([x, y]))
     }
 
+    /// Generates noise between 0 & 1 for a point with fractional coordinates.
+    /// Ignores the minimum & maximum.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::noise::Noise;
+    /// let noise = Noise::new(3, 10, 0, 255).unwrap();
+    ///
+    /// for i in 0..100 {
+    ///     let value = noise.generate_normalized(i as f32 * 0.7, i as f32 * 1.3);
+    ///     assert!((0.0..=1.0).contains(&value));
+    /// }
+    /// ```
+    pub fn generate_normalized(&self, x: f32, y: f32) -> f32 {
+        let x = x as f64 / self.scale_f64;
+        let y = y as f64 / self.scale_f64;
+        ((self.algo.get([x, y]) + 1.0) / 2.0).clamp(0.0, 1.0) as f32
+    }
+
     /// Transforms the generated value from [-1,+1] to [min_value,max_value].
     fn transform(&self, value: f64) -> u8 {
         let positive_value = value + self.base;
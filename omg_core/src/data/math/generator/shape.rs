@@ -0,0 +1,317 @@
+use crate::data::math::resampling::scale_length;
+use anyhow::{bail, Result};
+use Shape::*;
+
+/// A geometric shape, which defines the signed distance of each point to its border.
+/// The distance is negative inside the shape.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Shape {
+    /// A circle around a center.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape::Circle;
+    /// let circle = Circle { center_x: 10, center_y: 20, radius: 5 };
+    ///
+    /// assert_eq!(circle.signed_distance(10.0, 20.0), -5.0);
+    /// assert_eq!(circle.signed_distance(13.0, 24.0), 0.0);
+    /// assert_eq!(circle.signed_distance(10.0, 28.0), 3.0);
+    /// ```
+    Circle {
+        center_x: u32,
+        center_y: u32,
+        radius: u32,
+    },
+    /// An axis aligned ellipse around a center. The distance is approximated.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape;
+    /// let ellipse = Shape::new_ellipse(10, 20, 8, 4).unwrap();
+    ///
+    /// assert_eq!(ellipse.signed_distance(10.0, 20.0), -4.0);
+    /// assert_eq!(ellipse.signed_distance(18.0, 20.0), 0.0);
+    /// assert_eq!(ellipse.signed_distance(10.0, 16.0), 0.0);
+    /// assert_eq!(ellipse.signed_distance(10.0, 26.0), 2.0);
+    /// ```
+    Ellipse {
+        center_x: u32,
+        center_y: u32,
+        radius_x: u32,
+        radius_y: u32,
+    },
+    /// An axis aligned rectangle with its top left corner at (x,y).
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape;
+    /// let rectangle = Shape::new_rectangle(10, 20, 8, 4).unwrap();
+    ///
+    /// assert_eq!(rectangle.signed_distance(14.0, 22.0), -2.0);
+    /// assert_eq!(rectangle.signed_distance(10.0, 22.0), 0.0);
+    /// assert_eq!(rectangle.signed_distance(14.0, 27.0), 3.0);
+    /// assert_eq!(rectangle.signed_distance(21.0, 28.0), 5.0);
+    /// ```
+    Rectangle {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    },
+    /// A line segment between 2 points. It has no inside.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape::Segment;
+    /// let segment = Segment { start_x: 10, start_y: 20, end_x: 20, end_y: 20 };
+    ///
+    /// assert_eq!(segment.signed_distance(15.0, 20.0), 0.0);
+    /// assert_eq!(segment.signed_distance(15.0, 24.0), 4.0);
+    /// assert_eq!(segment.signed_distance(5.0, 20.0), 5.0);
+    /// assert_eq!(segment.signed_distance(23.0, 24.0), 5.0);
+    /// ```
+    Segment {
+        start_x: u32,
+        start_y: u32,
+        end_x: u32,
+        end_y: u32,
+    },
+    /// A polygon defined by its corners.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape;
+    /// let polygon = Shape::new_polygon(vec![[0, 0], [10, 0], [0, 10]]).unwrap();
+    ///
+    /// assert_eq!(polygon.signed_distance(2.0, 1.0), -1.0);
+    /// assert_eq!(polygon.signed_distance(5.0, 0.0), 0.0);
+    /// assert_eq!(polygon.signed_distance(5.0, -3.0), 3.0);
+    /// assert_eq!(polygon.signed_distance(-3.0, -4.0), 5.0);
+    /// ```
+    Polygon(Vec<[u32; 2]>),
+}
+
+impl Shape {
+    /// Creates an ellipse, unless a radius is 0:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape;
+    /// assert!(Shape::new_ellipse(10, 20, 0, 4).is_err());
+    /// assert!(Shape::new_ellipse(10, 20, 8, 0).is_err());
+    /// ```
+    pub fn new_ellipse(
+        center_x: u32,
+        center_y: u32,
+        radius_x: u32,
+        radius_y: u32,
+    ) -> Result<Shape> {
+        if radius_x == 0 || radius_y == 0 {
+            bail!("The radii of the ellipse must be greater 0!");
+        }
+
+        Ok(Ellipse {
+            center_x,
+            center_y,
+            radius_x,
+            radius_y,
+        })
+    }
+
+    /// Creates a rectangle, unless it is empty:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape;
+    /// assert!(Shape::new_rectangle(10, 20, 0, 4).is_err());
+    /// assert!(Shape::new_rectangle(10, 20, 8, 0).is_err());
+    /// ```
+    pub fn new_rectangle(x: u32, y: u32, width: u32, height: u32) -> Result<Shape> {
+        if width == 0 || height == 0 {
+            bail!("The width & height of the rectangle must be greater 0!");
+        }
+
+        Ok(Rectangle {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a polygon, unless it has less than 3 corners:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape;
+    /// assert!(Shape::new_polygon(vec![[0, 0], [10, 0]]).is_err());
+    /// ```
+    pub fn new_polygon(corners: Vec<[u32; 2]>) -> Result<Shape> {
+        if corners.len() < 3 {
+            bail!("The polygon needs at least 3 corners!");
+        }
+
+        Ok(Polygon(corners))
+    }
+
+    /// Returns the signed distance of a point to the border of the shape.
+    pub fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        match self {
+            Circle {
+                center_x,
+                center_y,
+                radius,
+            } => length(x - *center_x as f32, y - *center_y as f32) - *radius as f32,
+            Ellipse {
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+            } => {
+                let (x, y) = (x - *center_x as f32, y - *center_y as f32);
+                let (radius_x, radius_y) = (*radius_x as f32, *radius_y as f32);
+                let k0 = length(x / radius_x, y / radius_y);
+                let k1 = length(x / (radius_x * radius_x), y / (radius_y * radius_y));
+
+                if k1 == 0.0 {
+                    return -radius_x.min(radius_y);
+                }
+
+                k0 * (k0 - 1.0) / k1
+            }
+            Rectangle {
+                x: start_x,
+                y: start_y,
+                width,
+                height,
+            } => {
+                let half_width = *width as f32 / 2.0;
+                let half_height = *height as f32 / 2.0;
+                let dx = (x - *start_x as f32 - half_width).abs() - half_width;
+                let dy = (y - *start_y as f32 - half_height).abs() - half_height;
+
+                length(dx.max(0.0), dy.max(0.0)) + dx.max(dy).min(0.0)
+            }
+            Segment {
+                start_x,
+                start_y,
+                end_x,
+                end_y,
+            } => distance_to_segment(
+                [x, y],
+                [*start_x as f32, *start_y as f32],
+                [*end_x as f32, *end_y as f32],
+            ),
+            Polygon(corners) => signed_distance_to_polygon([x, y], corners),
+        }
+    }
+
+    /// Returns a copy with its coordinates scaled by a factor per axis.
+    /// Radii of circles use the average of both factors.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape;
+    /// let rectangle = Shape::new_rectangle(10, 20, 8, 4).unwrap();
+    ///
+    /// assert_eq!(rectangle.resize(2.0, 0.5), Shape::new_rectangle(20, 10, 16, 2).unwrap());
+    /// ```
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> Shape {
+        let x = |value: &u32| scale_length(*value, factor_x);
+        let y = |value: &u32| scale_length(*value, factor_y);
+        let positive = |value: u32| value.max(1);
+
+        match self {
+            Circle {
+                center_x,
+                center_y,
+                radius,
+            } => Circle {
+                center_x: x(center_x),
+                center_y: y(center_y),
+                radius: scale_length(*radius, (factor_x + factor_y) / 2.0),
+            },
+            Ellipse {
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+            } => Ellipse {
+                center_x: x(center_x),
+                center_y: y(center_y),
+                radius_x: positive(x(radius_x)),
+                radius_y: positive(y(radius_y)),
+            },
+            Rectangle {
+                x: start_x,
+                y: start_y,
+                width,
+                height,
+            } => Rectangle {
+                x: x(start_x),
+                y: y(start_y),
+                width: positive(x(width)),
+                height: positive(y(height)),
+            },
+            Segment {
+                start_x,
+                start_y,
+                end_x,
+                end_y,
+            } => Segment {
+                start_x: x(start_x),
+                start_y: y(start_y),
+                end_x: x(end_x),
+                end_y: y(end_y),
+            },
+            Polygon(corners) => Polygon(
+                corners
+                    .iter()
+                    .map(|[corner_x, corner_y]| [x(corner_x), y(corner_y)])
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn length(x: f32, y: f32) -> f32 {
+    (x * x + y * y).sqrt()
+}
+
+fn distance_to_segment(point: [f32; 2], start: [f32; 2], end: [f32; 2]) -> f32 {
+    let (px, py) = (point[0] - start[0], point[1] - start[1]);
+    let (sx, sy) = (end[0] - start[0], end[1] - start[1]);
+    let squared_length = sx * sx + sy * sy;
+    let factor = if squared_length > 0.0 {
+        ((px * sx + py * sy) / squared_length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    length(px - sx * factor, py - sy * factor)
+}
+
+/// Uses the even-odd rule to determine the inside of the polygon.
+fn signed_distance_to_polygon(point: [f32; 2], corners: &[[u32; 2]]) -> f32 {
+    let mut distance = f32::MAX;
+    let mut is_inside = false;
+    let mut previous = to_f32(corners[corners.len() - 1]);
+
+    for corner in corners {
+        let current = to_f32(*corner);
+        distance = distance.min(distance_to_segment(point, previous, current));
+
+        if (current[1] > point[1]) != (previous[1] > point[1]) {
+            let crossing_x = (previous[0] - current[0]) * (point[1] - current[1])
+                / (previous[1] - current[1])
+                + current[0];
+
+            if point[0] < crossing_x {
+                is_inside = !is_inside;
+            }
+        }
+
+        previous = current;
+    }
+
+    if is_inside {
+        -distance
+    } else {
+        distance
+    }
+}
+
+fn to_f32(point: [u32; 2]) -> [f32; 2] {
+    [point[0] as f32, point[1] as f32]
+}
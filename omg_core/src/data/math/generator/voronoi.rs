@@ -0,0 +1,183 @@
+use crate::data::math::random::{hash2d, random_factor};
+use crate::data::math::resampling::scale_length;
+use anyhow::{bail, Result};
+
+/// Divides the plane into cells around randomly placed seeds.
+/// Each point belongs to the cell of the nearest seed.
+///
+/// Each square of the grid with the cell size contains exactly 1 seed,
+/// so that the nearest seed can be found without knowing all seeds.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Voronoi {
+    seed: u32,
+    cell_size: u32,
+}
+
+/// The nearest seed of a point.
+struct Nearest {
+    grid_x: i64,
+    grid_y: i64,
+    position: [f32; 2],
+}
+
+impl Voronoi {
+    /// Creates the cells, unless the cell size is 0:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::voronoi::Voronoi;
+    /// assert!(Voronoi::new(42, 0).is_err());
+    /// ```
+    pub fn new(seed: u32, cell_size: u32) -> Result<Voronoi> {
+        if cell_size == 0 {
+            bail!("The cell size of the voronoi cells must be greater 0!");
+        }
+
+        Ok(Voronoi { seed, cell_size })
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    pub fn cell_size(&self) -> u32 {
+        self.cell_size
+    }
+
+    /// Returns a copy with its cell size scaled by a factor.
+    pub fn resize(&self, factor: f32) -> Voronoi {
+        Voronoi {
+            seed: self.seed,
+            cell_size: scale_length(self.cell_size, factor).max(1),
+        }
+    }
+
+    /// Returns the random id of the cell containing the point.
+    /// Neighbouring cells have likely, but not guaranteed different ids.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::voronoi::Voronoi;
+    /// let voronoi = Voronoi::new(42, 10).unwrap();
+    ///
+    /// assert_eq!(voronoi.get_cell_id(5, 5), voronoi.get_cell_id(5, 5));
+    /// ```
+    pub fn get_cell_id(&self, x: u32, y: u32) -> u8 {
+        let nearest = self.find_nearest(x, y);
+        hash2d(self.seed, nearest.grid_x as u32, nearest.grid_y as u32) as u8
+    }
+
+    /// Returns the distance of a point to the border of its cell.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::voronoi::Voronoi;
+    /// let voronoi = Voronoi::new(42, 10).unwrap();
+    ///
+    /// assert!(voronoi.get_distance_to_border(5, 5) >= 0.0);
+    /// ```
+    pub fn get_distance_to_border(&self, x: u32, y: u32) -> f32 {
+        let nearest = self.find_nearest(x, y);
+        let point = [x as f32, y as f32];
+        let mut distance = f32::MAX;
+
+        self.for_each_seed(x, y, |grid_x, grid_y, position| {
+            if grid_x == nearest.grid_x && grid_y == nearest.grid_y {
+                return;
+            }
+
+            // distance to the perpendicular bisector of both seeds
+            let between = [
+                position[0] - nearest.position[0],
+                position[1] - nearest.position[1],
+            ];
+            let length = (between[0] * between[0] + between[1] * between[1]).sqrt();
+            let squared_other = squared_distance(point, position);
+            let squared_nearest = squared_distance(point, nearest.position);
+            let border = (squared_other - squared_nearest) / (2.0 * length);
+
+            distance = distance.min(border);
+        });
+
+        distance
+    }
+
+    fn find_nearest(&self, x: u32, y: u32) -> Nearest {
+        let point = [x as f32, y as f32];
+        let mut nearest = Nearest {
+            grid_x: 0,
+            grid_y: 0,
+            position: [f32::MAX, f32::MAX],
+        };
+        let mut min_distance = f32::MAX;
+
+        self.for_each_seed(x, y, |grid_x, grid_y, position| {
+            let distance = squared_distance(point, position);
+
+            if distance < min_distance {
+                min_distance = distance;
+                nearest = Nearest {
+                    grid_x,
+                    grid_y,
+                    position,
+                };
+            }
+        });
+
+        nearest
+    }
+
+    /// Calls the function for the seeds of the 5x5 grid squares around the point.
+    fn for_each_seed<F: FnMut(i64, i64, [f32; 2])>(&self, x: u32, y: u32, mut function: F) {
+        let cell_size = self.cell_size as i64;
+        let center_x = x as i64 / cell_size;
+        let center_y = y as i64 / cell_size;
+
+        for grid_y in (center_y - 2)..=(center_y + 2) {
+            for grid_x in (center_x - 2)..=(center_x + 2) {
+                let position = self.get_seed_position(grid_x, grid_y);
+                function(grid_x, grid_y, position);
+            }
+        }
+    }
+
+    fn get_seed_position(&self, grid_x: i64, grid_y: i64) -> [f32; 2] {
+        let (hash_x, hash_y) = (grid_x as u32, grid_y as u32);
+        let offset_x = random_factor(self.seed, hash_x, hash_y);
+        let offset_y = random_factor(self.seed.wrapping_add(1), hash_x, hash_y);
+        let cell_size = self.cell_size as f32;
+
+        [
+            (grid_x as f32 + offset_x) * cell_size,
+            (grid_y as f32 + offset_y) * cell_size,
+        ]
+    }
+}
+
+fn squared_distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+    let x = a[0] - b[0];
+    let y = a[1] - b[1];
+    x * x + y * y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_are_contiguous() {
+        let voronoi = Voronoi::new(7, 8).unwrap();
+        let mut borders = 0;
+
+        for y in 0..40 {
+            for x in 0..40 {
+                let distance = voronoi.get_distance_to_border(x, y);
+                assert!(distance >= 0.0);
+
+                if voronoi.get_cell_id(x, y) != voronoi.get_cell_id(x + 1, y) {
+                    borders += 1;
+                    assert!(distance <= 1.0);
+                }
+            }
+        }
+
+        assert!(borders > 10);
+    }
+}
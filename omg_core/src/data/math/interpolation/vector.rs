@@ -80,7 +80,7 @@ impl<T: Input, V: Interpolate> VectorInterpolator<T, V> {
     /// assert_eq!(interpolator.interpolate(150), 200);
     /// ```
     pub fn interpolate(&self, input: T) -> V {
-        let mut last_entry = self.vector.get(0).unwrap();
+        let mut last_entry = self.vector.first().unwrap();
 
         if input <= last_entry.threshold {
             return last_entry.value.clone();
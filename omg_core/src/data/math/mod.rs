@@ -1,6 +1,15 @@
+pub mod blend;
 pub mod distance;
+pub mod distance_transform;
+pub mod edge;
+pub mod expression;
+pub mod filter;
 pub mod generator;
 pub mod interpolation;
+pub mod predicate;
+pub mod random;
+pub mod region;
+pub mod resampling;
 pub mod selector;
 pub mod size2d;
 pub mod transformer;
@@ -0,0 +1,103 @@
+use anyhow::{bail, Result};
+use Predicate::*;
+
+/// Checks if a value fulfills a condition.
+/// Used to select cells of a map, e.g. all cells with ocean.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Predicate {
+    /// Is the value below a threshold?
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate::Below;
+    /// assert!(Below(76).check(0));
+    /// assert!(Below(76).check(75));
+    /// assert!(!Below(76).check(76));
+    /// assert!(!Below(76).check(255));
+    /// ```
+    Below(u8),
+    /// Is the value above a threshold?
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate::Above;
+    /// assert!(!Above(160).check(0));
+    /// assert!(!Above(160).check(160));
+    /// assert!(Above(160).check(161));
+    /// assert!(Above(160).check(255));
+    /// ```
+    Above(u8),
+    /// Is the value equal to a specific value?
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate::Equal;
+    /// assert!(!Equal(12).check(11));
+    /// assert!(Equal(12).check(12));
+    /// assert!(!Equal(12).check(13));
+    /// ```
+    Equal(u8),
+    /// Is the value inside a range? Includes minimum & maximum.
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate;
+    /// let predicate = Predicate::new_in_range(10, 20).unwrap();
+    ///
+    /// assert!(!predicate.check(9));
+    /// assert!(predicate.check(10));
+    /// assert!(predicate.check(15));
+    /// assert!(predicate.check(20));
+    /// assert!(!predicate.check(21));
+    /// ```
+    InRange { min: u8, max: u8 },
+    /// Is the value one of the specified ones? E.g. a group of biomes.
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate::OneOf;
+    /// let predicate = OneOf(vec![3, 7]);
+    ///
+    /// assert!(!predicate.check(2));
+    /// assert!(predicate.check(3));
+    /// assert!(!predicate.check(4));
+    /// assert!(predicate.check(7));
+    /// ```
+    OneOf(Vec<u8>),
+}
+
+impl Predicate {
+    /// Creates a range, unless the minimum is greater than the maximum:
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate;
+    /// assert!(Predicate::new_in_range(20, 10).is_err());
+    /// ```
+    pub fn new_in_range(min: u8, max: u8) -> Result<Predicate> {
+        if min > max {
+            bail!("The range's minimum {} is above its maximum {}!", min, max);
+        }
+
+        Ok(InRange { min, max })
+    }
+
+    /// Creates a set of values, unless it is empty:
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate;
+    /// assert!(Predicate::new_one_of(Vec::new()).is_err());
+    /// ```
+    pub fn new_one_of(values: Vec<u8>) -> Result<Predicate> {
+        if values.is_empty() {
+            bail!("The set of values is empty!");
+        }
+
+        Ok(OneOf(values))
+    }
+
+    /// Checks the value.
+    pub fn check(&self, value: u8) -> bool {
+        match self {
+            Below(threshold) => value < *threshold,
+            Above(threshold) => value > *threshold,
+            Equal(other) => value == *other,
+            InRange { min, max } => value >= *min && value <= *max,
+            OneOf(values) => values.contains(&value),
+        }
+    }
+}
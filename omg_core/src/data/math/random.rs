@@ -0,0 +1,37 @@
+/// Hashes a seed & 2d coordinates to a pseudo random number.
+///
+/// The result only depends on the input, so it can be evaluated in any order.
+/// E.g. for each cell of a tile independent of the other tiles.
+///
+/// ```
+///# use omg_core::data::math::random::hash2d;
+/// assert_eq!(hash2d(0, 1, 2), hash2d(0, 1, 2));
+/// assert_ne!(hash2d(0, 1, 2), hash2d(1, 1, 2));
+/// assert_ne!(hash2d(0, 1, 2), hash2d(0, 2, 1));
+/// ```
+pub fn hash2d(seed: u32, x: u32, y: u32) -> u32 {
+    mix(mix(mix(seed) ^ x) ^ y)
+}
+
+/// Returns a pseudo random number in the range [0,1).
+///
+/// ```
+///# use omg_core::data::math::random::random_factor;
+/// for x in 0..100 {
+///     let factor = random_factor(42, x, 7);
+///     assert!(factor >= 0.0 && factor < 1.0);
+/// }
+/// ```
+pub fn random_factor(seed: u32, x: u32, y: u32) -> f32 {
+    (hash2d(seed, x, y) >> 8) as f32 / (1 << 24) as f32
+}
+
+/// The finalizer of MurmurHash3.
+fn mix(value: u32) -> u32 {
+    let mut value = value.wrapping_add(0x9E37_79B9);
+    value ^= value >> 16;
+    value = value.wrapping_mul(0x85EB_CA6B);
+    value ^= value >> 13;
+    value = value.wrapping_mul(0xC2B2_AE35);
+    value ^ (value >> 16)
+}
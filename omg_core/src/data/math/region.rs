@@ -0,0 +1,102 @@
+use crate::data::math::size2d::Size2d;
+use anyhow::{bail, Result};
+
+/// A rectangular part of a larger map, e.g. a tile.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Region {
+    x: u32,
+    y: u32,
+    size: Size2d,
+}
+
+impl Region {
+    /// Creates a region with its top left corner at (x,y).
+    pub fn new(x: u32, y: u32, size: Size2d) -> Region {
+        Region { x, y, size }
+    }
+
+    /// Creates a region covering the whole map.
+    pub fn full(size: Size2d) -> Region {
+        Region::new(0, 0, size)
+    }
+
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    pub fn size(&self) -> Size2d {
+        self.size
+    }
+
+    /// Returns the x-coordinate after the region.
+    pub fn end_x(&self) -> u32 {
+        self.x + self.size.width()
+    }
+
+    /// Returns the y-coordinate after the region.
+    pub fn end_y(&self) -> u32 {
+        self.y + self.size.height()
+    }
+
+    /// Is the region completely inside the other one?
+    ///
+    /// ```
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(2, 3, Size2d::unchecked(4, 5));
+    ///
+    /// assert!(region.is_inside(&Region::new(2, 3, Size2d::unchecked(4, 5))));
+    /// assert!(region.is_inside(&Region::full(Size2d::unchecked(10, 10))));
+    /// assert!(!region.is_inside(&Region::full(Size2d::unchecked(5, 10))));
+    /// assert!(!region.is_inside(&Region::new(3, 0, Size2d::unchecked(10, 10))));
+    /// ```
+    pub fn is_inside(&self, other: &Region) -> bool {
+        self.x >= other.x
+            && self.y >= other.y
+            && self.end_x() <= other.end_x()
+            && self.end_y() <= other.end_y()
+    }
+
+    /// Checks, that the region is inside a map of a specific size.
+    ///
+    /// ```
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(2, 3, Size2d::unchecked(4, 5));
+    ///
+    /// assert!(region.validate(Size2d::unchecked(6, 8)).is_ok());
+    /// assert!(region.validate(Size2d::unchecked(5, 8)).is_err());
+    /// assert!(region.validate(Size2d::unchecked(6, 7)).is_err());
+    /// ```
+    pub fn validate(&self, map_size: Size2d) -> Result<()> {
+        if !self.is_inside(&Region::full(map_size)) {
+            bail!("{:?} is outside the map of {:?}!", self, map_size);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the region expanded by a border on all sides, but limited to a map of a specific size.
+    ///
+    /// ```
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let region = Region::new(2, 3, Size2d::unchecked(4, 5));
+    /// let map_size = Size2d::unchecked(10, 10);
+    ///
+    /// assert_eq!(region.expand(1, map_size), Region::new(1, 2, Size2d::unchecked(6, 7)));
+    /// assert_eq!(region.expand(5, map_size), Region::full(map_size));
+    /// ```
+    pub fn expand(&self, border: u32, map_size: Size2d) -> Region {
+        let x = self.x.saturating_sub(border);
+        let y = self.y.saturating_sub(border);
+        let end_x = self.end_x().saturating_add(border).min(map_size.width());
+        let end_y = self.end_y().saturating_add(border).min(map_size.height());
+
+        Region::new(x, y, Size2d::unchecked(end_x - x, end_y - y))
+    }
+}
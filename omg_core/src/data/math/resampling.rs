@@ -0,0 +1,212 @@
+use crate::data::math::interpolation::lerp;
+use crate::data::math::region::Region;
+use crate::data::math::size2d::Size2d;
+use ResamplingFilter::*;
+
+/// Defines how values are sampled, if a grid of values is resized.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ResamplingFilter {
+    /// Uses the value of the nearest cell.
+    Nearest,
+    /// Interpolates the 4 nearest cells linearly.
+    Bilinear,
+    /// Averages all cells covered by the new cell. Preferable for downsampling.
+    Area,
+}
+
+/// Resizes a grid of values.
+///
+/// ```
+///# use omg_core::data::math::resampling::resample;
+///# use omg_core::data::math::resampling::ResamplingFilter::*;
+///# use omg_core::data::math::size2d::Size2d;
+/// let from = Size2d::unchecked(2, 1);
+/// let to = Size2d::unchecked(4, 2);
+/// let values = vec![0, 100];
+///
+/// assert_eq!(resample(&values, from, to, Nearest), vec![0, 0, 100, 100, 0, 0, 100, 100]);
+/// assert_eq!(resample(&values, from, to, Bilinear), vec![0, 25, 75, 100, 0, 25, 75, 100]);
+/// assert_eq!(resample(&values, from, to, Area), vec![0, 0, 100, 100, 0, 0, 100, 100]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the number of values doesn't match the size.
+pub fn resample(values: &[u8], from: Size2d, to: Size2d, filter: ResamplingFilter) -> Vec<u8> {
+    if from == to {
+        return values.to_vec();
+    }
+
+    resample_region(values, from, to, Region::full(to), filter)
+}
+
+/// Resizes a grid of values, but only returns a region of the result.
+///
+/// ```
+///# use omg_core::data::math::region::Region;
+///# use omg_core::data::math::resampling::resample_region;
+///# use omg_core::data::math::resampling::ResamplingFilter::Bilinear;
+///# use omg_core::data::math::size2d::Size2d;
+/// let from = Size2d::unchecked(2, 1);
+/// let to = Size2d::unchecked(4, 2);
+/// let region = Region::new(1, 1, Size2d::unchecked(2, 1));
+/// let values = vec![0, 100];
+///
+/// assert_eq!(resample_region(&values, from, to, region, Bilinear), vec![25, 75]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the number of values doesn't match the size.
+pub fn resample_region(
+    values: &[u8],
+    from: Size2d,
+    to: Size2d,
+    region: Region,
+    filter: ResamplingFilter,
+) -> Vec<u8> {
+    assert_eq!(
+        values.len(),
+        from.get_area(),
+        "The number of values doesn't match the size!"
+    );
+
+    let factor_x = from.width() as f32 / to.width() as f32;
+    let factor_y = from.height() as f32 / to.height() as f32;
+    let mut result = Vec::with_capacity(region.size().get_area());
+
+    for y in region.y()..region.end_y() {
+        let source_y = to_source(y, factor_y, from.height());
+
+        for x in region.x()..region.end_x() {
+            let source_x = to_source(x, factor_x, from.width());
+
+            let value = match filter {
+                Nearest => {
+                    let index =
+                        from.saturating_to_index(source_x.round() as u32, source_y.round() as u32);
+                    values[index]
+                }
+                Bilinear => sample_bilinear(values, from, source_x, source_y),
+                Area => sample_area(values, from, x, y, factor_x, factor_y),
+            };
+
+            result.push(value);
+        }
+    }
+
+    result
+}
+
+/// Samples a grid of values at a point between the cells by interpolating the 4 nearest cells.
+/// Points outside the grid are limited to it.
+///
+/// ```
+///# use omg_core::data::math::resampling::sample_bilinear;
+///# use omg_core::data::math::size2d::Size2d;
+/// let size = Size2d::unchecked(2, 2);
+/// let values = vec![0, 100, 100, 200];
+///
+/// assert_eq!(sample_bilinear(&values, size, 0.0, 0.0), 0);
+/// assert_eq!(sample_bilinear(&values, size, 0.5, 0.0), 50);
+/// assert_eq!(sample_bilinear(&values, size, 0.5, 0.5), 100);
+/// assert_eq!(sample_bilinear(&values, size, 1.0, 1.0), 200);
+/// assert_eq!(sample_bilinear(&values, size, -1.0, 5.0), 100);
+/// ```
+pub fn sample_bilinear(values: &[u8], size: Size2d, x: f32, y: f32) -> u8 {
+    let x = x.clamp(0.0, (size.width() - 1) as f32);
+    let y = y.clamp(0.0, (size.height() - 1) as f32);
+    let x0 = x.floor() as u32;
+    let y0 = y.floor() as u32;
+    let factor_x = x - x0 as f32;
+    let factor_y = y - y0 as f32;
+
+    let top = lerp(
+        values[size.saturating_to_index(x0, y0)],
+        values[size.saturating_to_index(x0 + 1, y0)],
+        factor_x,
+    );
+    let bottom = lerp(
+        values[size.saturating_to_index(x0, y0 + 1)],
+        values[size.saturating_to_index(x0 + 1, y0 + 1)],
+        factor_x,
+    );
+
+    lerp(top, bottom, factor_y)
+}
+
+/// Averages all source cells covered by a target cell.
+fn sample_area(values: &[u8], size: Size2d, x: u32, y: u32, factor_x: f32, factor_y: f32) -> u8 {
+    let [start_x, end_x] = get_covered(x, factor_x, size.width());
+    let [start_y, end_y] = get_covered(y, factor_y, size.height());
+    let mut sum = 0;
+    let mut count = 0;
+
+    for source_y in start_y..end_y {
+        for source_x in start_x..end_x {
+            sum += values[size.saturating_to_index(source_x, source_y)] as u32;
+            count += 1;
+        }
+    }
+
+    (sum as f32 / count as f32).round() as u8
+}
+
+/// Returns the range of source cells covered by a target cell. Covers at least 1 cell.
+fn get_covered(target: u32, factor: f32, length: u32) -> [u32; 2] {
+    let start = ((target as f32 * factor).floor() as u32).min(length - 1);
+    let end = (((target + 1) as f32 * factor).ceil() as u32).clamp(start + 1, length);
+    [start, end]
+}
+
+/// Scales a length or coordinate of a map by a factor, e.g. if the map is resized.
+///
+/// ```
+///# use omg_core::data::math::resampling::scale_length;
+/// assert_eq!(scale_length(10, 0.5), 5);
+/// assert_eq!(scale_length(10, 1.0), 10);
+/// assert_eq!(scale_length(10, 1.26), 13);
+/// assert_eq!(scale_length(10, 4.0), 40);
+/// ```
+pub fn scale_length(length: u32, factor: f32) -> u32 {
+    (length as f32 * factor).round() as u32
+}
+
+/// Maps the center of a target cell to the source grid.
+fn to_source(target: u32, factor: f32, length: u32) -> f32 {
+    let source = (target as f32 + 0.5) * factor - 0.5;
+    source.clamp(0.0, (length - 1) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_size() {
+        let size = Size2d::unchecked(2, 2);
+        let values = vec![1, 2, 3, 4];
+
+        assert_eq!(resample(&values, size, size, Bilinear), values);
+    }
+
+    #[test]
+    fn test_downsample() {
+        let from = Size2d::unchecked(4, 1);
+        let to = Size2d::unchecked(2, 1);
+        let values = vec![0, 100, 200, 250];
+
+        assert_eq!(resample(&values, from, to, Nearest), vec![100, 250]);
+        assert_eq!(resample(&values, from, to, Bilinear), vec![50, 225]);
+        assert_eq!(resample(&values, from, to, Area), vec![50, 225]);
+    }
+
+    #[test]
+    fn test_downsample_with_area() {
+        let from = Size2d::unchecked(3, 2);
+        let to = Size2d::unchecked(1, 1);
+        let values = vec![0, 10, 20, 30, 40, 50];
+
+        assert_eq!(resample(&values, from, to, Area), vec![25]);
+    }
+}
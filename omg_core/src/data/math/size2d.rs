@@ -61,8 +61,18 @@ impl Size2d {
     /// let size = Size2d::unchecked(2, 3);
     /// assert_eq!(size.get_area(), 6);
     /// ```
+    ///
+    /// Doesn't overflow for large maps:
+    ///
+    /// ```
+    ///# use omg_core::data::math::size2d::Size2d;
+    /// let size = Size2d::unchecked(100_000, 100_000);
+    /// assert_eq!(size.get_area(), 10_000_000_000);
+    /// assert_eq!(size.to_index_risky(99_999, 99_999), 9_999_999_999);
+    /// assert_eq!(size.to_x_and_y(9_999_999_999), [99_999, 99_999]);
+    /// ```
     pub fn get_area(&self) -> usize {
-        (self.width * self.height) as usize
+        self.width as usize * self.height as usize
     }
 
     /// Returns the size along the x-axis.
@@ -112,7 +122,7 @@ impl Size2d {
     /// assert_eq!(size.to_x(5), 1);
     /// ```
     pub fn to_x(&self, index: usize) -> u32 {
-        index as u32 % self.width
+        (index % self.width as usize) as u32
     }
 
     /// Converts an index to the y-coordinate of the equivalent point.
@@ -123,7 +133,7 @@ impl Size2d {
     /// assert_eq!(size.to_y(5), 2);
     /// ```
     pub fn to_y(&self, index: usize) -> u32 {
-        index as u32 / self.width
+        (index / self.width as usize) as u32
     }
 
     /// Converts an index to the equivalent point.
@@ -163,7 +173,7 @@ impl Size2d {
     /// assert_eq!(size.to_index_risky(1, 2), 5);
     /// ```
     pub fn to_index_risky(&self, x: u32, y: u32) -> usize {
-        (y * self.width + x) as usize
+        y as usize * self.width as usize + x as usize
     }
 
     /// Converts a point to the equivalent index.
@@ -188,7 +198,7 @@ impl Size2d {
     pub fn saturating_to_index(&self, x: u32, y: u32) -> usize {
         let x = x.min(self.width - 1);
         let y = y.min(self.height - 1);
-        (y * self.width + x) as usize
+        y as usize * self.width as usize + x as usize
     }
 }
 
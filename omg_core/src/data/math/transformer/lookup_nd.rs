@@ -0,0 +1,104 @@
+use anyhow::{bail, Result};
+
+/// A lookup table with any number of inputs.
+///
+/// Each input has an axis of ascending thresholds, which split it into cells of arbitrary size.
+/// A value equal to a threshold belongs to the cell above it.
+/// The cells of the first input are next to each other in the values.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LookupTableNd {
+    axes: Vec<Vec<u8>>,
+    values: Vec<u8>,
+}
+
+impl LookupTableNd {
+    /// Returns the lookup table, if valid:
+    ///
+    /// ```
+    ///# use omg_core::data::math::transformer::lookup_nd::LookupTableNd;
+    /// assert!(LookupTableNd::new(vec![vec![100]], vec![1, 2]).is_ok());
+    /// // no axis
+    /// assert!(LookupTableNd::new(Vec::new(), vec![1]).is_err());
+    /// // the thresholds are not ascending
+    /// assert!(LookupTableNd::new(vec![vec![100, 50]], vec![1, 2, 3]).is_err());
+    /// assert!(LookupTableNd::new(vec![vec![100, 100]], vec![1, 2, 3]).is_err());
+    /// // the number of values doesn't match
+    /// assert!(LookupTableNd::new(vec![vec![100], vec![50]], vec![1, 2, 3]).is_err());
+    /// // too many cells
+    /// assert!(LookupTableNd::new(vec![(0..=255).collect(); 8], vec![1]).is_err());
+    /// ```
+    pub fn new(axes: Vec<Vec<u8>>, values: Vec<u8>) -> Result<LookupTableNd> {
+        if axes.is_empty() {
+            bail!("The lookup table has no axis!");
+        }
+
+        for (index, axis) in axes.iter().enumerate() {
+            if axis.windows(2).any(|pair| pair[0] >= pair[1]) {
+                bail!("The thresholds of axis {} are not ascending!", index);
+            }
+        }
+
+        let number_of_cells = axes
+            .iter()
+            .try_fold(1usize, |cells, axis| cells.checked_mul(axis.len() + 1));
+        let number_of_cells = match number_of_cells {
+            Some(number) => number,
+            None => bail!("The lookup table has too many cells!"),
+        };
+
+        if number_of_cells != values.len() {
+            bail!(
+                "The number of cells ({}) doesn't match the number of values ({})!",
+                number_of_cells,
+                values.len()
+            );
+        }
+
+        Ok(LookupTableNd { axes, values })
+    }
+
+    pub fn axes(&self) -> &[Vec<u8>] {
+        &self.axes
+    }
+
+    pub fn values(&self) -> &[u8] {
+        &self.values
+    }
+
+    /// Returns the value of the cell for the input values.
+    ///
+    /// ```
+    ///# use omg_core::data::math::transformer::lookup_nd::LookupTableNd;
+    /// let axes = vec![vec![50, 200], vec![100]];
+    /// let table = LookupTableNd::new(axes, vec![10, 20, 30, 40, 50, 60]).unwrap();
+    ///
+    /// assert_eq!(table.lookup(&[0, 0]), 10);
+    /// assert_eq!(table.lookup(&[50, 99]), 20);
+    /// assert_eq!(table.lookup(&[255, 0]), 30);
+    /// assert_eq!(table.lookup(&[49, 100]), 40);
+    /// assert_eq!(table.lookup(&[199, 255]), 50);
+    /// assert_eq!(table.lookup(&[200, 100]), 60);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of inputs doesn't match the number of axes.
+    pub fn lookup(&self, inputs: &[u8]) -> u8 {
+        assert_eq!(
+            inputs.len(),
+            self.axes.len(),
+            "The number of inputs doesn't match the number of axes!"
+        );
+
+        let mut index = 0;
+        let mut stride = 1;
+
+        for (axis, input) in self.axes.iter().zip(inputs) {
+            let cell = axis.partition_point(|threshold| threshold <= input);
+            index += cell * stride;
+            stride *= axis.len() + 1;
+        }
+
+        self.values[index]
+    }
+}
@@ -1,4 +1,6 @@
 pub mod lookup2d;
+pub mod lookup_nd;
 pub mod threshold;
 pub mod transformer1d;
 pub mod transformer2d;
+pub mod transformer_nd;
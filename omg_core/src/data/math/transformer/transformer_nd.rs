@@ -0,0 +1,184 @@
+use crate::data::math::generator::shape::Shape;
+use crate::data::math::predicate::Predicate;
+use crate::data::math::transformer::lookup_nd::LookupTableNd;
+use anyhow::{bail, Result};
+use RuleCondition::*;
+use TransformerNd::*;
+
+/// A condition of a [`Rule`] based on the values of its inputs.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RuleCondition {
+    /// The value of an input fulfills a predicate, e.g. a range of temperatures.
+    Value { input: usize, predicate: Predicate },
+    /// The values of 2 inputs are a point inside or on the border of a shape.
+    /// E.g. a polygon in the temperature/rainfall plane like a Whittaker diagram.
+    InShape { x: usize, y: usize, shape: Shape },
+}
+
+impl RuleCondition {
+    /// Checks the condition with a function, which returns the value of an input.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::shape::Shape;
+    ///# use omg_core::data::math::transformer::transformer_nd::RuleCondition::InShape;
+    /// let shape = Shape::new_polygon(vec![[0, 0], [100, 0], [0, 100]]).unwrap();
+    /// let condition = InShape { x: 0, y: 1, shape };
+    /// let check = |inputs: [u8; 2]| condition.check(|input| inputs[input]);
+    ///
+    /// assert!(check([10, 10]));
+    /// assert!(check([50, 50]));
+    /// assert!(!check([100, 10]));
+    /// ```
+    pub fn check<F: Fn(usize) -> u8>(&self, get_input: F) -> bool {
+        match self {
+            Value { input, predicate } => predicate.check(get_input(*input)),
+            InShape { x, y, shape } => {
+                let x = get_input(*x) as f32;
+                let y = get_input(*y) as f32;
+                shape.signed_distance(x, y) <= 0.0
+            }
+        }
+    }
+
+    /// Returns the highest input used by the condition.
+    pub fn get_max_input(&self) -> usize {
+        match self {
+            Value { input, .. } => *input,
+            InShape { x, y, .. } => *x.max(y),
+        }
+    }
+}
+
+/// Returns its value, if all conditions are fulfilled.
+/// A rule without conditions is always fulfilled.
+#[derive(new, Debug, PartialEq, Eq, Clone)]
+pub struct Rule {
+    conditions: Vec<RuleCondition>,
+    value: u8,
+}
+
+impl Rule {
+    pub fn conditions(&self) -> &[RuleCondition] {
+        &self.conditions
+    }
+
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// Checks all conditions with a function, which returns the value of an input.
+    pub fn check<F: Fn(usize) -> u8>(&self, get_input: F) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.check(&get_input))
+    }
+}
+
+/// Returns the value of the first [`Rule`], whose conditions are fulfilled.
+pub fn find_first_value<'a, I, F>(rules: I, get_input: F) -> Option<u8>
+where
+    I: IntoIterator<Item = &'a Rule>,
+    F: Fn(usize) -> u8,
+{
+    rules
+        .into_iter()
+        .find(|rule| rule.check(&get_input))
+        .map(|rule| rule.value)
+}
+
+/// Transforms any number of inputs into an output.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TransformerNd {
+    /// Looks up the output in a table with non-uniform axes.
+    ///
+    /// ```
+    ///# use omg_core::data::math::transformer::lookup_nd::LookupTableNd;
+    ///# use omg_core::data::math::transformer::transformer_nd::TransformerNd::Lookup;
+    /// let table = LookupTableNd::new(vec![vec![100], vec![50], vec![200]], (0..8).collect()).unwrap();
+    /// let transformer = Lookup(table);
+    ///
+    /// assert_eq!(transformer.transform(&[0, 0, 0]), 0);
+    /// assert_eq!(transformer.transform(&[100, 0, 0]), 1);
+    /// assert_eq!(transformer.transform(&[0, 50, 0]), 2);
+    /// assert_eq!(transformer.transform(&[0, 0, 200]), 4);
+    /// assert_eq!(transformer.transform(&[255, 255, 255]), 7);
+    /// ```
+    Lookup(LookupTableNd),
+    /// Returns the value of the first [`Rule`], whose conditions are fulfilled, or the default value.
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate::*;
+    ///# use omg_core::data::math::transformer::transformer_nd::{Rule, TransformerNd};
+    ///# use omg_core::data::math::transformer::transformer_nd::RuleCondition::Value;
+    /// let ocean = Rule::new(vec![Value { input: 0, predicate: Below(76) }], 12);
+    /// let desert = Rule::new(
+    ///     vec![
+    ///         Value { input: 1, predicate: Above(200) },
+    ///         Value { input: 2, predicate: Below(50) },
+    ///     ],
+    ///     9,
+    /// );
+    /// let transformer = TransformerNd::new_rules(vec![ocean, desert], 7).unwrap();
+    ///
+    /// assert_eq!(transformer.transform(&[50, 220, 20]), 12);
+    /// assert_eq!(transformer.transform(&[100, 220, 20]), 9);
+    /// assert_eq!(transformer.transform(&[100, 220, 60]), 7);
+    /// assert_eq!(transformer.transform(&[100, 150, 20]), 7);
+    /// ```
+    Rules { rules: Vec<Rule>, default: u8 },
+}
+
+impl TransformerNd {
+    /// Creates a list of rules, unless it is empty:
+    ///
+    /// ```
+    ///# use omg_core::data::math::transformer::transformer_nd::TransformerNd;
+    /// assert!(TransformerNd::new_rules(Vec::new(), 0).is_err());
+    /// ```
+    pub fn new_rules(rules: Vec<Rule>, default: u8) -> Result<TransformerNd> {
+        if rules.is_empty() {
+            bail!("The transformer has no rules!");
+        }
+
+        Ok(Rules { rules, default })
+    }
+
+    /// Returns the minimum number of inputs.
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate::Below;
+    ///# use omg_core::data::math::transformer::lookup_nd::LookupTableNd;
+    ///# use omg_core::data::math::transformer::transformer_nd::{Rule, TransformerNd};
+    ///# use omg_core::data::math::transformer::transformer_nd::RuleCondition::Value;
+    /// let table = LookupTableNd::new(vec![vec![100], vec![50]], vec![1, 2, 3, 4]).unwrap();
+    /// let rule = Rule::new(vec![Value { input: 3, predicate: Below(76) }], 12);
+    ///
+    /// assert_eq!(TransformerNd::Lookup(table).get_number_of_inputs(), 2);
+    /// assert_eq!(TransformerNd::new_rules(vec![rule], 0).unwrap().get_number_of_inputs(), 4);
+    /// ```
+    pub fn get_number_of_inputs(&self) -> usize {
+        match self {
+            Lookup(table) => table.axes().len(),
+            Rules { rules, .. } => rules
+                .iter()
+                .flat_map(|rule| rule.conditions())
+                .map(|condition| condition.get_max_input() + 1)
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    /// Transforms the inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are too few inputs.
+    pub fn transform(&self, inputs: &[u8]) -> u8 {
+        match self {
+            Lookup(table) => table.lookup(inputs),
+            Rules { rules, default } => {
+                find_first_value(rules, |input| inputs[input]).unwrap_or(*default)
+            }
+        }
+    }
+}
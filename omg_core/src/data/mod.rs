@@ -1,5 +1,6 @@
 pub mod color;
 pub mod input;
+pub mod layout;
 pub mod map;
 pub mod math;
 pub mod name;
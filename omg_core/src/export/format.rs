@@ -0,0 +1,290 @@
+use crate::data::map::attribute::Attribute;
+use crate::export::rescaling::Rescaling;
+use anyhow::{Context, Result};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use tiff::encoder::colortype::Gray32Float;
+use tiff::encoder::TiffEncoder;
+use tiff::tags::Tag;
+use ExportFormat::*;
+
+const MODEL_PIXEL_SCALE_TAG: u16 = 33550;
+const MODEL_TIEPOINT_TAG: u16 = 33922;
+const GEO_KEY_DIRECTORY_TAG: u16 = 34735;
+
+/// Places an exported map in a geographic coordinate system (WGS 84).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GeoReference {
+    longitude: f64,
+    latitude: f64,
+    pixel_size: f64,
+}
+
+impl GeoReference {
+    /// Creates a geo reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `longitude` - The longitude of the top left corner in degrees.
+    /// * `latitude` - The latitude of the top left corner in degrees.
+    /// * `pixel_size` - The size of a cell in degrees.
+    pub fn new(longitude: f64, latitude: f64, pixel_size: f64) -> GeoReference {
+        GeoReference {
+            longitude,
+            latitude,
+            pixel_size,
+        }
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn pixel_size(&self) -> f64 {
+        self.pixel_size
+    }
+}
+
+/// The file formats to export an [`Attribute`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ExportFormat {
+    /// A grayscale PNG with 8 bits per cell.
+    Png8,
+    /// A grayscale PNG with 16 bits per cell.
+    Png16,
+    /// A raw heightmap with an unsigned 16 bit integer in little endian per cell.
+    RawR16,
+    /// A raw heightmap with a 32 bit float in little endian per cell.
+    RawR32,
+    /// A grayscale TIFF with a 32 bit float per cell.
+    /// Adds GeoTIFF tags, if it has a [`GeoReference`].
+    TiffFloat(Option<GeoReference>),
+}
+
+impl ExportFormat {
+    /// Writes the attribute to a file.
+    pub fn export(
+        &self,
+        attribute: &Attribute,
+        rescaling: Option<&Rescaling>,
+        path: &str,
+    ) -> Result<()> {
+        info!(
+            "Export attribute '{}' as {:?} to '{}'",
+            attribute.name(),
+            self,
+            path
+        );
+
+        let file = File::create(path).with_context(|| format!("Failed to create '{}'", path))?;
+        let mut writer = BufWriter::new(file);
+
+        match self {
+            Png8 => {
+                let values = to_u8_values(attribute, rescaling);
+                write_png(&mut writer, attribute, png::BitDepth::Eight, &values)
+            }
+            Png16 => {
+                let values: Vec<u8> = to_u16_values(attribute, rescaling)
+                    .into_iter()
+                    .flat_map(|value| value.to_be_bytes())
+                    .collect();
+                write_png(&mut writer, attribute, png::BitDepth::Sixteen, &values)
+            }
+            RawR16 => {
+                let values: Vec<u8> = to_u16_values(attribute, rescaling)
+                    .into_iter()
+                    .flat_map(|value| value.to_le_bytes())
+                    .collect();
+                Ok(writer.write_all(&values)?)
+            }
+            RawR32 => {
+                let values: Vec<u8> = to_f32_values(attribute, rescaling)
+                    .into_iter()
+                    .flat_map(|value| value.to_le_bytes())
+                    .collect();
+                Ok(writer.write_all(&values)?)
+            }
+            TiffFloat(reference) => {
+                let values = to_f32_values(attribute, rescaling);
+                write_tiff(&mut writer, attribute, reference.as_ref(), &values)
+            }
+        }
+        .with_context(|| format!("Failed to write attribute '{}'", attribute.name()))
+    }
+}
+
+/// Converts the values of an attribute to 8 bit. Without rescaling, they are unchanged.
+///
+/// ```
+///# use omg_core::data::map::attribute::Attribute;
+///# use omg_core::data::math::size2d::Size2d;
+///# use omg_core::export::format::to_u8_values;
+///# use omg_core::export::rescaling::Rescaling;
+/// let attribute = Attribute::new("test", Size2d::unchecked(3, 1), vec![0, 128, 255]).unwrap();
+/// let rescaling = Rescaling::new(0, 255, 0.0, 510.0).unwrap();
+///
+/// assert_eq!(to_u8_values(&attribute, None), vec![0, 128, 255]);
+/// assert_eq!(to_u8_values(&attribute, Some(&rescaling)), vec![0, 255, 255]);
+/// ```
+pub fn to_u8_values(attribute: &Attribute, rescaling: Option<&Rescaling>) -> Vec<u8> {
+    match rescaling {
+        None => attribute.get_all().clone(),
+        Some(rescaling) => attribute
+            .get_all()
+            .iter()
+            .map(|value| rescaling.rescale(*value).round().clamp(0.0, u8::MAX as f32) as u8)
+            .collect(),
+    }
+}
+
+/// Converts the values of an attribute to 16 bit.
+/// Without rescaling, the full range of 8 bit is mapped to the full range of 16 bit.
+///
+/// ```
+///# use omg_core::data::map::attribute::Attribute;
+///# use omg_core::data::math::size2d::Size2d;
+///# use omg_core::export::format::to_u16_values;
+///# use omg_core::export::rescaling::Rescaling;
+/// let attribute = Attribute::new("test", Size2d::unchecked(3, 1), vec![0, 1, 255]).unwrap();
+/// let rescaling = Rescaling::new(0, 255, -10.0, 100000.0).unwrap();
+///
+/// assert_eq!(to_u16_values(&attribute, None), vec![0, 257, 65535]);
+/// assert_eq!(to_u16_values(&attribute, Some(&rescaling)), vec![0, 382, 65535]);
+/// ```
+pub fn to_u16_values(attribute: &Attribute, rescaling: Option<&Rescaling>) -> Vec<u16> {
+    match rescaling {
+        None => attribute
+            .get_all()
+            .iter()
+            .map(|value| *value as u16 * 257)
+            .collect(),
+        Some(rescaling) => attribute
+            .get_all()
+            .iter()
+            .map(|value| {
+                rescaling
+                    .rescale(*value)
+                    .round()
+                    .clamp(0.0, u16::MAX as f32) as u16
+            })
+            .collect(),
+    }
+}
+
+/// Converts the values of an attribute to 32 bit floats. Without rescaling, they are unchanged.
+///
+/// ```
+///# use omg_core::data::map::attribute::Attribute;
+///# use omg_core::data::math::size2d::Size2d;
+///# use omg_core::export::format::to_f32_values;
+///# use omg_core::export::rescaling::Rescaling;
+/// let attribute = Attribute::new("test", Size2d::unchecked(3, 1), vec![0, 51, 255]).unwrap();
+/// let rescaling = Rescaling::new(0, 255, -1.0, 1.0).unwrap();
+///
+/// assert_eq!(to_f32_values(&attribute, None), vec![0.0, 51.0, 255.0]);
+/// assert_eq!(to_f32_values(&attribute, Some(&rescaling)), vec![-1.0, -0.6, 1.0]);
+/// ```
+pub fn to_f32_values(attribute: &Attribute, rescaling: Option<&Rescaling>) -> Vec<f32> {
+    attribute
+        .get_all()
+        .iter()
+        .map(|value| match rescaling {
+            None => *value as f32,
+            Some(rescaling) => rescaling.rescale(*value),
+        })
+        .collect()
+}
+
+fn write_png<W: Write>(
+    writer: W,
+    attribute: &Attribute,
+    depth: png::BitDepth,
+    data: &[u8],
+) -> Result<()> {
+    let size = attribute.size();
+    let mut encoder = png::Encoder::new(writer, size.width(), size.height());
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(depth);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(data)?;
+    Ok(())
+}
+
+fn write_tiff<W: Write + std::io::Seek>(
+    writer: &mut W,
+    attribute: &Attribute,
+    reference: Option<&GeoReference>,
+    data: &[f32],
+) -> Result<()> {
+    let size = attribute.size();
+    let mut encoder = TiffEncoder::new(writer)?;
+    let mut image = encoder.new_image::<Gray32Float>(size.width(), size.height())?;
+
+    if let Some(reference) = reference {
+        let directory = image.encoder();
+        let pixel_scale = [reference.pixel_size, reference.pixel_size, 0.0];
+        let tiepoint = [0.0, 0.0, 0.0, reference.longitude, reference.latitude, 0.0];
+        // Geographic model, pixel is area & WGS 84
+        let geo_keys: [u16; 16] = [1, 1, 0, 3, 1024, 0, 1, 2, 1025, 0, 1, 1, 2048, 0, 1, 4326];
+        directory.write_tag(Tag::Unknown(MODEL_PIXEL_SCALE_TAG), &pixel_scale[..])?;
+        directory.write_tag(Tag::Unknown(MODEL_TIEPOINT_TAG), &tiepoint[..])?;
+        directory.write_tag(Tag::Unknown(GEO_KEY_DIRECTORY_TAG), &geo_keys[..])?;
+    }
+
+    image.write_data(data)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::size2d::Size2d;
+    use std::fs;
+
+    #[test]
+    fn test_export_raw_r16() {
+        let values = export(RawR16, "r16");
+
+        assert_eq!(values, vec![0, 0, 1, 1, 255, 255]);
+    }
+
+    #[test]
+    fn test_export_raw_r32() {
+        let values = export(RawR32, "r32");
+
+        assert_eq!(values.len(), 12);
+        assert_eq!(&values[8..12], &255.0f32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_export_png() {
+        let values = export(Png16, "png");
+
+        assert_eq!(&values[1..4], b"PNG");
+    }
+
+    #[test]
+    fn test_export_geo_tiff() {
+        let reference = GeoReference::new(10.0, 50.0, 0.01);
+        let values = export(TiffFloat(Some(reference)), "tif");
+
+        assert_eq!(&values[0..2], b"II");
+    }
+
+    fn export(format: ExportFormat, extension: &str) -> Vec<u8> {
+        let attribute = Attribute::new("test", Size2d::unchecked(3, 1), vec![0, 1, 255]).unwrap();
+        let path = std::env::temp_dir().join(format!("omg_export_test.{}", extension));
+        let path = path.to_str().unwrap();
+
+        format.export(&attribute, None, path).unwrap();
+
+        let result = fs::read(path).unwrap();
+        fs::remove_file(path).unwrap();
+        result
+    }
+}
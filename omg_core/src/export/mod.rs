@@ -0,0 +1,3 @@
+pub mod format;
+pub mod preset;
+pub mod rescaling;
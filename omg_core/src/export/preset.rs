@@ -0,0 +1,87 @@
+use crate::data::map::Map2d;
+use crate::export::format::ExportFormat;
+use crate::export::rescaling::Rescaling;
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+/// Defines how to export an [`Attribute`](crate::data::map::attribute::Attribute) of a map.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExportPreset {
+    attribute_id: usize,
+    format: ExportFormat,
+    rescaling: Option<Rescaling>,
+    path: String,
+}
+
+impl ExportPreset {
+    /// Creates a preset, unless the path is empty:
+    ///
+    /// ```
+    ///# use omg_core::export::format::ExportFormat;
+    ///# use omg_core::export::preset::ExportPreset;
+    /// assert!(ExportPreset::new(0, ExportFormat::Png16, None, "").is_err());
+    /// assert!(ExportPreset::new(0, ExportFormat::Png16, None, "  ").is_err());
+    /// ```
+    pub fn new<S: Into<String>>(
+        attribute_id: usize,
+        format: ExportFormat,
+        rescaling: Option<Rescaling>,
+        path: S,
+    ) -> Result<ExportPreset> {
+        let path = path.into();
+
+        if path.trim().is_empty() {
+            bail!("The path of the export preset is empty!");
+        }
+
+        Ok(ExportPreset {
+            attribute_id,
+            format,
+            rescaling,
+            path,
+        })
+    }
+
+    pub fn attribute_id(&self) -> usize {
+        self.attribute_id
+    }
+
+    pub fn format(&self) -> &ExportFormat {
+        &self.format
+    }
+
+    pub fn rescaling(&self) -> Option<&Rescaling> {
+        self.rescaling.as_ref()
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Exports the attribute. The path of the preset is relative to the directory.
+    ///
+    /// Returns an error, if the map has no attribute with the id:
+    ///
+    /// ```
+    ///# use omg_core::data::map::Map2d;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::export::format::ExportFormat;
+    ///# use omg_core::export::preset::ExportPreset;
+    /// let map = Map2d::new(Size2d::unchecked(2, 2));
+    /// let preset = ExportPreset::new(0, ExportFormat::Png16, None, "elevation.png").unwrap();
+    ///
+    /// assert!(preset.export(&map, "../temp").is_err());
+    /// ```
+    pub fn export(&self, map: &Map2d, directory: &str) -> Result<()> {
+        let attribute = map.get_attribute(self.attribute_id).with_context(|| {
+            format!(
+                "Unknown attribute id {} of export preset '{}'!",
+                self.attribute_id, self.path
+            )
+        })?;
+        let path = Path::new(directory).join(&self.path);
+
+        self.format
+            .export(attribute, self.rescaling.as_ref(), &path.to_string_lossy())
+    }
+}
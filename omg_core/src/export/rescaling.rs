@@ -0,0 +1,78 @@
+use anyhow::{bail, Result};
+
+/// Maps a range of input values linearly to a range of output values during the export.
+/// E.g. from elevation values to meters.
+///
+/// Input values outside the input range are limited to it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rescaling {
+    input_min: u8,
+    input_max: u8,
+    output_min: f32,
+    output_max: f32,
+}
+
+impl Rescaling {
+    /// Creates a rescaling, unless the input range is empty:
+    ///
+    /// ```
+    ///# use omg_core::export::rescaling::Rescaling;
+    /// assert!(Rescaling::new(100, 100, 0.0, 1.0).is_err());
+    /// assert!(Rescaling::new(200, 100, 0.0, 1.0).is_err());
+    /// ```
+    pub fn new(
+        input_min: u8,
+        input_max: u8,
+        output_min: f32,
+        output_max: f32,
+    ) -> Result<Rescaling> {
+        if input_min >= input_max {
+            bail!(
+                "The rescaling's input minimum {} must be below its maximum {}!",
+                input_min,
+                input_max
+            );
+        }
+
+        Ok(Rescaling {
+            input_min,
+            input_max,
+            output_min,
+            output_max,
+        })
+    }
+
+    pub fn input_min(&self) -> u8 {
+        self.input_min
+    }
+
+    pub fn input_max(&self) -> u8 {
+        self.input_max
+    }
+
+    pub fn output_min(&self) -> f32 {
+        self.output_min
+    }
+
+    pub fn output_max(&self) -> f32 {
+        self.output_max
+    }
+
+    /// Rescales a value.
+    ///
+    /// ```
+    ///# use omg_core::export::rescaling::Rescaling;
+    /// let rescaling = Rescaling::new(76, 176, 0.0, 1000.0).unwrap();
+    ///
+    /// assert_eq!(rescaling.rescale(0), 0.0);
+    /// assert_eq!(rescaling.rescale(76), 0.0);
+    /// assert_eq!(rescaling.rescale(126), 500.0);
+    /// assert_eq!(rescaling.rescale(176), 1000.0);
+    /// assert_eq!(rescaling.rescale(255), 1000.0);
+    /// ```
+    pub fn rescale(&self, value: u8) -> f32 {
+        let value = value.clamp(self.input_min, self.input_max);
+        let factor = (value - self.input_min) as f32 / (self.input_max - self.input_min) as f32;
+        self.output_min + (self.output_max - self.output_min) * factor
+    }
+}
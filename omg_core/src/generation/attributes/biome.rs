@@ -0,0 +1,196 @@
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::transformer::transformer_nd::{find_first_value, Rule, RuleCondition};
+use crate::data::name::validate_name;
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+
+/// A named biome, which is selected if all its conditions are fulfilled.
+/// A biome without conditions is selected for all cells.
+///
+/// It is a named [`Rule`], whose inputs are the ids of the attributes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Biome {
+    name: String,
+    rule: Rule,
+}
+
+impl Biome {
+    /// Creates a biome, unless the name is invalid:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::biome::Biome;
+    /// assert!(Biome::new("", 0, Vec::new()).is_err());
+    /// assert!(Biome::new("   ", 0, Vec::new()).is_err());
+    /// ```
+    pub fn new<S: Into<String>>(
+        name: S,
+        value: u8,
+        conditions: Vec<RuleCondition>,
+    ) -> Result<Biome> {
+        let name = validate_name(name)?;
+
+        Ok(Biome {
+            name,
+            rule: Rule::new(conditions, value),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value written into the attribute.
+    pub fn value(&self) -> u8 {
+        self.rule.value()
+    }
+
+    pub fn conditions(&self) -> &[RuleCondition] {
+        self.rule.conditions()
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// Checks all conditions for a cell.
+    ///
+    /// ```
+    ///# use omg_core::data::map::Map2d;
+    ///# use omg_core::data::math::generator::shape::Shape;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::data::math::transformer::transformer_nd::RuleCondition::InShape;
+    ///# use omg_core::generation::attributes::biome::Biome;
+    /// let mut map = Map2d::new(Size2d::unchecked(3, 1));
+    /// map.create_attribute_from("temperature", vec![10, 50, 100]);
+    /// map.create_attribute_from("rainfall", vec![10, 50, 10]);
+    /// let shape = Shape::new_polygon(vec![[0, 0], [100, 0], [0, 100]]).unwrap();
+    /// let biome = Biome::new("tundra", 3, vec![InShape { x: 0, y: 1, shape }]).unwrap();
+    ///
+    /// assert!(biome.check(&map, 0));
+    /// assert!(biome.check(&map, 1));
+    /// assert!(!biome.check(&map, 2));
+    /// ```
+    pub fn check(&self, map: &Map2d, index: usize) -> bool {
+        self.rule.check(|id| get_attribute(map, id)[index])
+    }
+}
+
+/// Classifies each cell as the first [`Biome`], whose conditions are fulfilled.
+/// So biomes with a higher priority like ocean or mountains must be first.
+///
+/// Cells without a biome keep their value.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ClassifyBiomesStep {
+    target_id: usize,
+    biomes: Vec<Biome>,
+}
+
+impl ClassifyBiomesStep {
+    /// Creates the step, unless there are no biomes:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::biome::ClassifyBiomesStep;
+    /// assert!(ClassifyBiomesStep::new(0, Vec::new()).is_err());
+    /// ```
+    ///
+    /// Or the names of the biomes are not unique:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::biome::{Biome, ClassifyBiomesStep};
+    /// let biomes = vec![
+    ///     Biome::new("ocean", 0, Vec::new()).unwrap(),
+    ///     Biome::new("ocean", 1, Vec::new()).unwrap(),
+    /// ];
+    ///
+    /// assert!(ClassifyBiomesStep::new(0, biomes).is_err());
+    /// ```
+    pub fn new(target_id: usize, biomes: Vec<Biome>) -> Result<ClassifyBiomesStep> {
+        if biomes.is_empty() {
+            bail!("There are no biomes!");
+        }
+
+        let mut names = HashSet::new();
+
+        for biome in &biomes {
+            if !names.insert(biome.name()) {
+                bail!("The biome name '{}' is not unique!", biome.name());
+            }
+        }
+
+        Ok(ClassifyBiomesStep { target_id, biomes })
+    }
+
+    pub fn target_id(&self) -> usize {
+        self.target_id
+    }
+
+    pub fn biomes(&self) -> &[Biome] {
+        &self.biomes
+    }
+
+    /// Returns the value of a biome.
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::biome::{Biome, ClassifyBiomesStep};
+    /// let biomes = vec![
+    ///     Biome::new("ocean", 12, Vec::new()).unwrap(),
+    ///     Biome::new("desert", 9, Vec::new()).unwrap(),
+    /// ];
+    /// let step = ClassifyBiomesStep::new(0, biomes).unwrap();
+    ///
+    /// assert_eq!(step.get_biome_value("ocean"), Some(12));
+    /// assert_eq!(step.get_biome_value("desert"), Some(9));
+    /// assert_eq!(step.get_biome_value("forest"), None);
+    /// ```
+    pub fn get_biome_value(&self, name: &str) -> Option<u8> {
+        self.biomes
+            .iter()
+            .find(|biome| biome.name() == name)
+            .map(|biome| biome.value())
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::biome::{Biome, ClassifyBiomesStep};
+    ///# use omg_core::data::math::transformer::transformer_nd::RuleCondition::Value;
+    /// let mut map = Map2d::new(Size2d::unchecked(5, 1));
+    /// map.create_attribute_from("elevation", vec![50, 100, 100, 200, 100]);
+    /// map.create_attribute_from("rainfall", vec![0, 30, 150, 150, 240]);
+    /// map.create_attribute("biome", 99);
+    /// let condition = |input, predicate| Value { input, predicate };
+    /// let biomes = vec![
+    ///     Biome::new("ocean", 12, vec![condition(0, Predicate::Below(76))]).unwrap(),
+    ///     Biome::new("mountain", 13, vec![condition(0, Predicate::Above(160))]).unwrap(),
+    ///     Biome::new("desert", 9, vec![condition(1, Predicate::Below(40))]).unwrap(),
+    ///     Biome::new("forest", 7, vec![condition(1, Predicate::new_in_range(40, 200).unwrap())]).unwrap(),
+    /// ];
+    /// let step = ClassifyBiomesStep::new(2, biomes).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 2).get_all(), &vec![12, 9, 7, 13, 99]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Classify {} biomes into '{}' of map '{}'",
+            self.biomes.len(),
+            get_attribute(map, self.target_id).name(),
+            map.name()
+        );
+
+        let target = get_attribute(map, self.target_id);
+        let values = (0..map.size().get_area())
+            .map(|index| {
+                let rules = self.biomes.iter().map(|biome| &biome.rule);
+                find_first_value(rules, |id| get_attribute(map, id)[index]).unwrap_or(target[index])
+            })
+            .collect();
+        let attribute = get_attribute_mut(map, self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::data::map::components::{Components, Connectivity};
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::predicate::Predicate;
+
+/// Labels the connected components of an [`Attribute`](crate::data::map::attribute::Attribute)
+/// & writes their ids into another. E.g. to identify continents, islands or lakes.
+///
+/// See [`Components`] for details.
+#[derive(new, Debug, PartialEq, Clone)]
+pub struct LabelComponentsStep {
+    source_id: usize,
+    target_id: usize,
+    predicate: Predicate,
+    connectivity: Connectivity,
+    min_area: usize,
+    #[new(default)]
+    fill: Option<u8>,
+}
+
+impl LabelComponentsStep {
+    pub fn source_id(&self) -> usize {
+        self.source_id
+    }
+
+    pub fn target_id(&self) -> usize {
+        self.target_id
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    pub fn connectivity(&self) -> Connectivity {
+        self.connectivity
+    }
+
+    pub fn min_area(&self) -> usize {
+        self.min_area
+    }
+
+    pub fn fill(&self) -> Option<u8> {
+        self.fill
+    }
+
+    /// Overwrites the source values of components without an id, which are below the minimum area
+    /// or not among the largest ones. E.g. to remove specks of land in the ocean.
+    pub fn with_fill(mut self, fill: Option<u8>) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Returns a copy for a map with a different resolution, which removes components of the same size.
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> LabelComponentsStep {
+        LabelComponentsStep {
+            min_area: (self.min_area as f32 * factor_x * factor_y).round() as usize,
+            ..self.clone()
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::map::components::Connectivity::Four;
+    ///# use omg_core::data::math::predicate::Predicate::Above;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::components::LabelComponentsStep;
+    /// let mut map = Map2d::new(Size2d::unchecked(7, 1));
+    /// map.create_attribute_from("elevation", vec![90, 0, 80, 70, 0, 60, 0]);
+    /// map.create_attribute("islands", 0);
+    /// let step = LabelComponentsStep::new(0, 1, Above(50), Four, 2).with_fill(Some(10));
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 0).get_all(), &vec![10, 0, 80, 70, 0, 10, 0]);
+    /// assert_eq!(get_attribute(&map, 1).get_all(), &vec![0, 0, 1, 1, 0, 0, 0]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Label components of '{}' with {:?} into '{}' of map '{}'",
+            get_attribute(map, self.source_id).name(),
+            self.predicate,
+            get_attribute(map, self.target_id).name(),
+            map.name()
+        );
+
+        let source = get_attribute(map, self.source_id);
+        let components = Components::new(source, &self.predicate, self.connectivity, self.min_area);
+
+        info!("Found {} components", components.len());
+
+        if let Some(fill) = self.fill {
+            let removed: Vec<usize> = source
+                .get_all()
+                .iter()
+                .zip(components.labels())
+                .enumerate()
+                .filter(|(_, (value, label))| **label == 0 && self.predicate.check(**value))
+                .map(|(index, _)| index)
+                .collect();
+
+            get_attribute_mut(map, self.source_id).replace_some(removed, fill);
+        }
+
+        let attribute = get_attribute_mut(map, self.target_id);
+
+        attribute.replace_all(components.labels().clone());
+    }
+}
@@ -0,0 +1,195 @@
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::distance_transform::DistanceMetric;
+use crate::data::math::predicate::Predicate;
+use anyhow::{bail, Result};
+
+/// Calculates the distance of each cell to the nearest cell, whose source value fulfills a [`Predicate`].
+/// E.g. the distance to the coast or to rivers.
+///
+/// The distance in cells is multiplied by a scale & limited to a maximum value.
+/// Without any matching cell, all cells get the maximum value.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DistanceTransformStep {
+    source_id: usize,
+    target_id: usize,
+    predicate: Predicate,
+    metric: DistanceMetric,
+    scale: f32,
+    max_value: u8,
+}
+
+impl DistanceTransformStep {
+    /// Creates the step, but returns an error if the scale isn't positive:
+    ///
+    /// ```
+    ///# use omg_core::data::math::distance_transform::DistanceMetric::Euclidean;
+    ///# use omg_core::data::math::predicate::Predicate::Below;
+    ///# use omg_core::generation::attributes::distance::DistanceTransformStep;
+    /// assert!(DistanceTransformStep::new(0, 1, Below(10), Euclidean, 0.0, 255).is_err());
+    /// assert!(DistanceTransformStep::new(0, 1, Below(10), Euclidean, -1.0, 255).is_err());
+    /// ```
+    pub fn new(
+        source_id: usize,
+        target_id: usize,
+        predicate: Predicate,
+        metric: DistanceMetric,
+        scale: f32,
+        max_value: u8,
+    ) -> Result<DistanceTransformStep> {
+        if scale <= 0.0 {
+            bail!("The scale {} must be positive!", scale);
+        }
+
+        Ok(DistanceTransformStep {
+            source_id,
+            target_id,
+            predicate,
+            metric,
+            scale,
+            max_value,
+        })
+    }
+
+    pub fn source_id(&self) -> usize {
+        self.source_id
+    }
+
+    pub fn target_id(&self) -> usize {
+        self.target_id
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    pub fn metric(&self) -> DistanceMetric {
+        self.metric
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn max_value(&self) -> u8 {
+        self.max_value
+    }
+
+    /// Returns the largest distance in cells, which doesn't reach the maximum value.
+    /// Cells further away can't change the result.
+    ///
+    /// ```
+    ///# use omg_core::data::math::distance_transform::DistanceMetric::Euclidean;
+    ///# use omg_core::data::math::predicate::Predicate::Below;
+    ///# use omg_core::generation::attributes::distance::DistanceTransformStep;
+    /// let step = DistanceTransformStep::new(0, 1, Below(10), Euclidean, 4.0, 100).unwrap();
+    ///
+    /// assert_eq!(step.get_max_distance(), 25);
+    /// ```
+    pub fn get_max_distance(&self) -> u32 {
+        (self.max_value as f32 / self.scale).ceil() as u32
+    }
+
+    /// Returns a copy for a map with a different resolution, which results in the same values.
+    pub fn resize(&self, factor: f32) -> DistanceTransformStep {
+        DistanceTransformStep {
+            source_id: self.source_id,
+            target_id: self.target_id,
+            predicate: self.predicate.clone(),
+            metric: self.metric,
+            scale: self.scale / factor,
+            max_value: self.max_value,
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::distance_transform::DistanceMetric::Euclidean;
+    ///# use omg_core::data::math::predicate::Predicate::Below;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::distance::DistanceTransformStep;
+    /// let mut map = Map2d::new(Size2d::unchecked(6, 1));
+    /// map.create_attribute_from("elevation", vec![0, 50, 80, 90, 120, 150]);
+    /// map.create_attribute("distance", 0);
+    /// let step = DistanceTransformStep::new(0, 1, Below(60), Euclidean, 10.0, 25).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 1).get_all(), &vec![0, 0, 10, 20, 25, 25]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Calculate the {:?} distance to cells of '{}' with {:?} into '{}' of map '{}'",
+            self.metric,
+            get_attribute(map, self.source_id).name(),
+            self.predicate,
+            get_attribute(map, self.target_id).name(),
+            map.name()
+        );
+
+        let source = get_attribute(map, self.source_id);
+        let selected: Vec<bool> = source
+            .get_all()
+            .iter()
+            .map(|value| self.predicate.check(*value))
+            .collect();
+        let max_value = self.max_value as f32;
+        let values = self
+            .metric
+            .calculate(&selected, map.size())
+            .into_iter()
+            .map(|distance| (distance * self.scale).min(max_value).round() as u8)
+            .collect();
+        let attribute = get_attribute_mut(map, self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::distance_transform::DistanceMetric;
+    use crate::data::math::predicate::Predicate;
+    use crate::data::math::size2d::Size2d;
+    use crate::generation::attributes::create::CreateAttributeStep;
+    use crate::generation::step::GenerationStep;
+    use crate::generation::tests::{assert_tiles_match, create_generator_with_distortions};
+    use crate::generation::MapGenerator;
+
+    #[test]
+    fn test_tiles_match_whole_map() {
+        let size = Size2d::unchecked(100, 80);
+        let mut steps = create_generator_with_distortions(size).steps().to_vec();
+        steps.push(GenerationStep::CreateAttribute(
+            CreateAttributeStep::new("distance", 0).unwrap(),
+        ));
+        steps.push(GenerationStep::CreateAttribute(
+            CreateAttributeStep::new("chamfer", 0).unwrap(),
+        ));
+        let euclidean = DistanceTransformStep::new(
+            0,
+            1,
+            Predicate::Below(80),
+            DistanceMetric::Euclidean,
+            20.0,
+            200,
+        )
+        .unwrap();
+        let chamfer = DistanceTransformStep::new(
+            0,
+            2,
+            Predicate::Above(170),
+            DistanceMetric::Chamfer,
+            30.0,
+            255,
+        )
+        .unwrap();
+        steps.push(GenerationStep::DistanceTransform(euclidean));
+        steps.push(GenerationStep::DistanceTransform(chamfer));
+        let generator = MapGenerator::new("map", size, steps).unwrap();
+
+        assert_tiles_match(&generator, Size2d::unchecked(25, 20));
+    }
+}
@@ -1,12 +1,25 @@
 use crate::data::map::attribute::Attribute;
 use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::edge::{sample, EdgeMode};
 use crate::data::math::generator::generator1d::Generator1d;
+use crate::data::math::resampling::scale_length;
 
 /// Shifts each column or row of an [`Attribute`] based on a [`Generator1d`].
+///
+/// The shift is the generated value minus the center,
+/// so values below the center shift in the negative direction.
 #[derive(new, Debug, PartialEq, Clone)]
 pub struct Distortion1dStep {
     attribute_id: usize,
     generator: Generator1d,
+    #[new(value = "1.0")]
+    shift_factor: f32,
+    #[new(value = "0")]
+    center: u8,
+    #[new(value = "EdgeMode::Clamp")]
+    edge_mode: EdgeMode,
+    #[new(value = "false")]
+    bilinear: bool,
 }
 
 impl Distortion1dStep {
@@ -18,6 +31,69 @@ impl Distortion1dStep {
         &self.generator
     }
 
+    pub fn center(&self) -> u8 {
+        self.center
+    }
+
+    pub fn edge_mode(&self) -> EdgeMode {
+        self.edge_mode
+    }
+
+    pub fn bilinear(&self) -> bool {
+        self.bilinear
+    }
+
+    /// Defines the generated value without a shift.
+    pub fn with_center(mut self, center: u8) -> Distortion1dStep {
+        self.center = center;
+        self
+    }
+
+    /// Defines how cells outside the map are handled.
+    pub fn with_edge_mode(mut self, edge_mode: EdgeMode) -> Distortion1dStep {
+        self.edge_mode = edge_mode;
+        self
+    }
+
+    /// Enables the interpolation between cells for shifts, which aren't whole numbers.
+    pub fn with_bilinear(mut self, bilinear: bool) -> Distortion1dStep {
+        self.bilinear = bilinear;
+        self
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_factor` - Scales the input of the generator, which is orthogonal to the shift.
+    /// * `shift_factor` - Scales the generated shift.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::generator::generator1d::Generator1d::InputAsOutput;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::distortion1d::Distortion1dStep;
+    /// let mut map = Map2d::new(Size2d::unchecked(4, 2));
+    /// let values = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    /// let attribute_id = map.create_attribute_from("test", values).unwrap();
+    /// let step = Distortion1dStep::new(attribute_id, InputAsOutput).resize(1.0, 2.0);
+    ///
+    /// step.distort_along_x(&mut map);
+    ///
+    /// let attribute = get_attribute(&map, attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![1u8, 2, 3, 4, 5, 5, 5, 6]);
+    /// ```
+    pub fn resize(&self, input_factor: f32, shift_factor: f32) -> Distortion1dStep {
+        Distortion1dStep {
+            attribute_id: self.attribute_id,
+            generator: self.generator.resize(input_factor),
+            shift_factor: self.shift_factor * shift_factor,
+            center: self.center,
+            edge_mode: self.edge_mode,
+            bilinear: self.bilinear,
+        }
+    }
+
     /// Shifts each each row along the x-axis based on a [`Generator1d`].
     ///
     /// ```
@@ -36,6 +112,28 @@ impl Distortion1dStep {
     /// let attribute = get_attribute(&map, attribute_id);
     /// assert_eq!(attribute.get_all(), &vec![1u8, 2, 3, 4, 4, 5, 7, 7, 7]);
     /// ```
+    ///
+    /// With a center & wrapping at the edges:
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::edge::EdgeMode::Wrap;
+    ///# use omg_core::data::math::generator::generator1d::Generator1d::InputAsOutput;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::distortion1d::Distortion1dStep;
+    /// let size = Size2d::unchecked(3, 3);
+    /// let mut map = Map2d::new(size);
+    /// let values = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let attribute_id = map.create_attribute_from("test", values).unwrap();
+    /// let step = Distortion1dStep::new(attribute_id, InputAsOutput)
+    ///     .with_center(1)
+    ///     .with_edge_mode(Wrap);
+    ///
+    /// step.distort_along_x(&mut map);
+    ///
+    /// let attribute = get_attribute(&map, attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![2u8, 3, 1, 4, 5, 6, 9, 7, 8]);
+    /// ```
     pub fn distort_along_x(&self, map: &mut Map2d) {
         info!(
             "Distort attribute '{}' of map '{}' along the x-axis.",
@@ -81,64 +179,73 @@ impl Distortion1dStep {
     }
 
     fn distort_map_along_x(&self, map: &Map2d) -> Vec<u8> {
-        let length = map.size().get_area();
+        let size = map.size();
         let attribute = get_attribute(map, self.attribute_id);
-        let mut values = Vec::with_capacity(length);
+        let mut values = Vec::with_capacity(size.get_area());
+
+        for y in 0..size.height() {
+            let offset = self.generate_offset(map.region().y() + y);
 
-        for y in 0..map.size().height() {
-            let shift = self.generator.generate(y);
-            self.distort_row(y, shift, attribute, &mut values);
+            for x in 0..size.width() {
+                values.push(self.sample(attribute, [x, y], [-offset, 0.0]));
+            }
         }
 
         values
     }
 
     fn distort_map_along_y(&self, map: &Map2d) -> Vec<u8> {
-        let length = map.size().get_area();
+        let size = map.size();
         let attribute = get_attribute(map, self.attribute_id);
-        let mut values = vec![0; length];
-
-        for x in 0..map.size().width() {
-            let shift = self.generator.generate(x);
-            self.distort_column(x, shift, attribute, &mut values);
+        let mut values = Vec::with_capacity(size.get_area());
+        let offsets: Vec<f32> = (0..size.width())
+            .map(|x| self.generate_offset(map.region().x() + x))
+            .collect();
+
+        for y in 0..size.height() {
+            for (x, offset) in offsets.iter().enumerate() {
+                values.push(self.sample(attribute, [x as u32, y], [0.0, -offset]));
+            }
         }
 
         values
     }
 
-    fn distort_row(&self, y: u32, shift: u8, attribute: &Attribute, values: &mut Vec<u8>) {
-        let start = attribute.size().to_index_risky(0, y);
-        let start_value = attribute[start];
-
-        for _x in 0..shift {
-            values.push(start_value);
-        }
-
-        let width = attribute.size().width().saturating_sub(shift as u32) as usize;
-
-        for x in 0..width {
-            values.push(attribute[start + x]);
-        }
+    /// Returns the maximum shift. Cells are read up to this distance away.
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::generator1d::Generator1d::InputAsOutput;
+    ///# use omg_core::generation::attributes::distortion1d::Distortion1dStep;
+    /// let step = Distortion1dStep::new(0, InputAsOutput);
+    ///
+    /// assert_eq!(step.get_max_shift(), 255);
+    /// assert_eq!(step.clone().with_center(100).get_max_shift(), 155);
+    /// assert_eq!(step.with_center(200).with_bilinear(true).get_max_shift(), 201);
+    /// ```
+    pub fn get_max_shift(&self) -> u32 {
+        let max_offset = self.center.max(u8::MAX - self.center) as u32;
+        scale_length(max_offset, self.shift_factor) + self.bilinear as u32
     }
 
-    fn distort_column(&self, x: u32, shift: u8, attribute: &Attribute, values: &mut [u8]) {
-        let start = attribute.size().to_index_risky(x, 0);
-        let start_value = attribute[start];
-        let mut index = start;
-        let width = attribute.size().width() as usize;
+    fn generate_offset(&self, input: u32) -> f32 {
+        let value = self.generator.generate(input) as f32 - self.center as f32;
+        let offset = value * self.shift_factor;
 
-        for _y in 0..shift {
-            values[index] = start_value;
-            index += width;
+        if self.bilinear {
+            offset
+        } else {
+            offset.round()
         }
+    }
 
-        let remaining_height = attribute.size().height().saturating_sub(shift as u32);
-        let mut distorted_index = start;
-
-        for _y in 0..remaining_height {
-            values[index] = attribute[distorted_index];
-            index += width;
-            distorted_index += width;
-        }
+    fn sample(&self, attribute: &Attribute, cell: [u32; 2], offset: [f32; 2]) -> u8 {
+        sample(
+            attribute.get_all(),
+            *attribute.size(),
+            cell,
+            offset,
+            self.edge_mode,
+            self.bilinear,
+        )
     }
 }
@@ -1,12 +1,27 @@
 use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::edge::{sample, EdgeMode};
 use crate::data::math::generator::generator2d::Generator2d;
+use crate::data::math::resampling::scale_length;
 
 /// Distorts an [`Attribute`](crate::data::map::attribute::Attribute) along 2 dimensions.
+///
+/// Each cell reads the value shifted by the generated values minus the center,
+/// so values below the center shift in the negative direction.
 #[derive(new, Debug, PartialEq, Clone)]
 pub struct Distortion2dStep {
     attribute_id: usize,
     generator_x: Generator2d,
     generator_y: Generator2d,
+    #[new(value = "1.0")]
+    shift_factor_x: f32,
+    #[new(value = "1.0")]
+    shift_factor_y: f32,
+    #[new(value = "0")]
+    center: u8,
+    #[new(value = "EdgeMode::Clamp")]
+    edge_mode: EdgeMode,
+    #[new(value = "false")]
+    bilinear: bool,
 }
 
 impl Distortion2dStep {
@@ -22,7 +37,83 @@ impl Distortion2dStep {
         &self.generator_y
     }
 
-    // Runs the step.
+    pub fn center(&self) -> u8 {
+        self.center
+    }
+
+    pub fn edge_mode(&self) -> EdgeMode {
+        self.edge_mode
+    }
+
+    pub fn bilinear(&self) -> bool {
+        self.bilinear
+    }
+
+    /// Defines the generated value without a shift.
+    pub fn with_center(mut self, center: u8) -> Distortion2dStep {
+        self.center = center;
+        self
+    }
+
+    /// Defines how cells outside the map are handled.
+    pub fn with_edge_mode(mut self, edge_mode: EdgeMode) -> Distortion2dStep {
+        self.edge_mode = edge_mode;
+        self
+    }
+
+    /// Enables the interpolation between cells for shifts, which aren't whole numbers.
+    pub fn with_bilinear(mut self, bilinear: bool) -> Distortion2dStep {
+        self.bilinear = bilinear;
+        self
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    /// The inputs of the generators & the generated shifts are scaled by a factor per axis.
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> Distortion2dStep {
+        Distortion2dStep {
+            attribute_id: self.attribute_id,
+            generator_x: self.generator_x.resize(factor_x, factor_y),
+            generator_y: self.generator_y.resize(factor_x, factor_y),
+            shift_factor_x: self.shift_factor_x * factor_x,
+            shift_factor_y: self.shift_factor_y * factor_y,
+            center: self.center,
+            edge_mode: self.edge_mode,
+            bilinear: self.bilinear,
+        }
+    }
+
+    /// Returns the maximum shift along both axes. Cells are read up to this distance away.
+    pub fn get_max_shift(&self) -> u32 {
+        let max_offset = self.center.max(u8::MAX - self.center) as u32;
+        let max_shift = scale_length(max_offset, self.shift_factor_x)
+            .max(scale_length(max_offset, self.shift_factor_y));
+        max_shift + self.bilinear as u32
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::edge::EdgeMode::Mirror;
+    ///# use omg_core::data::math::generator::generator1d::Generator1d::InputAsOutput;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::distortion2d::Distortion2dStep;
+    /// let size = Size2d::unchecked(3, 2);
+    /// let mut map = Map2d::new(size);
+    /// let values = vec![1, 2, 3, 4, 5, 6];
+    /// let attribute_id = map.create_attribute_from("test", values).unwrap();
+    /// let generator_x = Generator2d::new_apply_to_x(InputAsOutput);
+    /// let generator_y = Generator2d::new_apply_to_x(InputAsOutput);
+    /// let step = Distortion2dStep::new(attribute_id, generator_x, generator_y)
+    ///     .with_center(1)
+    ///     .with_edge_mode(Mirror);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let attribute = get_attribute(&map, attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![1u8, 2, 6, 1, 5, 6]);
+    /// ```
     pub fn run(&self, map: &mut Map2d) {
         info!(
             "Distort attribute '{}' of map '{}' in 2 dimensions.",
@@ -38,21 +129,42 @@ impl Distortion2dStep {
 
     fn distort_map(&self, map: &Map2d) -> Vec<u8> {
         let size = map.size();
-        let length = size.get_area();
         let attribute = get_attribute(map, self.attribute_id);
-        let mut values = Vec::with_capacity(length);
+        let mut values = Vec::with_capacity(size.get_area());
 
         for y in 0..size.height() {
             for x in 0..size.width() {
-                let shift_x = self.generator_x.generate(x, y) as u32;
-                let shift_y = self.generator_y.generate(x, y) as u32;
-                let distorted_x = x + shift_x;
-                let distorted_y = y + shift_y;
-                let index = size.saturating_to_index(distorted_x, distorted_y);
-                values.push(attribute[index]);
+                let [world_x, world_y] = map.to_world(x, y);
+                let offset_x = self.generate_offset(&self.generator_x, world_x, world_y);
+                let offset_x = self.scale_offset(offset_x, self.shift_factor_x);
+                let offset_y = self.generate_offset(&self.generator_y, world_x, world_y);
+                let offset_y = self.scale_offset(offset_y, self.shift_factor_y);
+
+                values.push(sample(
+                    attribute.get_all(),
+                    size,
+                    [x, y],
+                    [offset_x, offset_y],
+                    self.edge_mode,
+                    self.bilinear,
+                ));
             }
         }
 
         values
     }
+
+    fn generate_offset(&self, generator: &Generator2d, x: u32, y: u32) -> f32 {
+        generator.generate(x, y) as f32 - self.center as f32
+    }
+
+    fn scale_offset(&self, offset: f32, factor: f32) -> f32 {
+        let offset = offset * factor;
+
+        if self.bilinear {
+            offset
+        } else {
+            offset.round()
+        }
+    }
 }
@@ -0,0 +1,133 @@
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::expression::Expression;
+use anyhow::Result;
+
+/// Calculates the value of each cell with a formula, which is parsed once.
+/// See [`parser`](crate::data::math::expression::parser) for its syntax.
+///
+/// The result is rounded & clamped to 0-255.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExpressionStep {
+    formula: String,
+    target_id: usize,
+    expression: Expression,
+    factor_x: f32,
+    factor_y: f32,
+}
+
+impl ExpressionStep {
+    /// Parses the formula with the names of the attributes, but returns an error if it is invalid:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::expression::ExpressionStep;
+    /// let attributes = ["elevation", "rainfall"];
+    ///
+    /// assert!(ExpressionStep::new("elevation - rainfall / 4", 0, &attributes).is_ok());
+    /// assert!(ExpressionStep::new("elevation - temperature", 0, &attributes).is_err());
+    /// assert!(ExpressionStep::new("elevation -", 0, &attributes).is_err());
+    /// ```
+    pub fn new<F: Into<String>, S: AsRef<str>>(
+        formula: F,
+        target_id: usize,
+        attributes: &[S],
+    ) -> Result<ExpressionStep> {
+        let formula = formula.into();
+        let expression = Expression::parse(&formula, attributes)?;
+
+        Ok(ExpressionStep {
+            formula,
+            target_id,
+            expression,
+            factor_x: 1.0,
+            factor_y: 1.0,
+        })
+    }
+
+    pub fn formula(&self) -> &str {
+        &self.formula
+    }
+
+    pub fn target_id(&self) -> usize {
+        self.target_id
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    /// The factor, by which the x coordinate is divided before the formula sees it.
+    pub fn factor_x(&self) -> f32 {
+        self.factor_x
+    }
+
+    /// The factor, by which the y coordinate is divided before the formula sees it.
+    pub fn factor_y(&self) -> f32 {
+        self.factor_y
+    }
+
+    pub fn with_factors(mut self, factor_x: f32, factor_y: f32) -> ExpressionStep {
+        self.factor_x = factor_x;
+        self.factor_y = factor_y;
+        self
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    /// The coordinates are divided by the factors, so the formula sees the same values.
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::expression::ExpressionStep;
+    /// let step = ExpressionStep::new("x + y", 0, &["result"]).unwrap();
+    /// let resized = step.resize(2.0, 4.0).resize(1.5, 0.5);
+    ///
+    /// assert_eq!(resized.factor_x(), 3.0);
+    /// assert_eq!(resized.factor_y(), 2.0);
+    /// ```
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> ExpressionStep {
+        self.clone()
+            .with_factors(self.factor_x * factor_x, self.factor_y * factor_y)
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::expression::ExpressionStep;
+    /// let mut map = Map2d::new(Size2d::unchecked(3, 2));
+    /// map.create_attribute_from("elevation", vec![100, 200, 250, 0, 10, 20]);
+    /// map.create_attribute("result", 0);
+    /// let step = ExpressionStep::new("elevation * 1.5 - x * 10 + y", 1, &["elevation", "result"]).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 1).get_all(), &vec![150, 255, 255, 1, 6, 11]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Evaluate '{}' into '{}' of map '{}'",
+            self.formula,
+            get_attribute(map, self.target_id).name(),
+            map.name()
+        );
+
+        let size = map.size();
+        let mut values = Vec::with_capacity(size.get_area());
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let [world_x, world_y] = map.to_world(x, y);
+                let position = [
+                    world_x as f32 / self.factor_x,
+                    world_y as f32 / self.factor_y,
+                ];
+                let index = size.to_index_risky(x, y);
+                let value = self.expression.evaluate(map, index, position);
+                values.push(value.round().clamp(0.0, 255.0) as u8);
+            }
+        }
+
+        let attribute = get_attribute_mut(map, self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
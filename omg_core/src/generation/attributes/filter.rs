@@ -0,0 +1,104 @@
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::filter::Filter;
+
+/// Filters an [`Attribute`](crate::data::map::attribute::Attribute) and writes into another.
+#[derive(new, Debug, PartialEq, Clone)]
+pub struct FilterStep {
+    source_id: usize,
+    target_id: usize,
+    filter: Filter,
+}
+
+impl FilterStep {
+    pub fn source_id(&self) -> usize {
+        self.source_id
+    }
+
+    pub fn target_id(&self) -> usize {
+        self.target_id
+    }
+
+    pub fn filter(&self) -> &Filter {
+        &self.filter
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    pub fn resize(&self, factor: f32) -> FilterStep {
+        FilterStep {
+            source_id: self.source_id,
+            target_id: self.target_id,
+            filter: self.filter.resize(factor),
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::filter::Filter::Dilate;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::filter::FilterStep;
+    /// let mut map = Map2d::new(Size2d::unchecked(3, 3));
+    /// map.create_attribute_from("source", vec![0, 0, 0, 0, 0, 0, 0, 0, 9]);
+    /// map.create_attribute("target", 1);
+    /// let step = FilterStep::new(0, 1, Dilate(1));
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 0).get_all(), &vec![0, 0, 0, 0, 0, 0, 0, 0, 9]);
+    /// assert_eq!(get_attribute(&map, 1).get_all(), &vec![0, 0, 0, 0, 9, 9, 0, 9, 9]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Filter '{}' with {:?} into '{}' of map '{}'",
+            get_attribute(map, self.source_id).name(),
+            self.filter,
+            get_attribute(map, self.target_id).name(),
+            map.name()
+        );
+
+        let source = get_attribute(map, self.source_id);
+        let values = self.filter.filter(source.get_all(), map.size());
+        let attribute = get_attribute_mut(map, self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::filter::Filter;
+    use crate::data::math::size2d::Size2d;
+    use crate::generation::attributes::create::CreateAttributeStep;
+    use crate::generation::step::GenerationStep;
+    use crate::generation::tests::{assert_tiles_match, create_generator_with_distortions};
+    use crate::generation::MapGenerator;
+
+    #[test]
+    fn test_tiles_match_whole_map() {
+        let size = Size2d::unchecked(60, 50);
+        let mut steps = create_generator_with_distortions(size).steps().to_vec();
+        steps.push(GenerationStep::CreateAttribute(
+            CreateAttributeStep::new("blurred", 0).unwrap(),
+        ));
+        steps.push(GenerationStep::Filter(FilterStep::new(
+            0,
+            1,
+            Filter::GaussianBlur(3),
+        )));
+        steps.push(GenerationStep::Filter(FilterStep::new(
+            1,
+            1,
+            Filter::Median(2),
+        )));
+        steps.push(GenerationStep::Filter(FilterStep::new(
+            0,
+            0,
+            Filter::Close(1),
+        )));
+        let generator = MapGenerator::new("map", size, steps).unwrap();
+
+        assert_tiles_match(&generator, Size2d::unchecked(25, 20));
+    }
+}
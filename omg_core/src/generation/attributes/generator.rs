@@ -1,7 +1,8 @@
 use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::blend::BlendMode;
 use crate::data::math::generator::generator2d::Generator2d;
 use crate::data::name::validate_name;
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 /// Modifies an [`Attribute`](crate::data::map::attribute::Attribute) with the values generated by a [`Generator2d`].
 #[derive(Debug, PartialEq, Clone)]
@@ -38,6 +39,15 @@ impl GeneratorStep {
         &self.generator
     }
 
+    /// Returns a copy for a map with a different resolution.
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> GeneratorStep {
+        GeneratorStep {
+            name: self.name.clone(),
+            attribute_id: self.attribute_id,
+            generator: self.generator.resize(factor_x, factor_y),
+        }
+    }
+
     // Adds the values.
     ///
     /// ```
@@ -65,18 +75,7 @@ impl GeneratorStep {
             map.name()
         );
 
-        let size = map.size();
-        let attribute = get_attribute_mut(map, self.attribute_id);
-        let mut index = 0;
-
-        for y in 0..size.height() {
-            for x in 0..size.width() {
-                let value = self.generator.generate(x, y);
-                let sum = attribute[index].saturating_add(value);
-                attribute[index] = sum;
-                index += 1;
-            }
-        }
+        apply_generator(map, self.attribute_id, &self.generator, BlendMode::Add, 1.0);
     }
 
     // Subtracts the values.
@@ -106,17 +105,139 @@ impl GeneratorStep {
             map.name()
         );
 
-        let size = map.size();
-        let attribute = get_attribute_mut(map, self.attribute_id);
-        let mut index = 0;
-
-        for y in 0..size.height() {
-            for x in 0..size.width() {
-                let value = self.generator.generate(x, y);
-                let sum = attribute[index].saturating_sub(value);
-                attribute[index] = sum;
-                index += 1;
-            }
+        apply_generator(map, self.attribute_id, &self.generator, BlendMode::Sub, 1.0);
+    }
+}
+
+/// Combines an [`Attribute`](crate::data::map::attribute::Attribute) with the values generated by a [`Generator2d`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ApplyGeneratorStep {
+    name: String,
+    attribute_id: usize,
+    generator: Generator2d,
+    mode: BlendMode,
+    weight: f32,
+}
+
+impl ApplyGeneratorStep {
+    /// Creates the step, but returns an error if the weight isn't between 0 & 1:
+    ///
+    /// ```
+    ///# use omg_core::data::math::blend::BlendMode::Max;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d::IndexGenerator;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::generator::ApplyGeneratorStep;
+    /// let generator = IndexGenerator(Size2d::unchecked(2, 3));
+    ///
+    /// assert!(ApplyGeneratorStep::new("test", 0, generator.clone(), Max, -0.1).is_err());
+    /// assert!(ApplyGeneratorStep::new("test", 0, generator, Max, 1.1).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - Interpolates between the old & the combined value, e.g. to make the generated values less visible.
+    pub fn new<S: Into<String>>(
+        name: S,
+        attribute_id: usize,
+        generator: Generator2d,
+        mode: BlendMode,
+        weight: f32,
+    ) -> Result<ApplyGeneratorStep> {
+        let name = validate_name(name)?;
+
+        if !(0.0..=1.0).contains(&weight) {
+            bail!("The weight {} must be between 0 & 1!", weight);
+        }
+
+        Ok(ApplyGeneratorStep {
+            name,
+            attribute_id,
+            generator,
+            mode,
+            weight,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn attribute_id(&self) -> usize {
+        self.attribute_id
+    }
+
+    pub fn generator(&self) -> &Generator2d {
+        &self.generator
+    }
+
+    pub fn mode(&self) -> BlendMode {
+        self.mode
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> ApplyGeneratorStep {
+        ApplyGeneratorStep {
+            generator: self.generator.resize(factor_x, factor_y),
+            ..self.clone()
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::blend::BlendMode::Max;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d::IndexGenerator;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::generator::ApplyGeneratorStep;
+    /// let size = Size2d::unchecked(2, 3);
+    /// let mut map = Map2d::new(size);
+    /// let attribute_id = map.create_attribute("elevation", 2).unwrap();
+    /// let step = ApplyGeneratorStep::new("test", attribute_id, IndexGenerator(size), Max, 0.5).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, attribute_id).get_all(), &vec![2u8, 2, 2, 2, 3, 3]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Apply '{}' to attribute '{}' of map '{}' with {:?}",
+            self.name,
+            get_attribute(map, self.attribute_id).name(),
+            map.name(),
+            self.mode
+        );
+
+        apply_generator(
+            map,
+            self.attribute_id,
+            &self.generator,
+            self.mode,
+            self.weight,
+        );
+    }
+}
+
+fn apply_generator(
+    map: &mut Map2d,
+    attribute_id: usize,
+    generator: &Generator2d,
+    mode: BlendMode,
+    weight: f32,
+) {
+    let region = map.region();
+    let attribute = get_attribute_mut(map, attribute_id);
+    let mut index = 0;
+
+    for y in region.y()..region.end_y() {
+        for x in region.x()..region.end_x() {
+            let value = generator.generate(x, y);
+            attribute[index] = mode.blend_weighted(attribute[index], value, weight);
+            index += 1;
         }
     }
 }
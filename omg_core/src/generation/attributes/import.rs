@@ -0,0 +1,190 @@
+use crate::data::map::attribute::Attribute;
+use crate::data::map::Map2d;
+use crate::data::math::resampling::{resample_region, ResamplingFilter};
+use crate::data::name::validate_name;
+use crate::import::format::ImportFormat;
+use crate::import::range::ValueRange;
+use anyhow::Result;
+
+/// Imports an [`Attribute`](crate::data::map::attribute::Attribute) from a file, e.g. a heightmap.
+/// Overwrites the attribute with the id or creates a new one with the name.
+///
+/// The file is read & validated, when the step is created, so running it only resamples
+/// the imported values to the size of the world.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImportAttributeStep {
+    attribute: String,
+    attribute_id: usize,
+    path: String,
+    format: ImportFormat,
+    range: Option<ValueRange>,
+    filter: ResamplingFilter,
+    source: Attribute,
+}
+
+impl ImportAttributeStep {
+    /// Creates the step & reads the file, but returns an error if the name is invalid:
+    ///
+    /// ```
+    ///# use omg_core::data::math::resampling::ResamplingFilter::Nearest;
+    ///# use omg_core::generation::attributes::import::ImportAttributeStep;
+    ///# use omg_core::import::format::ImportFormat::Csv;
+    /// let path = std::env::temp_dir().join("omg_import_step_invalid.csv");
+    /// let path = path.to_str().unwrap();
+    /// std::fs::write(path, "1, 2\n3, 4").unwrap();
+    ///
+    /// assert!(ImportAttributeStep::new("", 0, path, Csv, None, Nearest).is_err());
+    /// assert!(ImportAttributeStep::new("   ", 0, path, Csv, None, Nearest).is_err());
+    ///# std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// Or if the file can't be imported:
+    ///
+    /// ```
+    ///# use omg_core::data::math::resampling::ResamplingFilter::Nearest;
+    ///# use omg_core::generation::attributes::import::ImportAttributeStep;
+    ///# use omg_core::import::format::ImportFormat::{Csv, Png};
+    /// let path = std::env::temp_dir().join("omg_import_step_corrupt.png");
+    /// let path = path.to_str().unwrap();
+    /// std::fs::write(path, "no png").unwrap();
+    ///
+    /// assert!(ImportAttributeStep::new("elevation", 0, "unknown.png", Png, None, Nearest).is_err());
+    /// assert!(ImportAttributeStep::new("elevation", 0, path, Png, None, Nearest).is_err());
+    ///# std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn new<A: Into<String>, P: Into<String>>(
+        attribute: A,
+        attribute_id: usize,
+        path: P,
+        format: ImportFormat,
+        range: Option<ValueRange>,
+        filter: ResamplingFilter,
+    ) -> Result<ImportAttributeStep> {
+        let attribute = validate_name(attribute)?;
+        let path = path.into();
+        let source = format.import(&attribute, &path, range.as_ref())?;
+
+        Ok(ImportAttributeStep {
+            attribute,
+            attribute_id,
+            path,
+            format,
+            range,
+            filter,
+            source,
+        })
+    }
+
+    /// The name of the attribute, if it is created by the step.
+    pub fn attribute(&self) -> &str {
+        &self.attribute
+    }
+
+    pub fn attribute_id(&self) -> usize {
+        self.attribute_id
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn format(&self) -> &ImportFormat {
+        &self.format
+    }
+
+    pub fn range(&self) -> Option<&ValueRange> {
+        self.range.as_ref()
+    }
+
+    pub fn filter(&self) -> ResamplingFilter {
+        self.filter
+    }
+
+    /// Returns the imported values before resampling.
+    pub fn source(&self) -> &Attribute {
+        &self.source
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::resampling::ResamplingFilter::Bilinear;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::import::ImportAttributeStep;
+    ///# use omg_core::import::format::ImportFormat::Csv;
+    /// let path = std::env::temp_dir().join("omg_import_step.csv");
+    /// let path = path.to_str().unwrap();
+    /// std::fs::write(path, "0, 100").unwrap();
+    /// let step = ImportAttributeStep::new("elevation", 0, path, Csv, None, Bilinear).unwrap();
+    ///# std::fs::remove_file(path).unwrap();
+    /// let mut map = Map2d::new(Size2d::unchecked(4, 1));
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let attribute = get_attribute(&map, 0);
+    /// assert_eq!(attribute.name(), "elevation");
+    /// assert_eq!(attribute.get_all(), &vec![0, 25, 75, 100]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Import attribute '{}' of map '{}' from '{}'",
+            self.attribute,
+            map.name(),
+            self.path
+        );
+
+        let values = resample_region(
+            self.source.get_all(),
+            *self.source.size(),
+            map.world_size(),
+            map.region(),
+            self.filter,
+        );
+
+        match map.get_attribute_mut(self.attribute_id) {
+            Some(attribute) => attribute.replace_all(values),
+            None => {
+                map.create_attribute_from(self.attribute.clone(), values)
+                    .expect("Failed to create the attribute!");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::map::get_attribute;
+    use crate::data::math::resampling::ResamplingFilter::Nearest;
+    use crate::data::math::size2d::Size2d;
+    use crate::import::format::ImportFormat::{Csv, RawR16};
+    use std::fs;
+
+    #[test]
+    fn test_overwrite_existing_attribute() {
+        let path = std::env::temp_dir().join("omg_import_step_overwrite.csv");
+        let path = path.to_str().unwrap();
+        fs::write(path, "10\n20").unwrap();
+        let step = ImportAttributeStep::new("elevation", 0, path, Csv, None, Nearest).unwrap();
+        let mut map = Map2d::new(Size2d::unchecked(1, 4));
+        map.create_attribute("elevation", 42).unwrap();
+
+        fs::remove_file(path).unwrap();
+        step.run(&mut map);
+
+        assert_eq!(map.get_all().len(), 1);
+        assert_eq!(get_attribute(&map, 0).get_all(), &vec![10, 10, 20, 20]);
+    }
+
+    #[test]
+    fn test_raw_file_with_wrong_length() {
+        let path = std::env::temp_dir().join("omg_import_step_wrong_length.r16");
+        let path = path.to_str().unwrap();
+        fs::write(path, [0u8; 5]).unwrap();
+        let format = RawR16(Size2d::unchecked(2, 2));
+
+        assert!(ImportAttributeStep::new("elevation", 0, path, format, None, Nearest).is_err());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,237 @@
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::interpolation::lerp;
+use crate::data::math::predicate::Predicate;
+use crate::generation::step::{GenerationStep, StepContext};
+
+/// Restricts another [`GenerationStep`] to the cells, where a mask fulfills a predicate.
+/// E.g. to add mountain noise only on land.
+///
+/// The other step runs on the whole map, but only the selected cells of the existing
+/// attributes, which it can change, keep its changes. Attributes created by it are kept unchanged.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MaskedStep {
+    mask_id: usize,
+    predicate: Predicate,
+    soft: bool,
+    step: Box<GenerationStep>,
+}
+
+impl MaskedStep {
+    pub fn new(mask_id: usize, predicate: Predicate, step: GenerationStep) -> MaskedStep {
+        MaskedStep {
+            mask_id,
+            predicate,
+            soft: false,
+            step: Box::new(step),
+        }
+    }
+
+    pub fn mask_id(&self) -> usize {
+        self.mask_id
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    /// Are the changes blended by the distance of the mask to the predicate's boundary instead of fully applied?
+    pub fn soft(&self) -> bool {
+        self.soft
+    }
+
+    pub fn step(&self) -> &GenerationStep {
+        &self.step
+    }
+
+    pub fn with_soft(mut self, soft: bool) -> MaskedStep {
+        self.soft = soft;
+        self
+    }
+
+    /// Returns the context of the other step.
+    pub fn get_context(&self) -> StepContext {
+        self.step.get_context()
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> MaskedStep {
+        MaskedStep {
+            step: Box::new(self.step.resize(factor_x, factor_y)),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the fraction of the change, which is applied to a cell.
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::generation::attributes::mask::MaskedStep;
+    ///# use omg_core::generation::step::GenerationStep;
+    /// let step = MaskedStep::new(0, Predicate::Above(155), GenerationStep::debug("test"));
+    ///
+    /// assert_eq!(step.get_weight(155), 0.0);
+    /// assert_eq!(step.get_weight(156), 1.0);
+    /// ```
+    ///
+    /// A soft mask ramps the weight up from the boundary of the predicate:
+    ///
+    /// ```
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::generation::attributes::mask::MaskedStep;
+    ///# use omg_core::generation::step::GenerationStep;
+    /// let above = MaskedStep::new(0, Predicate::Above(155), GenerationStep::debug("test"))
+    ///     .with_soft(true);
+    ///
+    /// assert_eq!(above.get_weight(155), 0.0);
+    /// assert_eq!(above.get_weight(156), 0.01);
+    /// assert_eq!(above.get_weight(205), 0.5);
+    /// assert_eq!(above.get_weight(255), 1.0);
+    ///
+    /// let below = MaskedStep::new(0, Predicate::Below(100), GenerationStep::debug("test"))
+    ///     .with_soft(true);
+    ///
+    /// assert_eq!(below.get_weight(100), 0.0);
+    /// assert_eq!(below.get_weight(50), 0.5);
+    /// assert_eq!(below.get_weight(0), 1.0);
+    ///
+    /// let range = Predicate::new_in_range(10, 16).unwrap();
+    /// let range = MaskedStep::new(0, range, GenerationStep::debug("test")).with_soft(true);
+    ///
+    /// assert_eq!(range.get_weight(9), 0.0);
+    /// assert_eq!(range.get_weight(10), 0.25);
+    /// assert_eq!(range.get_weight(13), 1.0);
+    /// assert_eq!(range.get_weight(16), 0.25);
+    /// ```
+    ///
+    /// Predicates without a range of values like [`Predicate::Equal`] have no ramp.
+    pub fn get_weight(&self, mask: u8) -> f32 {
+        if !self.predicate.check(mask) {
+            return 0.0;
+        } else if !self.soft {
+            return 1.0;
+        }
+
+        let (distance, max_distance) = match self.predicate {
+            Predicate::Above(threshold) => (mask - threshold, 255 - threshold),
+            Predicate::Below(threshold) => (threshold - mask, threshold),
+            Predicate::InRange { min, max } => {
+                let distance = (mask - min).min(max - mask) + 1;
+                (distance, (max - min) / 2 + 1)
+            }
+            Predicate::Equal(_) | Predicate::OneOf(_) => return 1.0,
+        };
+
+        distance as f32 / max_distance as f32
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::generator::generator2d::Generator2d::IndexGenerator;
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::generator::GeneratorStep;
+    ///# use omg_core::generation::attributes::mask::MaskedStep;
+    ///# use omg_core::generation::step::GenerationStep;
+    /// let size = Size2d::unchecked(4, 1);
+    /// let mut map = Map2d::new(size);
+    /// map.create_attribute_from("elevation", vec![50, 100, 150, 255]);
+    /// let add = GeneratorStep::new("index", 0, IndexGenerator(size)).unwrap();
+    /// let step = MaskedStep::new(0, Predicate::Above(76), GenerationStep::GeneratorAdd(add));
+    ///
+    /// step.clone().run(&mut map);
+    /// assert_eq!(get_attribute(&map, 0).get_all(), &vec![50, 101, 152, 255]);
+    /// ```
+    ///
+    /// A soft mask blends the changes:
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::predicate::Predicate;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::data::math::transformer::transformer2d::Transformer2d::Const;
+    ///# use omg_core::generation::attributes::mask::MaskedStep;
+    ///# use omg_core::generation::attributes::transformer::TransformAttribute2dStep;
+    ///# use omg_core::generation::step::GenerationStep;
+    /// let mut map = Map2d::new(Size2d::unchecked(4, 1));
+    /// map.create_attribute_from("elevation", vec![55, 105, 155, 255]);
+    /// map.create_attribute("hills", 0).unwrap();
+    /// let set = TransformAttribute2dStep::new("set", 0, 1, 1, Const(100)).unwrap();
+    /// let step = MaskedStep::new(0, Predicate::Above(55), GenerationStep::TransformAttribute2d(set))
+    ///     .with_soft(true);
+    ///
+    /// step.run(&mut map);
+    /// assert_eq!(get_attribute(&map, 1).get_all(), &vec![0, 25, 50, 100]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Mask the next step with '{}' of map '{}'",
+            get_attribute(map, self.mask_id).name(),
+            map.name()
+        );
+
+        let weights: Vec<f32> = get_attribute(map, self.mask_id)
+            .get_all()
+            .iter()
+            .map(|mask| self.get_weight(*mask))
+            .collect();
+        let mut target_ids = self.step.get_target_ids(map);
+        target_ids.sort_unstable();
+        target_ids.dedup();
+        let previous: Vec<(usize, Vec<u8>)> = target_ids
+            .into_iter()
+            .filter_map(|id| {
+                map.get_attribute(id)
+                    .map(|attribute| (id, attribute.get_all().clone()))
+            })
+            .collect();
+
+        self.step.run(map);
+
+        for (id, old_values) in previous {
+            let attribute = get_attribute_mut(map, id);
+            let values = attribute
+                .get_all()
+                .iter()
+                .zip(old_values)
+                .zip(&weights)
+                .map(|((new, old), weight)| lerp(old, *new, *weight))
+                .collect();
+
+            attribute.replace_all(values);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::filter::Filter;
+    use crate::data::math::generator::generator2d::Generator2d;
+    use crate::data::math::generator::noise::Noise;
+    use crate::data::math::size2d::Size2d;
+    use crate::generation::attributes::filter::FilterStep;
+    use crate::generation::attributes::generator::GeneratorStep;
+    use crate::generation::tests::{assert_tiles_match, create_generator_with_distortions};
+    use crate::generation::MapGenerator;
+
+    #[test]
+    fn test_tiles_match_whole_map() {
+        let size = Size2d::unchecked(60, 50);
+        let mut steps = create_generator_with_distortions(size).steps().to_vec();
+        let filter = FilterStep::new(0, 0, Filter::GaussianBlur(3));
+        let noise = Generator2d::Noise2d(Noise::new(4, 7, 0, 80).unwrap());
+        let add = GeneratorStep::new("noise", 0, noise).unwrap();
+        let masked_filter =
+            MaskedStep::new(0, Predicate::Below(100), GenerationStep::Filter(filter));
+        let masked_add =
+            MaskedStep::new(0, Predicate::Above(120), GenerationStep::GeneratorAdd(add))
+                .with_soft(true);
+        steps.push(GenerationStep::Masked(masked_filter));
+        steps.push(GenerationStep::Masked(masked_add));
+        let generator = MapGenerator::new("map", size, steps).unwrap();
+
+        assert_tiles_match(&generator, Size2d::unchecked(25, 20));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::data::map::{get_attribute, Map2d};
+use crate::data::name::validate_name;
+use anyhow::{bail, Result};
+use MathOperation::*;
+
+/// An operation, which combines the values of multiple attributes from left to right.
+#[derive(Debug, PartialEq, Clone)]
+pub enum MathOperation {
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation::Add;
+    /// assert_eq!(Add.calculate(&[100.0, 50.0, 10.0]), 160.0);
+    /// ```
+    Add,
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation::Sub;
+    /// assert_eq!(Sub.calculate(&[100.0, 50.0, 10.0]), 40.0);
+    /// ```
+    Sub,
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation::Mul;
+    /// assert_eq!(Mul.calculate(&[100.0, 5.0, 2.0]), 1000.0);
+    /// ```
+    Mul,
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation::Div;
+    /// assert_eq!(Div.calculate(&[100.0, 5.0, 2.0]), 10.0);
+    /// ```
+    Div,
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation::Min;
+    /// assert_eq!(Min.calculate(&[100.0, 5.0, 20.0]), 5.0);
+    /// ```
+    Min,
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation::Max;
+    /// assert_eq!(Max.calculate(&[100.0, 5.0, 200.0]), 200.0);
+    /// ```
+    Max,
+    /// The absolute difference.
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation::Difference;
+    /// assert_eq!(Difference.calculate(&[100.0, 150.0]), 50.0);
+    /// assert_eq!(Difference.calculate(&[100.0, 150.0, 70.0]), 20.0);
+    /// ```
+    Difference,
+    /// Averages the values with one weight per attribute.
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation;
+    /// let operation = MathOperation::new_weighted_average(vec![3.0, 1.0]).unwrap();
+    ///
+    /// assert_eq!(operation.calculate(&[100.0, 200.0]), 125.0);
+    /// ```
+    WeightedAverage(Vec<f32>),
+}
+
+impl MathOperation {
+    /// Returns a weighted average, unless a weight is negative:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation;
+    /// assert!(MathOperation::new_weighted_average(vec![1.0, -1.0]).is_err());
+    /// ```
+    ///
+    /// Or not a finite number:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation;
+    /// assert!(MathOperation::new_weighted_average(vec![1.0, f32::NAN]).is_err());
+    /// assert!(MathOperation::new_weighted_average(vec![1.0, f32::INFINITY]).is_err());
+    /// ```
+    ///
+    /// Or all weights are 0:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::MathOperation;
+    /// assert!(MathOperation::new_weighted_average(vec![0.0, 0.0]).is_err());
+    /// ```
+    pub fn new_weighted_average(weights: Vec<f32>) -> Result<MathOperation> {
+        if weights.iter().any(|weight| !weight.is_finite()) {
+            bail!("The weights {:?} must be finite!", weights);
+        } else if weights.iter().any(|weight| *weight < 0.0) {
+            bail!("The weights {:?} must not be negative!", weights);
+        } else if weights.iter().sum::<f32>() <= 0.0 {
+            bail!("The sum of the weights {:?} must be greater 0!", weights);
+        }
+
+        Ok(WeightedAverage(weights))
+    }
+
+    /// Calculates the result without any limits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no values or a weighted average gets a different number of values than weights.
+    pub fn calculate(&self, values: &[f32]) -> f32 {
+        let first = values[0];
+        let rest = values[1..].iter();
+
+        match self {
+            Add => rest.fold(first, |a, b| a + b),
+            Sub => rest.fold(first, |a, b| a - b),
+            Mul => rest.fold(first, |a, b| a * b),
+            Div => rest.fold(first, |a, b| a / b),
+            Min => rest.fold(first, |a, b| a.min(*b)),
+            Max => rest.fold(first, |a, b| a.max(*b)),
+            Difference => rest.fold(first, |a, b| (a - b).abs()),
+            WeightedAverage(weights) => {
+                assert_eq!(weights.len(), values.len(), "Wrong number of values!");
+                let sum: f32 = weights.iter().zip(values).map(|(w, v)| w * v).sum();
+                sum / weights.iter().sum::<f32>()
+            }
+        }
+    }
+}
+
+/// How results outside of 0-255 are handled.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Overflow {
+    /// Limits the result to 0-255. Division by zero results in 255 & zero by zero in 0.
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::Overflow::Saturate;
+    /// assert_eq!(Saturate.apply(-10.0), 0);
+    /// assert_eq!(Saturate.apply(100.4), 100);
+    /// assert_eq!(Saturate.apply(300.0), 255);
+    /// assert_eq!(Saturate.apply(f32::INFINITY), 255);
+    /// assert_eq!(Saturate.apply(f32::NAN), 0);
+    /// ```
+    Saturate,
+    /// Continues at the other end like integer arithmetic. Division by zero results in 0.
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::Overflow::Wrap;
+    /// assert_eq!(Wrap.apply(-10.0), 246);
+    /// assert_eq!(Wrap.apply(100.4), 100);
+    /// assert_eq!(Wrap.apply(300.0), 44);
+    /// assert_eq!(Wrap.apply(f32::INFINITY), 0);
+    /// ```
+    Wrap,
+}
+
+impl Overflow {
+    /// Rounds the result & maps it to 0-255.
+    pub fn apply(&self, value: f32) -> u8 {
+        match self {
+            Overflow::Saturate => value.round().clamp(0.0, 255.0) as u8,
+            Overflow::Wrap => {
+                if value.is_finite() {
+                    (value.round() as i64).rem_euclid(256) as u8
+                } else {
+                    0
+                }
+            }
+        }
+    }
+}
+
+/// Combines the values of 2 or more attributes & writes the result into the target,
+/// which can be one of the sources. The target is created, if it doesn't exist.
+/// All calculations are done with floating point numbers & only the result is rounded.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AttributeMathStep {
+    source_ids: Vec<usize>,
+    target: String,
+    operation: MathOperation,
+    overflow: Overflow,
+}
+
+impl AttributeMathStep {
+    /// Creates the step, but returns an error if there are less than 2 sources:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::{AttributeMathStep, MathOperation, Overflow};
+    /// assert!(AttributeMathStep::new(vec![0], "sum", MathOperation::Add, Overflow::Saturate).is_err());
+    /// ```
+    ///
+    /// Or the name of the target is invalid:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::{AttributeMathStep, MathOperation, Overflow};
+    /// assert!(AttributeMathStep::new(vec![0, 1], " ", MathOperation::Add, Overflow::Saturate).is_err());
+    /// ```
+    ///
+    /// Or the number of weights doesn't match the number of sources:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::math::{AttributeMathStep, MathOperation, Overflow};
+    /// let average = MathOperation::new_weighted_average(vec![1.0, 2.0, 3.0]).unwrap();
+    ///
+    /// assert!(AttributeMathStep::new(vec![0, 1], "sum", average, Overflow::Saturate).is_err());
+    /// ```
+    pub fn new<S: Into<String>>(
+        source_ids: Vec<usize>,
+        target: S,
+        operation: MathOperation,
+        overflow: Overflow,
+    ) -> Result<AttributeMathStep> {
+        let target = validate_name(target)?;
+
+        if source_ids.len() < 2 {
+            bail!("The step needs at least 2 sources!");
+        }
+
+        if let WeightedAverage(weights) = &operation {
+            if weights.len() != source_ids.len() {
+                bail!(
+                    "The number of weights ({}) doesn't match the number of sources ({})!",
+                    weights.len(),
+                    source_ids.len()
+                );
+            }
+        }
+
+        Ok(AttributeMathStep {
+            source_ids,
+            target,
+            operation,
+            overflow,
+        })
+    }
+
+    pub fn source_ids(&self) -> &[usize] {
+        &self.source_ids
+    }
+
+    /// The name of the target, which is created if needed.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn operation(&self) -> &MathOperation {
+        &self.operation
+    }
+
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::math::{AttributeMathStep, MathOperation, Overflow};
+    /// let mut map = Map2d::new(Size2d::unchecked(3, 1));
+    /// map.create_attribute_from("a", vec![100, 200, 50]);
+    /// map.create_attribute_from("b", vec![50, 100, 100]);
+    ///
+    /// AttributeMathStep::new(vec![0, 1], "result", MathOperation::Sub, Overflow::Saturate).unwrap().run(&mut map);
+    /// assert_eq!(get_attribute(&map, 2).name(), "result");
+    /// assert_eq!(get_attribute(&map, 2).get_all(), &vec![50, 100, 0]);
+    ///
+    /// AttributeMathStep::new(vec![0, 1], "result", MathOperation::Add, Overflow::Wrap).unwrap().run(&mut map);
+    /// assert_eq!(get_attribute(&map, 2).get_all(), &vec![150, 44, 150]);
+    ///
+    /// AttributeMathStep::new(vec![0, 1], "a", MathOperation::Max, Overflow::Saturate).unwrap().run(&mut map);
+    /// assert_eq!(get_attribute(&map, 0).get_all(), &vec![100, 200, 100]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Calculate attribute '{}' of map '{}' with {:?} of {} attributes",
+            self.target,
+            map.name(),
+            self.operation,
+            self.source_ids.len()
+        );
+
+        let sources: Vec<&[u8]> = self
+            .source_ids
+            .iter()
+            .map(|id| get_attribute(map, *id).get_all().as_slice())
+            .collect();
+        let mut inputs = vec![0.0; sources.len()];
+        let values = (0..map.size().get_area())
+            .map(|index| {
+                for (input, source) in inputs.iter_mut().zip(&sources) {
+                    *input = source[index] as f32;
+                }
+
+                self.overflow.apply(self.operation.calculate(&inputs))
+            })
+            .collect();
+
+        map.replace_or_create_attribute(&self.target, values)
+            .expect("Failed to create the attribute!");
+    }
+}
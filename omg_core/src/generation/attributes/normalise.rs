@@ -0,0 +1,222 @@
+use crate::data::map::statistics::Histogram;
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use anyhow::{bail, Result};
+use Normalisation::*;
+
+/// How to remap the values of an [`Attribute`](crate::data::map::attribute::Attribute)
+/// based on their [`Histogram`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Normalisation {
+    /// Remaps the values piecewise linear, so that the percentage of cells is below the threshold.
+    /// E.g. to get a specific share of ocean below the sea level.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::normalise::Normalisation;
+    /// let values = vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 255];
+    /// let attribute = Attribute::new("test", Size2d::unchecked(10, 1), values).unwrap();
+    /// let normalisation = Normalisation::new_to_percentile(70.0, 100).unwrap();
+    /// let lookup = normalisation.calculate_lookup(&Histogram::new(&attribute));
+    ///
+    /// assert_eq!(lookup[0], 0);
+    /// assert_eq!(lookup[30], 49);
+    /// assert_eq!(lookup[60], 98);
+    /// assert_eq!(lookup[61], 100);
+    /// assert_eq!(lookup[70], 107);
+    /// assert_eq!(lookup[80], 115);
+    /// assert_eq!(lookup[255], 255);
+    /// ```
+    ToPercentile { percentage: f32, threshold: u8 },
+    /// Spreads the values evenly over 0-255 (histogram equalisation).
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::normalise::Normalisation::Equalise;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(4, 1), vec![10, 11, 12, 200]).unwrap();
+    /// let lookup = Equalise.calculate_lookup(&Histogram::new(&attribute));
+    ///
+    /// assert_eq!(lookup[10], 0);
+    /// assert_eq!(lookup[11], 85);
+    /// assert_eq!(lookup[12], 170);
+    /// assert_eq!(lookup[200], 255);
+    /// ```
+    Equalise,
+    /// Stretches the values linearly from their minimum & maximum to the range.
+    ///
+    /// ```
+    ///# use omg_core::data::map::attribute::Attribute;
+    ///# use omg_core::data::map::statistics::Histogram;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::normalise::Normalisation;
+    /// let attribute = Attribute::new("test", Size2d::unchecked(3, 1), vec![50, 75, 150]).unwrap();
+    /// let normalisation = Normalisation::new_remap_to_range(0, 200).unwrap();
+    /// let lookup = normalisation.calculate_lookup(&Histogram::new(&attribute));
+    ///
+    /// assert_eq!(lookup[50], 0);
+    /// assert_eq!(lookup[75], 50);
+    /// assert_eq!(lookup[150], 200);
+    /// ```
+    RemapToRange { min: u8, max: u8 },
+}
+
+impl Normalisation {
+    /// Returns a normalisation to a percentile, unless the percentage isn't between 0 & 100:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::normalise::Normalisation;
+    /// assert!(Normalisation::new_to_percentile(-1.0, 76).is_err());
+    /// assert!(Normalisation::new_to_percentile(101.0, 76).is_err());
+    /// ```
+    pub fn new_to_percentile(percentage: f32, threshold: u8) -> Result<Normalisation> {
+        if !(0.0..=100.0).contains(&percentage) {
+            bail!("The percentage {} must be between 0 & 100!", percentage);
+        }
+
+        Ok(ToPercentile {
+            percentage,
+            threshold,
+        })
+    }
+
+    /// Returns a remapping to a range, unless the minimum isn't below the maximum:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::normalise::Normalisation;
+    /// assert!(Normalisation::new_remap_to_range(100, 100).is_err());
+    /// assert!(Normalisation::new_remap_to_range(200, 100).is_err());
+    /// ```
+    pub fn new_remap_to_range(min: u8, max: u8) -> Result<Normalisation> {
+        if min >= max {
+            bail!("The minimum {} must be below the maximum {}!", min, max);
+        }
+
+        Ok(RemapToRange { min, max })
+    }
+
+    /// Calculates the new value for each of the 256 values.
+    /// Returns the values unchanged, if the histogram is empty.
+    pub fn calculate_lookup(&self, histogram: &Histogram) -> Vec<u8> {
+        let (min, max) = match (histogram.min(), histogram.max()) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return (0..=u8::MAX).collect(),
+        };
+
+        match self {
+            ToPercentile {
+                percentage,
+                threshold,
+            } => {
+                // All values below the pivot are below the threshold.
+                let pivot = histogram.percentile(*percentage).unwrap_or(max) as f32 + 1.0;
+                let threshold = *threshold as f32;
+                let upper_factor = (255.0 - threshold) / (255.0 - pivot).max(1.0);
+
+                (0..=u8::MAX)
+                    .map(|value| {
+                        let value = value as f32;
+
+                        if value < pivot {
+                            (value * threshold / pivot).floor() as u8
+                        } else {
+                            (threshold + (value - pivot) * upper_factor)
+                                .round()
+                                .min(255.0) as u8
+                        }
+                    })
+                    .collect()
+            }
+            Equalise => {
+                let total = histogram.total();
+                let below_min = histogram.count(min);
+
+                if total == below_min {
+                    return (0..=u8::MAX).collect();
+                }
+
+                let mut sum = 0;
+
+                histogram
+                    .counts()
+                    .iter()
+                    .map(|count| {
+                        sum += count;
+                        let share =
+                            sum.saturating_sub(below_min) as f32 / (total - below_min) as f32;
+                        (share * 255.0).round() as u8
+                    })
+                    .collect()
+            }
+            RemapToRange {
+                min: new_min,
+                max: new_max,
+            } => {
+                let range = (max - min).max(1) as f32;
+                let new_range = (new_max - new_min) as f32;
+
+                (0..=u8::MAX)
+                    .map(|value| {
+                        let factor = (value.clamp(min, max) - min) as f32 / range;
+                        (*new_min as f32 + factor * new_range).round() as u8
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Remaps the values of an [`Attribute`](crate::data::map::attribute::Attribute)
+/// based on the statistics of the whole map.
+#[derive(new, Debug, PartialEq, Clone)]
+pub struct NormaliseStep {
+    attribute_id: usize,
+    normalisation: Normalisation,
+}
+
+impl NormaliseStep {
+    pub fn attribute_id(&self) -> usize {
+        self.attribute_id
+    }
+
+    pub fn normalisation(&self) -> Normalisation {
+        self.normalisation
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::normalise::{Normalisation, NormaliseStep};
+    /// let mut map = Map2d::new(Size2d::unchecked(5, 1));
+    /// map.create_attribute_from("elevation", vec![10, 20, 30, 40, 50]);
+    /// let normalisation = Normalisation::new_to_percentile(60.0, 76).unwrap();
+    /// let step = NormaliseStep::new(0, normalisation);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 0).get_all(), &vec![24, 49, 73, 83, 91]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Normalise attribute '{}' of map '{}' with {:?}",
+            get_attribute(map, self.attribute_id).name(),
+            map.name(),
+            self.normalisation
+        );
+
+        let histogram = Histogram::new(get_attribute(map, self.attribute_id));
+        let lookup = self.normalisation.calculate_lookup(&histogram);
+        let attribute = get_attribute_mut(map, self.attribute_id);
+        let values = attribute
+            .get_all()
+            .iter()
+            .map(|value| lookup[*value as usize])
+            .collect();
+
+        attribute.replace_all(values);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use anyhow::{bail, Result};
+use Wind::*;
+
+/// The direction, in which the wind blows.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Wind {
+    /// The same direction everywhere in degrees. Clockwise from north (negative y) over east (positive x).
+    Constant(f32),
+    /// Reads the direction from an [`Attribute`](crate::data::map::attribute::Attribute).
+    /// Maps 0-255 clockwise from north (negative y) over east (positive x) like
+    /// [`Aspect`](crate::generation::attributes::terrain::TerrainProperty::Aspect).
+    Attribute(usize),
+}
+
+impl Wind {
+    /// Returns the step of the wind per cell.
+    fn get_step(&self, map: &Map2d, index: usize) -> [f32; 2] {
+        let radians = match self {
+            Constant(degrees) => degrees.to_radians(),
+            Attribute(id) => get_attribute(map, *id)[index] as f32 / 256.0 * std::f32::consts::TAU,
+        };
+
+        [radians.sin(), -radians.cos()]
+    }
+}
+
+/// Simulates the rainfall caused by moist air, which the wind carries from the ocean across the land.
+///
+/// The air is fully moist over the ocean & when it enters the map.
+/// It loses a fraction of its moisture per cell over land, which increases when the wind climbs a slope.
+/// So windward slopes get more rain & the land behind mountains less (rain shadow).
+///
+/// The wind is only traced back up to the maximum distance, so tiles need a border of that size.
+/// If no ocean is found, the air is assumed to be fully moist at that distance.
+/// So the rainfall far from the ocean levels off at the rain after the maximum distance
+/// over flat land instead of dropping to 0, but mountains further upwind cast no rain shadow.
+///
+/// The rainfall is the lost moisture relative to the loss over flat land next to the ocean,
+/// which gets 255.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RainfallSimulationStep {
+    elevation_id: usize,
+    rainfall_id: usize,
+    wind: Wind,
+    sea_level: u8,
+    loss: f32,
+    orographic: f32,
+    max_distance: u32,
+}
+
+impl RainfallSimulationStep {
+    /// Creates the step, but returns an error if the loss isn't between 0 & 1:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::rainfall::RainfallSimulationStep;
+    ///# use omg_core::generation::attributes::rainfall::Wind::Constant;
+    /// assert!(RainfallSimulationStep::new(0, 1, Constant(90.0), 76, 0.0, 0.1, 100).is_err());
+    /// assert!(RainfallSimulationStep::new(0, 1, Constant(90.0), 76, 1.1, 0.1, 100).is_err());
+    /// ```
+    ///
+    /// Or the orographic factor is negative:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::rainfall::RainfallSimulationStep;
+    ///# use omg_core::generation::attributes::rainfall::Wind::Constant;
+    /// assert!(RainfallSimulationStep::new(0, 1, Constant(90.0), 76, 0.1, -0.1, 100).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `sea_level` - Cells with an elevation up to it are ocean.
+    /// * `loss` - The fraction of the moisture, which the air loses per cell over flat land.
+    /// * `orographic` - Multiplies the loss per elevation the wind climbs.
+    /// * `max_distance` - The maximum distance in cells, which the wind is traced back.
+    pub fn new(
+        elevation_id: usize,
+        rainfall_id: usize,
+        wind: Wind,
+        sea_level: u8,
+        loss: f32,
+        orographic: f32,
+        max_distance: u32,
+    ) -> Result<RainfallSimulationStep> {
+        if loss <= 0.0 || loss > 1.0 {
+            bail!("The loss {} must be greater than 0 & at most 1!", loss);
+        } else if orographic < 0.0 {
+            bail!("The orographic factor {} must not be negative!", orographic);
+        }
+
+        Ok(RainfallSimulationStep {
+            elevation_id,
+            rainfall_id,
+            wind,
+            sea_level,
+            loss,
+            orographic,
+            max_distance,
+        })
+    }
+
+    pub fn elevation_id(&self) -> usize {
+        self.elevation_id
+    }
+
+    pub fn rainfall_id(&self) -> usize {
+        self.rainfall_id
+    }
+
+    pub fn wind(&self) -> Wind {
+        self.wind
+    }
+
+    pub fn sea_level(&self) -> u8 {
+        self.sea_level
+    }
+
+    pub fn loss(&self) -> f32 {
+        self.loss
+    }
+
+    pub fn orographic(&self) -> f32 {
+        self.orographic
+    }
+
+    pub fn max_distance(&self) -> u32 {
+        self.max_distance
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    /// The air loses the same moisture over the same distance, which is spread over more or less cells.
+    pub fn resize(&self, factor: f32) -> RainfallSimulationStep {
+        let loss = 1.0 - (1.0 - self.loss).powf(1.0 / factor);
+        let loss = loss.clamp(f32::MIN_POSITIVE, 1.0);
+
+        RainfallSimulationStep {
+            loss,
+            orographic: self.orographic * self.loss / loss,
+            max_distance: (self.max_distance as f32 * factor).round() as u32,
+            ..self.clone()
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::rainfall::RainfallSimulationStep;
+    ///# use omg_core::generation::attributes::rainfall::Wind::Constant;
+    /// let mut map = Map2d::new(Size2d::unchecked(8, 1));
+    /// map.create_attribute_from("elevation", vec![0, 100, 100, 200, 100, 100, 100, 0]);
+    /// map.create_attribute("rainfall", 0);
+    /// let step = RainfallSimulationStep::new(0, 1, Constant(90.0), 50, 0.1, 0.05, 8).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 1).get_all(), &vec![255, 255, 102, 255, 37, 33, 30, 27]);
+    /// ```
+    ///
+    /// Beyond the maximum distance from the ocean the rainfall levels off:
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::rainfall::RainfallSimulationStep;
+    ///# use omg_core::generation::attributes::rainfall::Wind::Constant;
+    /// let mut map = Map2d::new(Size2d::unchecked(8, 1));
+    /// map.create_attribute_from("elevation", vec![0, 60, 60, 60, 60, 60, 60, 60]);
+    /// map.create_attribute("rainfall", 0);
+    /// let step = RainfallSimulationStep::new(0, 1, Constant(90.0), 50, 0.1, 0.0, 4).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 1).get_all(), &vec![255, 255, 230, 207, 186, 167, 167, 167]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Simulate rainfall with {:?} over '{}' into '{}' of map '{}'",
+            self.wind,
+            get_attribute(map, self.elevation_id).name(),
+            get_attribute(map, self.rainfall_id).name(),
+            map.name()
+        );
+
+        let values = (0..map.size().get_area())
+            .map(|index| self.simulate_cell(map, index))
+            .collect();
+        let attribute = get_attribute_mut(map, self.rainfall_id);
+
+        attribute.replace_all(values);
+    }
+
+    /// Traces the wind back to the ocean, the edge of the world or the maximum distance
+    /// & then follows it to the cell. The air is fully moist at the first cell of the trace,
+    /// which has no rise.
+    fn simulate_cell(&self, map: &Map2d, index: usize) -> u8 {
+        let size = map.size();
+        let world_size = map.world_size();
+        let region = map.region();
+        let elevation = get_attribute(map, self.elevation_id);
+        let [x, y] = map.to_world(size.to_x(index), size.to_y(index));
+        let mut position = [x as f32, y as f32];
+        let mut trace = vec![index];
+        let mut moisture = 1.0;
+
+        for _ in 0..self.max_distance {
+            let step = self.wind.get_step(map, trace[trace.len() - 1]);
+            position = [position[0] - step[0], position[1] - step[1]];
+            let x = position[0].round();
+            let y = position[1].round();
+
+            if x < 0.0 || y < 0.0 || !world_size.is_inside(x as u32, y as u32) {
+                break;
+            }
+
+            // Only happens for cells of the halo around a tile, which are discarded.
+            let local_x = x as i64 - region.x() as i64;
+            let local_y = y as i64 - region.y() as i64;
+
+            if local_x < 0 || local_y < 0 || !size.is_inside(local_x as u32, local_y as u32) {
+                break;
+            }
+
+            let upwind = size.to_index_risky(local_x as u32, local_y as u32);
+
+            trace.push(upwind);
+
+            if elevation[upwind] <= self.sea_level {
+                break;
+            }
+        }
+
+        let mut rain = 0.0;
+
+        for i in (0..trace.len()).rev() {
+            let current = elevation[trace[i]];
+            let previous = trace.get(i + 1).map(|c| elevation[*c]).unwrap_or(current);
+            let rise = current.saturating_sub(previous) as f32;
+            let rate = (self.loss * (1.0 + self.orographic * rise)).min(1.0);
+            rain = moisture * rate;
+            moisture -= rain;
+
+            if current <= self.sea_level {
+                moisture = 1.0;
+            }
+        }
+
+        (rain / self.loss * 255.0).round().min(255.0) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::size2d::Size2d;
+    use crate::generation::attributes::create::CreateAttributeStep;
+    use crate::generation::step::GenerationStep;
+    use crate::generation::tests::{assert_tiles_match, create_generator_with_distortions};
+    use crate::generation::MapGenerator;
+
+    #[test]
+    fn test_tiles_match_whole_map() {
+        let size = Size2d::unchecked(100, 80);
+        let mut steps = create_generator_with_distortions(size).steps().to_vec();
+        steps.push(GenerationStep::CreateAttribute(
+            CreateAttributeStep::new("wind", 40).unwrap(),
+        ));
+        steps.push(GenerationStep::CreateAttribute(
+            CreateAttributeStep::new("rainfall", 0).unwrap(),
+        ));
+        steps.push(GenerationStep::CreateAttribute(
+            CreateAttributeStep::new("rainfall with wind", 0).unwrap(),
+        ));
+        let constant =
+            RainfallSimulationStep::new(0, 2, Wind::Constant(110.0), 100, 0.05, 0.02, 12).unwrap();
+        let attribute =
+            RainfallSimulationStep::new(0, 3, Wind::Attribute(1), 100, 0.05, 0.02, 12).unwrap();
+        steps.push(GenerationStep::RainfallSimulation(constant));
+        steps.push(GenerationStep::RainfallSimulation(attribute));
+        let generator = MapGenerator::new("map", size, steps).unwrap();
+
+        assert_tiles_match(&generator, Size2d::unchecked(25, 20));
+    }
+}
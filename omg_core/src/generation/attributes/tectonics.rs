@@ -0,0 +1,483 @@
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::random::random_factor;
+use crate::data::math::resampling::scale_length;
+use crate::data::math::size2d::Size2d;
+use anyhow::{bail, Result};
+
+/// The maximum number of plates, so that their ids fit into an attribute.
+pub const MAX_PLATES: u32 = 256;
+
+/// A tectonic plate.
+#[derive(new, Debug, PartialEq, Clone, Copy)]
+pub struct Plate {
+    position: [f32; 2],
+    /// The movement per time step.
+    motion: [f32; 2],
+    continental: bool,
+}
+
+impl Plate {
+    pub fn position(&self) -> [f32; 2] {
+        self.position
+    }
+
+    pub fn motion(&self) -> [f32; 2] {
+        self.motion
+    }
+
+    pub fn is_continental(&self) -> bool {
+        self.continental
+    }
+}
+
+/// The elevations added by a [`PlateTectonicsStep`].
+#[derive(new, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PlateElevation {
+    /// The base elevation of oceanic plates.
+    oceanic: u8,
+    /// The base elevation of continental plates.
+    continental: u8,
+    /// The maximum height of mountains at convergent boundaries.
+    mountain: u8,
+    /// The maximum depth of rifts at divergent boundaries & trenches of subducting plates.
+    rift: u8,
+}
+
+impl PlateElevation {
+    pub fn oceanic(&self) -> u8 {
+        self.oceanic
+    }
+
+    pub fn continental(&self) -> u8 {
+        self.continental
+    }
+
+    pub fn mountain(&self) -> u8 {
+        self.mountain
+    }
+
+    pub fn rift(&self) -> u8 {
+        self.rift
+    }
+}
+
+/// Divides the world into tectonic plates & adds their elevation.
+///
+/// The plates are the voronoi cells of randomly placed seeds. Each has a random motion
+/// & is either oceanic or continental.
+/// Where 2 plates move towards each other, mountains rise. Unless an oceanic plate moves under
+/// a continental one, which creates a trench on the oceanic side.
+/// Where they move apart, a rift forms.
+/// Both fall off linearly with the distance to the boundary.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PlateTectonicsStep {
+    plate_id: usize,
+    elevation_id: usize,
+    seed: u32,
+    number_of_plates: u32,
+    continental_ratio: f32,
+    elevation: PlateElevation,
+    boundary_width: u32,
+    wrap_x: bool,
+}
+
+impl PlateTectonicsStep {
+    /// Creates the step, but returns an error if the number of plates isn't between 2 & 256:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::tectonics::{PlateElevation, PlateTectonicsStep};
+    /// let elevation = PlateElevation::new(20, 100, 100, 30);
+    ///
+    /// assert!(PlateTectonicsStep::new(0, 1, 42, 1, 0.5, elevation, 20).is_err());
+    /// assert!(PlateTectonicsStep::new(0, 1, 42, 257, 0.5, elevation, 20).is_err());
+    /// ```
+    ///
+    /// Or the ratio of continental plates isn't between 0 & 1:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::tectonics::{PlateElevation, PlateTectonicsStep};
+    /// let elevation = PlateElevation::new(20, 100, 100, 30);
+    ///
+    /// assert!(PlateTectonicsStep::new(0, 1, 42, 10, -0.1, elevation, 20).is_err());
+    /// assert!(PlateTectonicsStep::new(0, 1, 42, 10, 1.1, elevation, 20).is_err());
+    /// ```
+    ///
+    /// Or the width of the boundaries is 0:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::tectonics::{PlateElevation, PlateTectonicsStep};
+    /// let elevation = PlateElevation::new(20, 100, 100, 30);
+    ///
+    /// assert!(PlateTectonicsStep::new(0, 1, 42, 10, 0.5, elevation, 0).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `plate_id` - The attribute, which stores the id of the plate of each cell.
+    /// * `elevation_id` - The attribute, to which the elevation is added.
+    /// * `continental_ratio` - The chance of a plate to be continental.
+    /// * `boundary_width` - The distance, at which mountains & rifts disappear.
+    pub fn new(
+        plate_id: usize,
+        elevation_id: usize,
+        seed: u32,
+        number_of_plates: u32,
+        continental_ratio: f32,
+        elevation: PlateElevation,
+        boundary_width: u32,
+    ) -> Result<PlateTectonicsStep> {
+        if !(2..=MAX_PLATES).contains(&number_of_plates) {
+            bail!(
+                "The number of plates {} must be between 2 & {}!",
+                number_of_plates,
+                MAX_PLATES
+            );
+        } else if !(0.0..=1.0).contains(&continental_ratio) {
+            bail!(
+                "The continental ratio {} must be between 0 & 1!",
+                continental_ratio
+            );
+        } else if boundary_width == 0 {
+            bail!("The boundary width must be greater 0!");
+        }
+
+        Ok(PlateTectonicsStep {
+            plate_id,
+            elevation_id,
+            seed,
+            number_of_plates,
+            continental_ratio,
+            elevation,
+            boundary_width,
+            wrap_x: false,
+        })
+    }
+
+    pub fn plate_id(&self) -> usize {
+        self.plate_id
+    }
+
+    pub fn elevation_id(&self) -> usize {
+        self.elevation_id
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    pub fn number_of_plates(&self) -> u32 {
+        self.number_of_plates
+    }
+
+    pub fn continental_ratio(&self) -> f32 {
+        self.continental_ratio
+    }
+
+    pub fn elevation(&self) -> PlateElevation {
+        self.elevation
+    }
+
+    pub fn boundary_width(&self) -> u32 {
+        self.boundary_width
+    }
+
+    /// Does the world wrap around along the x-axis like a cylinder?
+    pub fn wrap_x(&self) -> bool {
+        self.wrap_x
+    }
+
+    pub fn with_wrap_x(mut self, wrap_x: bool) -> PlateTectonicsStep {
+        self.wrap_x = wrap_x;
+        self
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    /// The plates are placed relative to the size of the world, so only the boundary changes.
+    pub fn resize(&self, factor: f32) -> PlateTectonicsStep {
+        PlateTectonicsStep {
+            boundary_width: scale_length(self.boundary_width, factor).max(1),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the randomly placed plates of a world.
+    ///
+    /// ```
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::tectonics::{PlateElevation, PlateTectonicsStep};
+    /// let elevation = PlateElevation::new(20, 100, 100, 30);
+    /// let step = PlateTectonicsStep::new(0, 1, 42, 10, 0.4, elevation, 20).unwrap();
+    /// let plates = step.get_plates(Size2d::unchecked(400, 300));
+    ///
+    /// assert_eq!(plates.len(), 10);
+    ///
+    /// for plate in plates {
+    ///     let [x, y] = plate.position();
+    ///     assert!((0.0..400.0).contains(&x));
+    ///     assert!((0.0..300.0).contains(&y));
+    /// }
+    /// ```
+    pub fn get_plates(&self, world_size: Size2d) -> Vec<Plate> {
+        (0..self.number_of_plates)
+            .map(|i| {
+                let random = |n| random_factor(self.seed, i, n);
+                let position = [
+                    random(0) * world_size.width() as f32,
+                    random(1) * world_size.height() as f32,
+                ];
+                let angle = random(2) * std::f32::consts::TAU;
+                let speed = random(3);
+                let motion = [angle.sin() * speed, -angle.cos() * speed];
+
+                Plate::new(position, motion, random(4) < self.continental_ratio)
+            })
+            .collect()
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::tectonics::{PlateElevation, PlateTectonicsStep};
+    /// let mut map = Map2d::new(Size2d::unchecked(40, 30));
+    /// map.create_attribute("plate", 0);
+    /// map.create_attribute("elevation", 0);
+    /// let elevation = PlateElevation::new(20, 100, 100, 30);
+    /// let step = PlateTectonicsStep::new(0, 1, 42, 5, 0.4, elevation, 5).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert!(get_attribute(&map, 0).get_all().iter().all(|id| *id < 5));
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Simulate {} tectonic plates into '{}' & '{}' of map '{}'",
+            self.number_of_plates,
+            get_attribute(map, self.plate_id).name(),
+            get_attribute(map, self.elevation_id).name(),
+            map.name()
+        );
+
+        let size = map.size();
+        let world_size = map.world_size();
+        let plates = self.get_plates(world_size);
+        let mut ids = Vec::with_capacity(size.get_area());
+        let mut contributions = Vec::with_capacity(size.get_area());
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let [world_x, world_y] = map.to_world(x, y);
+                let point = [world_x as f32, world_y as f32];
+                let (id, contribution) = self.calculate_cell(&plates, point, world_size);
+                ids.push(id);
+                contributions.push(contribution);
+            }
+        }
+
+        get_attribute_mut(map, self.plate_id).replace_all(ids);
+
+        let attribute = get_attribute_mut(map, self.elevation_id);
+
+        for (index, contribution) in contributions.into_iter().enumerate() {
+            let value = attribute[index] as f32 + contribution;
+            attribute[index] = value.round().clamp(0.0, 255.0) as u8;
+        }
+    }
+
+    /// Returns the id of the plate of a point & the elevation added to it.
+    fn calculate_cell(&self, plates: &[Plate], point: [f32; 2], world_size: Size2d) -> (u8, f32) {
+        let width = world_size.width() as f32;
+        let offsets: Vec<[f32; 2]> = plates
+            .iter()
+            .map(|plate| {
+                let mut dx = plate.position[0] - point[0];
+
+                if self.wrap_x {
+                    dx -= width * (dx / width).round();
+                }
+
+                [dx, plate.position[1] - point[1]]
+            })
+            .collect();
+
+        let nearest = (0..plates.len())
+            .min_by(|a, b| length2(offsets[*a]).total_cmp(&length2(offsets[*b])))
+            .unwrap_or(0);
+
+        // the distance to the perpendicular bisector between both seeds
+        let mut border = f32::MAX;
+        let mut neighbour = nearest;
+
+        for (other, offset) in offsets.iter().enumerate() {
+            if other == nearest {
+                continue;
+            }
+
+            let between = sub(*offset, offsets[nearest]);
+            let length = length2(between).sqrt();
+
+            if length == 0.0 {
+                continue;
+            }
+
+            let distance = (length2(*offset) - length2(offsets[nearest])) / (2.0 * length);
+
+            if distance < border {
+                border = distance;
+                neighbour = other;
+            }
+        }
+
+        let plate = &plates[nearest];
+        let base = if plate.continental {
+            self.elevation.continental
+        } else {
+            self.elevation.oceanic
+        } as f32;
+
+        if neighbour == nearest {
+            return (nearest as u8, base);
+        }
+
+        let other = &plates[neighbour];
+        let between = sub(offsets[neighbour], offsets[nearest]);
+        let length = length2(between).sqrt();
+        let normal = [between[0] / length, between[1] / length];
+        let relative_motion = sub(plate.motion, other.motion);
+        let convergence =
+            (relative_motion[0] * normal[0] + relative_motion[1] * normal[1]).clamp(-1.0, 1.0);
+        let falloff = (1.0 - border / self.boundary_width as f32).max(0.0);
+
+        let boundary = if convergence <= 0.0 {
+            convergence * self.elevation.rift as f32
+        } else if !plate.continental && other.continental {
+            -convergence * self.elevation.rift as f32
+        } else {
+            convergence * self.elevation.mountain as f32
+        };
+
+        (nearest as u8, base + boundary * falloff)
+    }
+}
+
+fn sub(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn length2(a: [f32; 2]) -> f32 {
+    a[0] * a[0] + a[1] * a[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::attributes::create::CreateAttributeStep;
+    use crate::generation::step::GenerationStep;
+    use crate::generation::tests::{assert_tiles_match, create_generator_with_distortions};
+    use crate::generation::MapGenerator;
+
+    fn size() -> Size2d {
+        Size2d::unchecked(100, 50)
+    }
+
+    fn create_step() -> PlateTectonicsStep {
+        let elevation = PlateElevation::new(20, 100, 100, 10);
+        PlateTectonicsStep::new(0, 1, 0, 2, 0.5, elevation, 10).unwrap()
+    }
+
+    fn create_plates(motion: f32, continental: bool) -> Vec<Plate> {
+        vec![
+            Plate::new([25.0, 25.0], [motion, 0.0], continental),
+            Plate::new([75.0, 25.0], [-motion, 0.0], true),
+        ]
+    }
+
+    #[test]
+    fn test_plate_ids() {
+        let step = create_step();
+        let plates = create_plates(0.0, true);
+
+        assert_eq!(step.calculate_cell(&plates, [10.0, 0.0], size()).0, 0);
+        assert_eq!(step.calculate_cell(&plates, [49.0, 30.0], size()).0, 0);
+        assert_eq!(step.calculate_cell(&plates, [51.0, 10.0], size()).0, 1);
+        assert_eq!(step.calculate_cell(&plates, [90.0, 49.0], size()).0, 1);
+    }
+
+    #[test]
+    fn test_wrap_x() {
+        let step = create_step().with_wrap_x(true);
+        let plates = vec![
+            Plate::new([10.0, 25.0], [0.0, 0.0], true),
+            Plate::new([60.0, 25.0], [0.0, 0.0], true),
+        ];
+
+        assert_eq!(step.calculate_cell(&plates, [95.0, 25.0], size()).0, 0);
+        assert_eq!(
+            create_step()
+                .calculate_cell(&plates, [95.0, 25.0], size())
+                .0,
+            1
+        );
+    }
+
+    #[test]
+    fn test_without_motion() {
+        let step = create_step();
+
+        assert_eq!(
+            step.calculate_cell(&create_plates(0.0, true), [49.0, 25.0], size()),
+            (0, 100.0)
+        );
+        assert_eq!(
+            step.calculate_cell(&create_plates(0.0, false), [49.0, 25.0], size()),
+            (0, 20.0)
+        );
+    }
+
+    #[test]
+    fn test_mountains_at_convergent_boundary() {
+        let step = create_step();
+        let plates = create_plates(0.5, true);
+
+        assert_eq!(step.calculate_cell(&plates, [50.0, 25.0], size()).1, 200.0);
+        assert_eq!(step.calculate_cell(&plates, [45.0, 25.0], size()).1, 150.0);
+        assert_eq!(step.calculate_cell(&plates, [55.0, 25.0], size()).1, 150.0);
+        assert_eq!(step.calculate_cell(&plates, [30.0, 25.0], size()).1, 100.0);
+    }
+
+    #[test]
+    fn test_trench_of_subducting_plate() {
+        let step = create_step();
+        let plates = create_plates(0.5, false);
+
+        assert_eq!(step.calculate_cell(&plates, [45.0, 25.0], size()).1, 15.0);
+        assert_eq!(step.calculate_cell(&plates, [55.0, 25.0], size()).1, 150.0);
+    }
+
+    #[test]
+    fn test_rift_at_divergent_boundary() {
+        let step = create_step();
+        let plates = create_plates(-0.5, true);
+
+        assert_eq!(step.calculate_cell(&plates, [45.0, 25.0], size()).1, 95.0);
+        assert_eq!(step.calculate_cell(&plates, [55.0, 25.0], size()).1, 95.0);
+    }
+
+    #[test]
+    fn test_tiles_match_whole_map() {
+        let size = Size2d::unchecked(60, 50);
+        let mut steps = create_generator_with_distortions(size).steps().to_vec();
+        steps.push(GenerationStep::CreateAttribute(
+            CreateAttributeStep::new("plate", 0).unwrap(),
+        ));
+        let elevation = PlateElevation::new(20, 100, 100, 30);
+        let tectonics = PlateTectonicsStep::new(1, 0, 7, 6, 0.5, elevation, 8)
+            .unwrap()
+            .with_wrap_x(true);
+        steps.push(GenerationStep::PlateTectonics(tectonics));
+        let generator = MapGenerator::new("map", size, steps).unwrap();
+
+        assert_tiles_match(&generator, Size2d::unchecked(25, 20));
+    }
+}
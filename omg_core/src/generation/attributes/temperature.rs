@@ -0,0 +1,167 @@
+use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
+use crate::data::math::generator::gradient::Gradient;
+use anyhow::{bail, Result};
+
+/// Calculates the temperature based on latitude & elevation.
+///
+/// The temperature decreases from the equator to the poles along the y-axis
+/// & with the elevation above sea level on land.
+/// The ocean moderates the temperature by moving it towards the mean of equator & poles.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TemperatureModelStep {
+    elevation_id: usize,
+    temperature_id: usize,
+    latitude: Gradient,
+    lapse_rate: f32,
+    sea_level: u8,
+    ocean_moderation: f32,
+}
+
+impl TemperatureModelStep {
+    /// Creates the step, but returns an error if the lapse rate is negative:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::gradient::Gradient;
+    ///# use omg_core::generation::attributes::temperature::TemperatureModelStep;
+    /// let latitude = Gradient::new(150, 150, 255, 50).unwrap();
+    ///
+    /// assert!(TemperatureModelStep::new(0, 1, latitude, -1.0, 76, 0.0).is_err());
+    /// ```
+    ///
+    /// Or the ocean moderation isn't between 0 & 1:
+    ///
+    /// ```
+    ///# use omg_core::data::math::generator::gradient::Gradient;
+    ///# use omg_core::generation::attributes::temperature::TemperatureModelStep;
+    /// let latitude = Gradient::new(150, 150, 255, 50).unwrap();
+    ///
+    /// assert!(TemperatureModelStep::new(0, 1, latitude, 1.0, 76, -0.1).is_err());
+    /// assert!(TemperatureModelStep::new(0, 1, latitude, 1.0, 76, 1.1).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Starts at the equator with its temperature & ends at the poles with theirs.
+    /// * `lapse_rate` - The decrease of the temperature per elevation above sea level.
+    /// * `sea_level` - Cells with an elevation up to it are ocean.
+    /// * `ocean_moderation` - How far the ocean moves the temperature towards the mean. 0 disables it.
+    pub fn new(
+        elevation_id: usize,
+        temperature_id: usize,
+        latitude: Gradient,
+        lapse_rate: f32,
+        sea_level: u8,
+        ocean_moderation: f32,
+    ) -> Result<TemperatureModelStep> {
+        if lapse_rate < 0.0 {
+            bail!("The lapse rate {} must not be negative!", lapse_rate);
+        } else if !(0.0..=1.0).contains(&ocean_moderation) {
+            bail!(
+                "The ocean moderation {} must be between 0 & 1!",
+                ocean_moderation
+            );
+        }
+
+        Ok(TemperatureModelStep {
+            elevation_id,
+            temperature_id,
+            latitude,
+            lapse_rate,
+            sea_level,
+            ocean_moderation,
+        })
+    }
+
+    pub fn elevation_id(&self) -> usize {
+        self.elevation_id
+    }
+
+    pub fn temperature_id(&self) -> usize {
+        self.temperature_id
+    }
+
+    pub fn latitude(&self) -> &Gradient {
+        &self.latitude
+    }
+
+    pub fn lapse_rate(&self) -> f32 {
+        self.lapse_rate
+    }
+
+    pub fn sea_level(&self) -> u8 {
+        self.sea_level
+    }
+
+    pub fn ocean_moderation(&self) -> f32 {
+        self.ocean_moderation
+    }
+
+    /// Returns a copy for a map with a different resolution along the y-axis.
+    pub fn resize(&self, factor: f32) -> TemperatureModelStep {
+        TemperatureModelStep {
+            latitude: self.latitude.resize(factor),
+            ..self.clone()
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::generator::gradient::Gradient;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::temperature::TemperatureModelStep;
+    /// let mut map = Map2d::new(Size2d::unchecked(2, 5));
+    /// map.create_attribute_from("elevation", vec![0, 100, 0, 100, 0, 100, 0, 200, 0, 100]);
+    /// map.create_attribute("temperature", 0);
+    /// let latitude = Gradient::new(2, 2, 200, 100).unwrap();
+    /// let step = TemperatureModelStep::new(0, 1, latitude, 0.5, 50, 0.2).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(
+    ///     get_attribute(&map, 1).get_all(),
+    ///     &vec![110, 75, 150, 125, 190, 175, 150, 75, 110, 75]
+    /// );
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Calculate the temperature '{}' based on latitude & '{}' of map '{}'",
+            get_attribute(map, self.temperature_id).name(),
+            get_attribute(map, self.elevation_id).name(),
+            map.name()
+        );
+
+        let size = map.size();
+        let elevation = get_attribute(map, self.elevation_id);
+        let mut values = Vec::with_capacity(size.get_area());
+
+        for y in 0..size.height() {
+            let [_, world_y] = map.to_world(0, y);
+            let temperature = self.latitude.generate_absolute(world_y);
+
+            for x in 0..size.width() {
+                let elevation = elevation[size.to_index_risky(x, y)];
+                values.push(self.calculate_value(temperature, elevation));
+            }
+        }
+
+        let attribute = get_attribute_mut(map, self.temperature_id);
+
+        attribute.replace_all(values);
+    }
+
+    fn calculate_value(&self, temperature: u8, elevation: u8) -> u8 {
+        let temperature = temperature as f32;
+
+        let value = if elevation <= self.sea_level {
+            let mean =
+                (self.latitude.value_start() as f32 + self.latitude.value_end() as f32) / 2.0;
+            temperature + (mean - temperature) * self.ocean_moderation
+        } else {
+            temperature - (elevation - self.sea_level) as f32 * self.lapse_rate
+        };
+
+        value.round().clamp(0.0, 255.0) as u8
+    }
+}
@@ -0,0 +1,280 @@
+use crate::data::map::attribute::Attribute;
+use crate::data::map::{get_attribute, Map2d};
+use crate::data::name::validate_name;
+use anyhow::{bail, Result};
+use std::f32::consts::PI;
+use TerrainProperty::*;
+
+/// A property of the terrain, which is derived from the elevation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TerrainProperty {
+    /// The steepness of the terrain. Maps 0° to 0 & 90° to 255.
+    Slope,
+    /// The direction the terrain faces, which is the direction of the steepest descent.
+    /// Maps clockwise from north (negative y) over east (positive x) to 0-255.
+    /// Flat cells face north.
+    Aspect,
+    /// The curvature along the direction of the slope, multiplied by a factor.
+    /// Positive values are concave (e.g. the foot of a hill) & negative values are convex.
+    /// Maps 0 to 128.
+    ProfileCurvature(f32),
+    /// The curvature orthogonal to the direction of the slope, multiplied by a factor.
+    /// Positive values are concave (e.g. valleys) & negative values are convex (e.g. ridges).
+    /// Maps 0 to 128.
+    PlanCurvature(f32),
+}
+
+/// Derives a [`TerrainProperty`] from an elevation [`Attribute`].
+/// Overwrites the attribute with the same name or creates a new one.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeriveTerrainStep {
+    attribute: String,
+    elevation_id: usize,
+    property: TerrainProperty,
+    height_scale: f32,
+}
+
+impl DeriveTerrainStep {
+    /// Creates the step, but returns an error if the name is invalid:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::terrain::DeriveTerrainStep;
+    ///# use omg_core::generation::attributes::terrain::TerrainProperty::Slope;
+    /// assert!(DeriveTerrainStep::new("", 0, Slope, 1.0).is_err());
+    /// assert!(DeriveTerrainStep::new("   ", 0, Slope, 1.0).is_err());
+    /// ```
+    ///
+    /// Or the height scale isn't positive:
+    ///
+    /// ```
+    ///# use omg_core::generation::attributes::terrain::DeriveTerrainStep;
+    ///# use omg_core::generation::attributes::terrain::TerrainProperty::Slope;
+    /// assert!(DeriveTerrainStep::new("slope", 0, Slope, 0.0).is_err());
+    /// assert!(DeriveTerrainStep::new("slope", 0, Slope, -1.0).is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `height_scale` - The height of an elevation of 1 relative to the size of a cell.
+    pub fn new<S: Into<String>>(
+        attribute: S,
+        elevation_id: usize,
+        property: TerrainProperty,
+        height_scale: f32,
+    ) -> Result<DeriveTerrainStep> {
+        let attribute = validate_name(attribute)?;
+
+        if height_scale <= 0.0 {
+            bail!("The height scale {} must be positive!", height_scale);
+        }
+
+        Ok(DeriveTerrainStep {
+            attribute,
+            elevation_id,
+            property,
+            height_scale,
+        })
+    }
+
+    pub fn attribute(&self) -> &str {
+        &self.attribute
+    }
+
+    pub fn elevation_id(&self) -> usize {
+        self.elevation_id
+    }
+
+    pub fn property(&self) -> TerrainProperty {
+        self.property
+    }
+
+    pub fn height_scale(&self) -> f32 {
+        self.height_scale
+    }
+
+    /// Returns a copy for a map with a different resolution, which derives the same terrain.
+    /// Smaller cells increase the relative height, but reduce the curvature per cell.
+    pub fn resize(&self, factor: f32) -> DeriveTerrainStep {
+        let property = match self.property {
+            ProfileCurvature(scale) => ProfileCurvature(scale * factor),
+            PlanCurvature(scale) => PlanCurvature(scale * factor),
+            Slope | Aspect => self.property,
+        };
+
+        DeriveTerrainStep {
+            attribute: self.attribute.clone(),
+            elevation_id: self.elevation_id,
+            property,
+            height_scale: self.height_scale * factor,
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::terrain::DeriveTerrainStep;
+    ///# use omg_core::generation::attributes::terrain::TerrainProperty::*;
+    /// let mut map = Map2d::new(Size2d::unchecked(3, 1));
+    /// map.create_attribute_from("elevation", vec![10, 20, 30]).unwrap();
+    ///
+    /// DeriveTerrainStep::new("slope", 0, Slope, 0.1).unwrap().run(&mut map);
+    /// DeriveTerrainStep::new("aspect", 0, Aspect, 0.1).unwrap().run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 1).get_all(), &vec![75, 128, 75]);
+    /// assert_eq!(get_attribute(&map, 2).get_all(), &vec![192, 192, 192]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        let elevation = get_attribute(map, self.elevation_id);
+
+        info!(
+            "Derive {:?} of '{}' as '{}' of map '{}'",
+            self.property,
+            elevation.name(),
+            self.attribute,
+            map.name()
+        );
+
+        let values = self.derive(elevation);
+
+        map.replace_or_create_attribute(&self.attribute, values)
+            .expect("Failed to create the attribute!");
+    }
+
+    fn derive(&self, elevation: &Attribute) -> Vec<u8> {
+        let size = elevation.size();
+        let mut values = Vec::with_capacity(size.get_area());
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let derivatives = self.calculate_derivatives(elevation, x, y);
+                values.push(self.to_value(derivatives));
+            }
+        }
+
+        values
+    }
+
+    /// Returns the first & second derivatives of the elevation based on the 3x3 neighbourhood.
+    /// Cells outside the map use the value of the nearest cell at the edge.
+    fn calculate_derivatives(&self, elevation: &Attribute, x: u32, y: u32) -> Derivatives {
+        let size = elevation.size();
+        let get = |dx: i64, dy: i64| {
+            let x = (x as i64 + dx).clamp(0, size.width() as i64 - 1) as u32;
+            let y = (y as i64 + dy).clamp(0, size.height() as i64 - 1) as u32;
+            elevation[size.to_index_risky(x, y)] as f32 * self.height_scale
+        };
+        let center = get(0, 0);
+
+        Derivatives {
+            dx: (get(1, 0) - get(-1, 0)) / 2.0,
+            dy: (get(0, 1) - get(0, -1)) / 2.0,
+            dxx: get(1, 0) - 2.0 * center + get(-1, 0),
+            dyy: get(0, 1) - 2.0 * center + get(0, -1),
+            dxy: (get(1, 1) - get(1, -1) - get(-1, 1) + get(-1, -1)) / 4.0,
+        }
+    }
+
+    fn to_value(&self, d: Derivatives) -> u8 {
+        let squared_gradient = d.dx * d.dx + d.dy * d.dy;
+
+        let value = match self.property {
+            Slope => squared_gradient.sqrt().atan() / (PI / 2.0) * 255.0,
+            Aspect => {
+                if squared_gradient == 0.0 {
+                    return 0;
+                }
+
+                let angle = (-d.dx).atan2(d.dy).rem_euclid(2.0 * PI);
+                return ((angle / (2.0 * PI) * 256.0) as u32).min(255) as u8;
+            }
+            ProfileCurvature(factor) => {
+                let curvature = if squared_gradient == 0.0 {
+                    0.0
+                } else {
+                    (d.dx * d.dx * d.dxx + 2.0 * d.dx * d.dy * d.dxy + d.dy * d.dy * d.dyy)
+                        / (squared_gradient * (1.0 + squared_gradient).powf(1.5))
+                };
+                128.0 + curvature * factor
+            }
+            PlanCurvature(factor) => {
+                let curvature = if squared_gradient == 0.0 {
+                    0.0
+                } else {
+                    (d.dy * d.dy * d.dxx - 2.0 * d.dx * d.dy * d.dxy + d.dx * d.dx * d.dyy)
+                        / squared_gradient.powf(1.5)
+                };
+                128.0 + curvature * factor
+            }
+        };
+
+        value.round().clamp(0.0, 255.0) as u8
+    }
+}
+
+/// The partial derivatives of the elevation.
+struct Derivatives {
+    dx: f32,
+    dy: f32,
+    dxx: f32,
+    dyy: f32,
+    dxy: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::size2d::Size2d;
+
+    #[test]
+    fn test_aspect_of_slopes() {
+        assert_eq!(derive_center(&[0, 0, 0, 0, 0, 0, 9, 9, 9], Aspect), 0);
+        assert_eq!(derive_center(&[9, 0, 0, 9, 0, 0, 9, 0, 0], Aspect), 64);
+        assert_eq!(derive_center(&[9, 9, 9, 0, 0, 0, 0, 0, 0], Aspect), 128);
+        assert_eq!(derive_center(&[0, 0, 9, 0, 0, 9, 0, 0, 9], Aspect), 192);
+    }
+
+    #[test]
+    fn test_curvature() {
+        let valley = [9, 5, 9, 8, 4, 8, 7, 3, 7];
+        let ridge = [1, 5, 1, 2, 6, 2, 3, 7, 3];
+
+        assert!(derive_center(&valley, PlanCurvature(100.0)) > 128);
+        assert!(derive_center(&ridge, PlanCurvature(100.0)) < 128);
+
+        let foot = [0, 0, 0, 1, 1, 1, 9, 9, 9];
+        let edge = [0, 0, 0, 8, 8, 8, 9, 9, 9];
+
+        assert!(derive_center(&foot, ProfileCurvature(100.0)) > 128);
+        assert!(derive_center(&edge, ProfileCurvature(100.0)) < 128);
+    }
+
+    #[test]
+    fn test_resize_keeps_slope() {
+        let step = DeriveTerrainStep::new("slope", 0, Slope, 0.1).unwrap();
+        let mut map = Map2d::new(Size2d::unchecked(8, 1));
+        map.create_attribute_from("elevation", (0..8).map(|x| x * 20).collect())
+            .unwrap();
+        let mut resized_map = Map2d::new(Size2d::unchecked(16, 1));
+        resized_map
+            .create_attribute_from("elevation", (0..16).map(|x| x * 10).collect())
+            .unwrap();
+
+        step.run(&mut map);
+        step.resize(2.0).run(&mut resized_map);
+
+        assert_eq!(get_attribute(&map, 1)[3], get_attribute(&resized_map, 1)[6]);
+    }
+
+    fn derive_center(values: &[u8], property: TerrainProperty) -> u8 {
+        let mut map = Map2d::new(Size2d::unchecked(3, 3));
+        map.create_attribute_from("elevation", values.to_vec())
+            .unwrap();
+        let step = DeriveTerrainStep::new("result", 0, property, 1.0).unwrap();
+
+        step.run(&mut map);
+
+        get_attribute(&map, 1)[4]
+    }
+}
@@ -1,5 +1,6 @@
 use crate::data::map::{get_attribute, get_attribute_mut, Map2d};
 use crate::data::math::transformer::transformer2d::Transformer2d;
+use crate::data::math::transformer::transformer_nd::TransformerNd;
 use crate::data::name::validate_name;
 use anyhow::{bail, Result};
 
@@ -107,3 +108,150 @@ impl TransformAttribute2dStep {
         biomes
     }
 }
+
+/// Transforms any number of [`Attribute`](crate::data::map::attribute::Attribute)s and writes into another.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TransformAttributeNdStep {
+    name: String,
+    source_ids: Vec<usize>,
+    target_id: usize,
+    transformer: TransformerNd,
+}
+
+impl TransformAttributeNdStep {
+    /// Creates the step, unless the name is invalid or there are too few sources for the transformer:
+    ///
+    /// ```
+    ///# use omg_core::data::math::transformer::lookup_nd::LookupTableNd;
+    ///# use omg_core::data::math::transformer::transformer_nd::TransformerNd;
+    ///# use omg_core::generation::attributes::transformer::TransformAttributeNdStep;
+    /// let table = LookupTableNd::new(vec![vec![100], vec![50]], vec![1, 2, 3, 4]).unwrap();
+    /// let transformer = TransformerNd::Lookup(table);
+    ///
+    /// assert!(TransformAttributeNdStep::new("", vec![0, 1], 2, transformer.clone()).is_err());
+    /// assert!(TransformAttributeNdStep::new("test", vec![0], 2, transformer).is_err());
+    /// ```
+    ///
+    /// Or a lookup table has more sources than axes:
+    ///
+    /// ```
+    ///# use omg_core::data::math::transformer::lookup_nd::LookupTableNd;
+    ///# use omg_core::data::math::transformer::transformer_nd::TransformerNd;
+    ///# use omg_core::generation::attributes::transformer::TransformAttributeNdStep;
+    /// let table = LookupTableNd::new(vec![vec![100], vec![50]], vec![1, 2, 3, 4]).unwrap();
+    /// let transformer = TransformerNd::Lookup(table);
+    ///
+    /// assert!(TransformAttributeNdStep::new("test", vec![0, 1, 2], 3, transformer).is_err());
+    /// ```
+    pub fn new<S: Into<String>>(
+        name: S,
+        source_ids: Vec<usize>,
+        target_id: usize,
+        transformer: TransformerNd,
+    ) -> Result<TransformAttributeNdStep> {
+        let name = validate_name(name)?;
+        let number_of_inputs = transformer.get_number_of_inputs();
+
+        if let TransformerNd::Lookup(_) = transformer {
+            if source_ids.len() != number_of_inputs {
+                bail!(
+                    "The lookup table has {} axes, but got {} sources!",
+                    number_of_inputs,
+                    source_ids.len()
+                );
+            }
+        } else if source_ids.len() < number_of_inputs {
+            bail!(
+                "The transformer needs {} sources, but got {}!",
+                number_of_inputs,
+                source_ids.len()
+            );
+        }
+
+        Ok(TransformAttributeNdStep {
+            name,
+            source_ids,
+            target_id,
+            transformer,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn source_ids(&self) -> &[usize] {
+        &self.source_ids
+    }
+
+    pub fn target_id(&self) -> usize {
+        self.target_id
+    }
+
+    pub fn transformer(&self) -> &TransformerNd {
+        &self.transformer
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use omg_core::data::map::{get_attribute, Map2d};
+    ///# use omg_core::data::math::predicate::Predicate::*;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::data::math::transformer::transformer_nd::{Rule, TransformerNd};
+    ///# use omg_core::data::math::transformer::transformer_nd::RuleCondition::Value;
+    ///# use omg_core::generation::attributes::transformer::TransformAttributeNdStep;
+    /// let mut map = Map2d::new(Size2d::unchecked(4, 1));
+    /// map.create_attribute_from("elevation", vec![50, 100, 100, 100]);
+    /// map.create_attribute_from("temperature", vec![220, 220, 220, 100]);
+    /// map.create_attribute_from("rainfall", vec![20, 20, 60, 20]);
+    /// map.create_attribute("biome", 0);
+    /// let ocean = Rule::new(vec![Value { input: 0, predicate: Below(76) }], 12);
+    /// let desert = Rule::new(
+    ///     vec![
+    ///         Value { input: 1, predicate: Above(200) },
+    ///         Value { input: 2, predicate: Below(50) },
+    ///     ],
+    ///     9,
+    /// );
+    /// let transformer = TransformerNd::new_rules(vec![ocean, desert], 7).unwrap();
+    /// let step = TransformAttributeNdStep::new("biomes", vec![0, 1, 2], 3, transformer).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(get_attribute(&map, 3).get_all(), &vec![12, 9, 7, 7]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        info!(
+            "Apply transformation '{}' using {} sources to '{}' of map '{}'",
+            self.name,
+            self.source_ids.len(),
+            get_attribute(map, self.target_id).name(),
+            map.name()
+        );
+
+        let values = self.transform(map);
+        let attribute = get_attribute_mut(map, self.target_id);
+
+        attribute.replace_all(values);
+    }
+
+    fn transform(&self, map: &Map2d) -> Vec<u8> {
+        let sources: Vec<_> = self
+            .source_ids
+            .iter()
+            .map(|id| get_attribute(map, *id))
+            .collect();
+        let mut inputs = vec![0; sources.len()];
+
+        (0..map.size().get_area())
+            .map(|index| {
+                for (input, source) in inputs.iter_mut().zip(&sources) {
+                    *input = source[index];
+                }
+
+                self.transformer.transform(&inputs)
+            })
+            .collect()
+    }
+}
@@ -1,20 +1,28 @@
 use std::ops::Sub;
 
 use crate::data::map::Map2d;
+use crate::data::math::region::Region;
 use crate::data::math::size2d::Size2d;
 use crate::data::name::validate_name;
-use crate::generation::step::GenerationStep;
-use anyhow::{bail, Result};
+use crate::export::preset::ExportPreset;
+use crate::generation::step::{GenerationStep, StepContext};
+use crate::generation::tiles::TileGrid;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
 
 pub mod attributes;
 pub mod step;
+pub mod tiles;
 
 /// Generates a map based on a number of steps.
 #[derive(Debug, PartialEq, Clone)]
 pub struct MapGenerator {
     name: String,
     size: Size2d,
+    reference_size: Size2d,
     steps: Vec<GenerationStep>,
+    exports: Vec<ExportPreset>,
 }
 
 impl MapGenerator {
@@ -52,7 +60,46 @@ impl MapGenerator {
             bail!("Map generator '{}' has too few steps!", name);
         }
 
-        Ok(MapGenerator { name, size, steps })
+        Ok(MapGenerator {
+            name,
+            size,
+            reference_size: size,
+            steps,
+            exports: Vec::new(),
+        })
+    }
+
+    /// Adds presets to export the generated map.
+    pub fn with_exports(mut self, exports: Vec<ExportPreset>) -> MapGenerator {
+        self.exports = exports;
+        self
+    }
+
+    /// Generates the map with another size, but keeps the world of the original size.
+    /// The coordinates & lengths of the steps are scaled accordingly.
+    /// This allows a preview with a low resolution & a final map with a high one.
+    ///
+    /// ```
+    ///# use omg_core::generation::MapGenerator;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::step::GenerationStep;
+    /// let size = Size2d::unchecked(100, 50);
+    /// let new_size = Size2d::unchecked(400, 200);
+    /// let steps = vec![GenerationStep::debug("a"), GenerationStep::debug("b")];
+    /// let generator = MapGenerator::new("map", size, steps).unwrap().with_size(new_size);
+    ///
+    /// assert_eq!(generator.size(), &new_size);
+    /// assert_eq!(generator.reference_size(), &size);
+    /// ```
+    pub fn with_size(mut self, size: Size2d) -> MapGenerator {
+        self.size = size;
+        self
+    }
+
+    /// Defines the size, for which the coordinates & lengths of the steps were chosen.
+    pub fn with_reference_size(mut self, reference_size: Size2d) -> MapGenerator {
+        self.reference_size = reference_size;
+        self
     }
 
     pub fn name(&self) -> &str {
@@ -63,10 +110,18 @@ impl MapGenerator {
         &self.size
     }
 
+    pub fn reference_size(&self) -> &Size2d {
+        &self.reference_size
+    }
+
     pub fn steps(&self) -> &[GenerationStep] {
         &self.steps
     }
 
+    pub fn exports(&self) -> &[ExportPreset] {
+        &self.exports
+    }
+
     /// Generates the map.
     pub fn generate(&self) -> Map2d {
         let start = std::time::Instant::now();
@@ -78,16 +133,9 @@ impl MapGenerator {
             self.steps.len(),
         );
 
-        let mut start_step = start;
         let mut map = Map2d::with_name(self.name.clone(), self.size);
 
-        self.steps.iter().for_each(|step| {
-            step.run(&mut map);
-            let end_step = std::time::Instant::now();
-            let duration = end_step.sub(start_step);
-            debug!("Step took {:?}", duration);
-            start_step = end_step;
-        });
+        run_steps(&self.get_resized_steps(), &mut map);
 
         let end = std::time::Instant::now();
         let duration = end.sub(start);
@@ -96,4 +144,366 @@ impl MapGenerator {
 
         map
     }
+
+    /// Generates only a tile of the map.
+    ///
+    /// Steps, which read neighbouring cells like distortions, run on the tile & a border around it.
+    /// So the tile is identical to the same region of the whole map.
+    ///
+    /// The border (halo) is the sum of the halos of all steps & limited by the edges of the map.
+    /// So each tile costs as much as generating the tile expanded by the halo.
+    /// E.g. a distortion with a maximum shift of 255 adds 255 cells on each side.
+    ///
+    /// Returns an error for [`Global`](StepContext::Global) steps
+    /// or if the halo is so large, that the whole map would be generated for the tile.
+    pub fn generate_tile(&self, tile: Region) -> Result<Map2d> {
+        let steps = self.get_resized_steps();
+        let region = self.expand_tile(&steps, tile)?;
+
+        info!(
+            "Generate the tile {:?} of map '{}' with the region {:?}",
+            tile, self.name, region
+        );
+
+        let mut map = Map2d::with_region(self.name.clone(), self.size, region)?;
+
+        run_steps(&steps, &mut map);
+
+        map.crop(tile)
+    }
+
+    /// Returns the region, which is needed to generate the tile.
+    fn expand_tile(&self, steps: &[GenerationStep], tile: Region) -> Result<Region> {
+        tile.validate(self.size)?;
+
+        let mut halo = 0u32;
+
+        for (index, step) in steps.iter().enumerate() {
+            match step.get_context().get_halo() {
+                Some(step_halo) => halo = halo.saturating_add(step_halo),
+                None => bail!(
+                    "The {}.step of map '{}' needs the whole map & prevents the generation of tiles!",
+                    index + 1,
+                    self.name
+                ),
+            }
+        }
+
+        let region = tile.expand(halo, self.size);
+
+        if region == Region::full(self.size) && region != tile {
+            bail!(
+                "The halo of {} cells around the tile {:?} covers the whole map '{}' & makes tiling pointless!",
+                halo,
+                tile,
+                self.name
+            );
+        }
+
+        Ok(region)
+    }
+
+    /// Generates only a region of the map. E.g. for a game server, which loads the world lazily.
+    /// Very large maps are possible, because only the region is kept in memory.
+    ///
+    /// Returns an error, if a step isn't [`PointWise`](StepContext::PointWise),
+    /// because it would need cells outside the region. E.g. a distortion.
+    /// Use [`MapGenerator::generate_tile`] for those.
+    ///
+    /// ```
+    ///# use omg_core::data::map::get_attribute;
+    ///# use omg_core::data::math::generator::generator1d::Generator1d::InputAsOutput;
+    ///# use omg_core::data::math::generator::generator2d::Generator2d;
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::attributes::create::CreateAttributeStep;
+    ///# use omg_core::generation::attributes::distortion1d::Distortion1dStep;
+    ///# use omg_core::generation::attributes::generator::GeneratorStep;
+    ///# use omg_core::generation::step::GenerationStep;
+    ///# use omg_core::generation::MapGenerator;
+    /// let size = Size2d::unchecked(100_000, 100_000);
+    /// let create = CreateAttributeStep::new("elevation", 0).unwrap();
+    /// let generator = Generator2d::new_apply_to_x(InputAsOutput);
+    /// let add = GeneratorStep::new("gradient", 0, generator).unwrap();
+    /// let mut steps = vec![
+    ///     GenerationStep::CreateAttribute(create),
+    ///     GenerationStep::GeneratorAdd(add),
+    /// ];
+    /// let region = Region::new(98, 5_000, Size2d::unchecked(4, 1));
+    ///
+    /// let generator = MapGenerator::new("world", size, steps.clone()).unwrap();
+    /// let map = generator.generate_region(region).unwrap();
+    ///
+    /// assert_eq!(map.region(), region);
+    /// assert_eq!(get_attribute(&map, 0).get_all(), &vec![98, 99, 100, 101]);
+    ///
+    /// let distortion = Distortion1dStep::new(0, InputAsOutput);
+    /// steps.push(GenerationStep::DistortAlongX(distortion));
+    /// let generator = MapGenerator::new("world", size, steps).unwrap();
+    ///
+    /// assert!(generator.generate_region(region).is_err());
+    /// ```
+    pub fn generate_region(&self, region: Region) -> Result<Map2d> {
+        region.validate(self.size)?;
+
+        let steps = self.get_resized_steps();
+
+        for (index, step) in steps.iter().enumerate() {
+            let context = step.get_context();
+
+            if context != StepContext::PointWise {
+                bail!(
+                    "The {}.step of map '{}' isn't point-wise ({:?}) & can't be used for a region!",
+                    index + 1,
+                    self.name,
+                    context
+                );
+            }
+        }
+
+        info!("Generate the region {:?} of map '{}'", region, self.name);
+
+        let mut map = Map2d::with_region(self.name.clone(), self.size, region)?;
+
+        run_steps(&steps, &mut map);
+
+        Ok(map)
+    }
+
+    /// Generates the map tile by tile & exports each tile with all presets.
+    /// Only one tile is kept in memory, so the map can be larger than the memory.
+    ///
+    /// The tile in column x & row y is exported to the subdirectory "x_y".
+    pub fn generate_tiles(&self, tile_size: Size2d, directory: &str) -> Result<()> {
+        if self.exports.is_empty() {
+            bail!("Map generator '{}' has no export presets!", self.name);
+        }
+
+        let grid = TileGrid::new(self.size, tile_size);
+        let steps = self.get_resized_steps();
+
+        for (_column, _row, tile) in grid.get_tiles() {
+            self.expand_tile(&steps, tile)?;
+        }
+
+        info!(
+            "Generate the map '{}' in {}x{} tiles",
+            self.name,
+            grid.columns(),
+            grid.rows()
+        );
+
+        for (column, row, tile) in grid.get_tiles() {
+            let map = self.generate_tile(tile)?;
+            let path = Path::new(directory).join(format!("{}_{}", column, row));
+            let tile_directory = path.to_string_lossy();
+
+            fs::create_dir_all(&path)
+                .with_context(|| format!("Failed to create directory '{}'", tile_directory))?;
+
+            self.export(&map, &tile_directory)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the steps scaled from the reference size to the size of the map.
+    pub fn get_resized_steps(&self) -> Vec<GenerationStep> {
+        if self.size == self.reference_size {
+            return self.steps.clone();
+        }
+
+        let factor_x = self.size.width() as f32 / self.reference_size.width() as f32;
+        let factor_y = self.size.height() as f32 / self.reference_size.height() as f32;
+
+        info!(
+            "Resize the steps from {:?} to {:?}",
+            self.reference_size, self.size
+        );
+
+        self.steps
+            .iter()
+            .map(|step| step.resize(factor_x, factor_y))
+            .collect()
+    }
+
+    /// Exports the generated map with all presets. Their paths are relative to the directory.
+    pub fn export(&self, map: &Map2d, directory: &str) -> Result<()> {
+        self.exports
+            .iter()
+            .try_for_each(|preset| preset.export(map, directory))
+    }
+}
+
+fn run_steps(steps: &[GenerationStep], map: &mut Map2d) {
+    let mut start_step = std::time::Instant::now();
+
+    steps.iter().for_each(|step| {
+        step.run(map);
+        let end_step = std::time::Instant::now();
+        let duration = end_step.sub(start_step);
+        debug!("Step took {:?}", duration);
+        start_step = end_step;
+    });
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::data::map::get_attribute;
+    use crate::data::math::edge::EdgeMode;
+    use crate::data::math::generator::generator1d::Generator1d;
+    use crate::data::math::generator::generator2d::Generator2d;
+    use crate::data::math::generator::gradient::Gradient;
+    use crate::data::math::generator::noise::Noise;
+    use crate::generation::attributes::create::CreateAttributeStep;
+    use crate::generation::attributes::distortion1d::Distortion1dStep;
+    use crate::generation::attributes::distortion2d::Distortion2dStep;
+    use crate::generation::attributes::generator::GeneratorStep;
+
+    #[test]
+    fn test_generate_with_other_size() {
+        let create = CreateAttributeStep::new("elevation", 0).unwrap();
+        let gradient = Gradient::new(2, 4, 0, 200).unwrap();
+        let generator = Generator2d::new_apply_to_x(Generator1d::Gradient(gradient));
+        let add = GeneratorStep::new("gradient", 0, generator).unwrap();
+        let steps = vec![
+            GenerationStep::CreateAttribute(create),
+            GenerationStep::GeneratorAdd(add),
+        ];
+        let generator = MapGenerator::new("map", Size2d::unchecked(8, 1), steps).unwrap();
+
+        let map = generator.generate();
+        let resized_map = generator.with_size(Size2d::unchecked(16, 2)).generate();
+
+        let values = get_attribute(&map, 0).get_all();
+        let resized_values = get_attribute(&resized_map, 0).get_all();
+        assert_eq!(values, &vec![0, 0, 0, 50, 100, 150, 200, 200]);
+        assert_eq!(resized_values.len(), 32);
+
+        for (index, value) in values.iter().enumerate() {
+            assert_eq!(resized_values[index * 2], *value);
+            assert_eq!(resized_values[16 + index * 2], *value);
+        }
+    }
+
+    #[test]
+    fn test_tiles_match_whole_map() {
+        let size = Size2d::unchecked(100, 80);
+        let generator = create_generator_with_distortions(size);
+
+        let context = generator.get_resized_steps()[2].get_context();
+        assert_eq!(context, StepContext::Neighbourhood(5));
+
+        assert_tiles_match(&generator, Size2d::unchecked(32, 30));
+    }
+
+    #[test]
+    fn test_region_matches_whole_map() {
+        let create = CreateAttributeStep::new("elevation", 10).unwrap();
+        let noise = Generator2d::Noise2d(Noise::new(1, 5, 0, 255).unwrap());
+        let add = GeneratorStep::new("noise", 0, noise).unwrap();
+        let steps = vec![
+            GenerationStep::CreateAttribute(create),
+            GenerationStep::GeneratorAdd(add),
+        ];
+        let generator = MapGenerator::new("map", Size2d::unchecked(30, 20), steps).unwrap();
+        let region = Region::new(12, 3, Size2d::unchecked(7, 9));
+
+        let map = generator.generate();
+
+        assert_eq!(
+            generator.generate_region(region).unwrap(),
+            map.crop(region).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_tile_outside_map() {
+        let size = Size2d::unchecked(30, 20);
+        let generator = create_generator_with_distortions(size);
+        let tile = Region::new(25, 0, Size2d::unchecked(8, 7));
+
+        assert!(generator.generate_tile(tile).is_err());
+    }
+
+    #[test]
+    fn test_tile_with_halo_covering_the_whole_map() {
+        let size = Size2d::unchecked(30, 20);
+        let generator = create_generator_with_distortions(size);
+        let tile = Region::new(10, 7, Size2d::unchecked(8, 7));
+
+        assert!(generator.generate_tile(tile).is_err());
+        assert!(generator.generate_tile(Region::full(size)).is_ok());
+    }
+
+    #[test]
+    fn test_tiles_with_centred_distortions_match_whole_map() {
+        let size = Size2d::unchecked(100, 80);
+        let generator = create_generator_with_options(size, 128, EdgeMode::Mirror, true);
+
+        let context = generator.get_resized_steps()[2].get_context();
+        assert_eq!(context, StepContext::Neighbourhood(4));
+
+        assert_tiles_match(&generator, Size2d::unchecked(32, 30));
+    }
+
+    #[test]
+    fn test_tiles_with_wrapping_distortions() {
+        let size = Size2d::unchecked(30, 20);
+        let generator = create_generator_with_options(size, 128, EdgeMode::Wrap, false);
+        let tile = Region::new(0, 0, Size2d::unchecked(8, 7));
+
+        assert!(generator.generate_tile(tile).is_err());
+    }
+
+    /// Asserts, that each tile is identical to the same region of the whole map.
+    pub(crate) fn assert_tiles_match(generator: &MapGenerator, tile_size: Size2d) {
+        let map = generator.generate();
+        let grid = TileGrid::new(map.size(), tile_size);
+
+        for (_column, _row, tile) in grid.get_tiles() {
+            assert_eq!(
+                generator.generate_tile(tile).unwrap(),
+                map.crop(tile).unwrap()
+            );
+        }
+    }
+
+    pub(crate) fn create_generator_with_distortions(size: Size2d) -> MapGenerator {
+        create_generator_with_options(size, 0, EdgeMode::Clamp, false)
+    }
+
+    fn create_generator_with_options(
+        size: Size2d,
+        center: u8,
+        edge_mode: EdgeMode,
+        bilinear: bool,
+    ) -> MapGenerator {
+        let create = CreateAttributeStep::new("elevation", 0).unwrap();
+        let noise = Generator2d::Noise2d(Noise::new(1, 5, 0, 255).unwrap());
+        let add = GeneratorStep::new("noise", 0, noise).unwrap();
+        let noise1d = Generator1d::Noise1d(Noise::new(2, 3, 0, 255).unwrap());
+        let distort_x = Distortion1dStep::new(0, noise1d.clone())
+            .with_center(center)
+            .with_edge_mode(edge_mode)
+            .with_bilinear(bilinear)
+            .resize(1.0, 0.02);
+        let distort_y = distort_x.clone();
+        let noise2d = Generator2d::Noise2d(Noise::new(3, 4, 0, 255).unwrap());
+        let distort_2d = Distortion2dStep::new(0, noise2d.clone(), noise2d)
+            .with_center(center)
+            .with_edge_mode(edge_mode)
+            .with_bilinear(bilinear)
+            .resize(0.02, 0.02);
+        let steps = vec![
+            GenerationStep::CreateAttribute(create),
+            GenerationStep::GeneratorAdd(add),
+            GenerationStep::DistortAlongX(distort_x),
+            GenerationStep::DistortAlongY(distort_y),
+            GenerationStep::Distortion2d(distort_2d),
+        ];
+
+        MapGenerator::new("map", size, steps).unwrap()
+    }
 }
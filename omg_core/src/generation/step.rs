@@ -1,24 +1,87 @@
 use crate::data::map::Map2d;
+use crate::data::math::edge::EdgeMode;
+use crate::generation::attributes::biome::ClassifyBiomesStep;
+use crate::generation::attributes::components::LabelComponentsStep;
 use crate::generation::attributes::create::CreateAttributeStep;
+use crate::generation::attributes::distance::DistanceTransformStep;
 use crate::generation::attributes::distortion1d::Distortion1dStep;
 use crate::generation::attributes::distortion2d::Distortion2dStep;
-use crate::generation::attributes::generator::GeneratorStep;
+use crate::generation::attributes::expression::ExpressionStep;
+use crate::generation::attributes::filter::FilterStep;
+use crate::generation::attributes::generator::{ApplyGeneratorStep, GeneratorStep};
+use crate::generation::attributes::import::ImportAttributeStep;
+use crate::generation::attributes::mask::MaskedStep;
+use crate::generation::attributes::math::AttributeMathStep;
 use crate::generation::attributes::modify::ModifyWithAttributeStep;
-use crate::generation::attributes::transformer::TransformAttribute2dStep;
+use crate::generation::attributes::normalise::NormaliseStep;
+use crate::generation::attributes::rainfall::RainfallSimulationStep;
+use crate::generation::attributes::tectonics::PlateTectonicsStep;
+use crate::generation::attributes::temperature::TemperatureModelStep;
+use crate::generation::attributes::terrain::DeriveTerrainStep;
+use crate::generation::attributes::transformer::{
+    TransformAttribute2dStep, TransformAttributeNdStep,
+};
 use GenerationStep::*;
+use StepContext::*;
+
+/// Which cells does a [`GenerationStep`] need to calculate the value of a cell?
+/// Determines, if a region of a map can be generated without the rest.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StepContext {
+    /// Only the cell itself. Any region can be generated on its own.
+    PointWise,
+    /// All cells up to this distance. A region needs a border of this size (halo).
+    Neighbourhood(u32),
+    /// The whole map, e.g. for statistics. A region can't be generated on its own.
+    Global,
+}
+
+impl StepContext {
+    /// Returns the size of the border, which a region needs.
+    /// Is [`None`] for global steps.
+    ///
+    /// ```
+    ///# use omg_core::generation::step::StepContext::*;
+    /// assert_eq!(PointWise.get_halo(), Some(0));
+    /// assert_eq!(Neighbourhood(5).get_halo(), Some(5));
+    /// assert_eq!(Global.get_halo(), None);
+    /// ```
+    pub fn get_halo(&self) -> Option<u32> {
+        match self {
+            PointWise => Some(0),
+            Neighbourhood(halo) => Some(*halo),
+            Global => None,
+        }
+    }
+}
 
 /// A step during [`MapGeneration`](crate::generation::MapGeneration).
 #[derive(Debug, PartialEq, Clone)]
 pub enum GenerationStep {
+    ApplyGenerator(ApplyGeneratorStep),
+    AttributeMath(AttributeMathStep),
+    ClassifyBiomes(ClassifyBiomesStep),
     CreateAttribute(CreateAttributeStep),
     Debug(String),
+    DeriveTerrain(DeriveTerrainStep),
     DistortAlongX(Distortion1dStep),
     DistortAlongY(Distortion1dStep),
     Distortion2d(Distortion2dStep),
+    DistanceTransform(DistanceTransformStep),
+    Expression(ExpressionStep),
+    Filter(FilterStep),
     GeneratorAdd(GeneratorStep),
     GeneratorSub(GeneratorStep),
+    ImportAttribute(ImportAttributeStep),
+    LabelComponents(LabelComponentsStep),
+    Masked(MaskedStep),
     ModifyWithAttribute(ModifyWithAttributeStep),
+    Normalise(NormaliseStep),
+    PlateTectonics(PlateTectonicsStep),
+    RainfallSimulation(RainfallSimulationStep),
+    TemperatureModel(TemperatureModelStep),
     TransformAttribute2d(TransformAttribute2dStep),
+    TransformAttributeNd(TransformAttributeNdStep),
 }
 
 impl GenerationStep {
@@ -28,15 +91,132 @@ impl GenerationStep {
     /// Runs the step.
     pub fn run(&self, map: &mut Map2d) {
         match self {
+            ApplyGenerator(step) => step.run(map),
+            AttributeMath(step) => step.run(map),
+            ClassifyBiomes(step) => step.run(map),
             CreateAttribute(step) => step.run(map),
             Debug(text) => info!("Debug step: {}", text),
+            DeriveTerrain(step) => step.run(map),
             DistortAlongX(step) => step.distort_along_x(map),
             DistortAlongY(step) => step.distort_along_y(map),
             Distortion2d(step) => step.run(map),
+            DistanceTransform(step) => step.run(map),
+            Expression(step) => step.run(map),
+            Filter(step) => step.run(map),
             GeneratorAdd(step) => step.add(map),
             GeneratorSub(step) => step.sub(map),
+            ImportAttribute(step) => step.run(map),
+            LabelComponents(step) => step.run(map),
+            Masked(step) => step.run(map),
             ModifyWithAttribute(step) => step.run(map),
+            Normalise(step) => step.run(map),
+            PlateTectonics(step) => step.run(map),
+            RainfallSimulation(step) => step.run(map),
+            TemperatureModel(step) => step.run(map),
             TransformAttribute2d(step) => step.run(map),
+            TransformAttributeNd(step) => step.run(map),
+        }
+    }
+
+    /// Returns, which cells the step needs to calculate the value of a cell.
+    pub fn get_context(&self) -> StepContext {
+        match self {
+            DistortAlongX(step) | DistortAlongY(step) => {
+                get_distortion_context(step.edge_mode(), step.get_max_shift())
+            }
+            Distortion2d(step) => get_distortion_context(step.edge_mode(), step.get_max_shift()),
+            DistanceTransform(step) => Neighbourhood(step.get_max_distance()),
+            Filter(step) => Neighbourhood(step.filter().get_radius()),
+            DeriveTerrain(_) => Neighbourhood(1),
+            LabelComponents(_) | Normalise(_) => Global,
+            Masked(step) => step.get_context(),
+            RainfallSimulation(step) => Neighbourhood(step.max_distance()),
+            ApplyGenerator(_)
+            | AttributeMath(_)
+            | ClassifyBiomes(_)
+            | CreateAttribute(_)
+            | Debug(_)
+            | Expression(_)
+            | GeneratorAdd(_)
+            | GeneratorSub(_)
+            | ImportAttribute(_)
+            | ModifyWithAttribute(_)
+            | PlateTectonics(_)
+            | TemperatureModel(_)
+            | TransformAttribute2d(_)
+            | TransformAttributeNd(_) => PointWise,
         }
     }
+
+    /// Returns the ids of the existing attributes, which the step can change.
+    /// Attributes created by the step are not included.
+    pub fn get_target_ids(&self, map: &Map2d) -> Vec<usize> {
+        match self {
+            ApplyGenerator(step) => vec![step.attribute_id()],
+            AttributeMath(step) => map.get_attribute_id(step.target()).into_iter().collect(),
+            ClassifyBiomes(step) => vec![step.target_id()],
+            DeriveTerrain(step) => map.get_attribute_id(step.attribute()).into_iter().collect(),
+            DistortAlongX(step) | DistortAlongY(step) => vec![step.attribute_id()],
+            Distortion2d(step) => vec![step.attribute_id()],
+            DistanceTransform(step) => vec![step.target_id()],
+            Expression(step) => vec![step.target_id()],
+            Filter(step) => vec![step.target_id()],
+            GeneratorAdd(step) | GeneratorSub(step) => vec![step.attribute_id()],
+            ImportAttribute(step) => vec![step.attribute_id()],
+            LabelComponents(step) => match step.fill() {
+                Some(_) => vec![step.source_id(), step.target_id()],
+                None => vec![step.target_id()],
+            },
+            Masked(step) => step.step().get_target_ids(map),
+            ModifyWithAttribute(step) => vec![step.target_id()],
+            Normalise(step) => vec![step.attribute_id()],
+            PlateTectonics(step) => vec![step.plate_id(), step.elevation_id()],
+            RainfallSimulation(step) => vec![step.rainfall_id()],
+            TemperatureModel(step) => vec![step.temperature_id()],
+            TransformAttribute2d(step) => vec![step.target_id()],
+            TransformAttributeNd(step) => vec![step.target_id()],
+            CreateAttribute(_) | Debug(_) => Vec::new(),
+        }
+    }
+
+    /// Returns a copy for a map with a different resolution.
+    /// All coordinates & lengths are scaled by a factor per axis.
+    pub fn resize(&self, factor_x: f32, factor_y: f32) -> GenerationStep {
+        match self {
+            ApplyGenerator(step) => ApplyGenerator(step.resize(factor_x, factor_y)),
+            DistortAlongX(step) => DistortAlongX(step.resize(factor_y, factor_x)),
+            DistortAlongY(step) => DistortAlongY(step.resize(factor_x, factor_y)),
+            Distortion2d(step) => Distortion2d(step.resize(factor_x, factor_y)),
+            DistanceTransform(step) => DistanceTransform(step.resize((factor_x + factor_y) / 2.0)),
+            Expression(step) => Expression(step.resize(factor_x, factor_y)),
+            Filter(step) => Filter(step.resize((factor_x + factor_y) / 2.0)),
+            DeriveTerrain(step) => DeriveTerrain(step.resize((factor_x + factor_y) / 2.0)),
+            GeneratorAdd(step) => GeneratorAdd(step.resize(factor_x, factor_y)),
+            GeneratorSub(step) => GeneratorSub(step.resize(factor_x, factor_y)),
+            LabelComponents(step) => LabelComponents(step.resize(factor_x, factor_y)),
+            Masked(step) => Masked(step.resize(factor_x, factor_y)),
+            PlateTectonics(step) => PlateTectonics(step.resize((factor_x + factor_y) / 2.0)),
+            RainfallSimulation(step) => {
+                RainfallSimulation(step.resize((factor_x + factor_y) / 2.0))
+            }
+            TemperatureModel(step) => TemperatureModel(step.resize(factor_y)),
+            AttributeMath(_)
+            | ClassifyBiomes(_)
+            | CreateAttribute(_)
+            | Debug(_)
+            | ImportAttribute(_)
+            | ModifyWithAttribute(_)
+            | Normalise(_)
+            | TransformAttribute2d(_)
+            | TransformAttributeNd(_) => self.clone(),
+        }
+    }
+}
+
+/// Wrapping reads cells at the opposite edge of the whole map.
+fn get_distortion_context(edge_mode: EdgeMode, max_shift: u32) -> StepContext {
+    match edge_mode {
+        EdgeMode::Wrap => Global,
+        EdgeMode::Clamp | EdgeMode::Mirror => Neighbourhood(max_shift),
+    }
 }
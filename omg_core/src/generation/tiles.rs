@@ -0,0 +1,83 @@
+use crate::data::math::region::Region;
+use crate::data::math::size2d::Size2d;
+
+/// Splits a world into tiles, which can be generated one after another.
+/// The tiles at the right & bottom border are smaller, if the world isn't a multiple of the tile size.
+#[derive(new, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TileGrid {
+    world_size: Size2d,
+    tile_size: Size2d,
+}
+
+impl TileGrid {
+    pub fn world_size(&self) -> Size2d {
+        self.world_size
+    }
+
+    pub fn tile_size(&self) -> Size2d {
+        self.tile_size
+    }
+
+    /// Returns the number of tiles along the x-axis.
+    ///
+    /// ```
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::tiles::TileGrid;
+    /// let grid = TileGrid::new(Size2d::unchecked(10, 10), Size2d::unchecked(4, 5));
+    ///
+    /// assert_eq!(grid.columns(), 3);
+    /// ```
+    pub fn columns(&self) -> u32 {
+        self.world_size.width().div_ceil(self.tile_size.width())
+    }
+
+    /// Returns the number of tiles along the y-axis.
+    ///
+    /// ```
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::tiles::TileGrid;
+    /// let grid = TileGrid::new(Size2d::unchecked(10, 10), Size2d::unchecked(4, 5));
+    ///
+    /// assert_eq!(grid.rows(), 2);
+    /// ```
+    pub fn rows(&self) -> u32 {
+        self.world_size.height().div_ceil(self.tile_size.height())
+    }
+
+    /// Returns the region of a tile.
+    ///
+    /// ```
+    ///# use omg_core::data::math::region::Region;
+    ///# use omg_core::data::math::size2d::Size2d;
+    ///# use omg_core::generation::tiles::TileGrid;
+    /// let grid = TileGrid::new(Size2d::unchecked(10, 10), Size2d::unchecked(4, 5));
+    ///
+    /// assert_eq!(grid.get_tile(0, 0), Some(Region::new(0, 0, Size2d::unchecked(4, 5))));
+    /// assert_eq!(grid.get_tile(2, 1), Some(Region::new(8, 5, Size2d::unchecked(2, 5))));
+    /// assert_eq!(grid.get_tile(3, 0), None);
+    /// assert_eq!(grid.get_tile(0, 2), None);
+    /// ```
+    pub fn get_tile(&self, column: u32, row: u32) -> Option<Region> {
+        if column >= self.columns() || row >= self.rows() {
+            return None;
+        }
+
+        let x = column * self.tile_size.width();
+        let y = row * self.tile_size.height();
+        let width = self.tile_size.width().min(self.world_size.width() - x);
+        let height = self.tile_size.height().min(self.world_size.height() - y);
+
+        Some(Region::new(x, y, Size2d::unchecked(width, height)))
+    }
+
+    /// Returns the column, row & region of all tiles row by row.
+    pub fn get_tiles(&self) -> Vec<(u32, u32, Region)> {
+        (0..self.rows())
+            .flat_map(|row| (0..self.columns()).map(move |column| (column, row)))
+            .filter_map(|(column, row)| {
+                self.get_tile(column, row)
+                    .map(|region| (column, row, region))
+            })
+            .collect()
+    }
+}
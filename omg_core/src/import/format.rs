@@ -0,0 +1,214 @@
+use crate::data::map::attribute::Attribute;
+use crate::data::math::size2d::Size2d;
+use crate::import::range::{ValueRange, RANGE_16_BIT, RANGE_8_BIT};
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::fs::File;
+use ImportFormat::*;
+
+/// The file formats to import an [`Attribute`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ImportFormat {
+    /// A PNG with 8 or 16 bits per channel. Colors are converted to grayscale.
+    Png,
+    /// A raw heightmap with an unsigned 16 bit integer in little endian per cell.
+    /// The file has no header, so the size must be known.
+    RawR16(Size2d),
+    /// A raw heightmap with a 32 bit float in little endian per cell.
+    /// The file has no header, so the size must be known.
+    RawR32(Size2d),
+    /// A text file with a row of cells per line. The values are separated by commas, semicolons or whitespace.
+    Csv,
+}
+
+impl ImportFormat {
+    /// Reads an attribute from a file. Its size is defined by the file.
+    ///
+    /// Without a [`ValueRange`], 8 bit values are unchanged & 16 bit values are mapped to the full range of 8 bit.
+    /// 32 bit floats & CSV values are limited to the range of 8 bit.
+    pub fn import(&self, name: &str, path: &str, range: Option<&ValueRange>) -> Result<Attribute> {
+        info!("Import attribute '{}' as {:?} from '{}'", name, self, path);
+
+        let (size, values, default_range) = match self {
+            Png => read_png(path),
+            RawR16(size) => read_raw(path, *size, 2, RANGE_16_BIT, |bytes| {
+                u16::from_le_bytes([bytes[0], bytes[1]]) as f32
+            }),
+            RawR32(size) => read_raw(path, *size, 4, RANGE_8_BIT, |bytes| {
+                f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+            }),
+            Csv => read_csv(path),
+        }
+        .with_context(|| format!("Failed to import attribute '{}' from '{}'", name, path))?;
+
+        let range = range.unwrap_or(&default_range);
+        let values = values.into_iter().map(|v| range.convert(v)).collect();
+
+        Attribute::new(name, size, values)
+    }
+}
+
+fn read_png(path: &str) -> Result<(Size2d, Vec<f32>, ValueRange)> {
+    let mut decoder = png::Decoder::new(File::open(path)?);
+    decoder.set_transformations(png::Transformations::EXPAND);
+    let mut reader = decoder.read_info()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+    let data = &buffer[..info.buffer_size()];
+
+    let (sample_size, range) = match info.bit_depth {
+        png::BitDepth::Sixteen => (2, RANGE_16_BIT),
+        _ => (1, RANGE_8_BIT),
+    };
+    let samples: Vec<f32> = data
+        .chunks_exact(sample_size)
+        .map(|bytes| match sample_size {
+            2 => u16::from_be_bytes([bytes[0], bytes[1]]) as f32,
+            _ => bytes[0] as f32,
+        })
+        .collect();
+
+    let values = match info.color_type.samples() {
+        1 | 2 => samples
+            .chunks_exact(info.color_type.samples())
+            .map(|pixel| pixel[0])
+            .collect(),
+        channels => samples
+            .chunks_exact(channels)
+            .map(|pixel| 0.299 * pixel[0] + 0.587 * pixel[1] + 0.114 * pixel[2])
+            .collect(),
+    };
+
+    Ok((Size2d::new(info.width, info.height)?, values, range))
+}
+
+fn read_raw<F: Fn(&[u8]) -> f32>(
+    path: &str,
+    size: Size2d,
+    bytes_per_cell: usize,
+    range: ValueRange,
+    convert: F,
+) -> Result<(Size2d, Vec<f32>, ValueRange)> {
+    let data = fs::read(path)?;
+
+    if data.len() != size.get_area() * bytes_per_cell {
+        bail!(
+            "The file has {} bytes instead of {} for {}x{} cells!",
+            data.len(),
+            size.get_area() * bytes_per_cell,
+            size.width(),
+            size.height()
+        );
+    }
+
+    let values = data.chunks_exact(bytes_per_cell).map(convert).collect();
+
+    Ok((size, values, range))
+}
+
+fn read_csv(path: &str) -> Result<(Size2d, Vec<f32>, ValueRange)> {
+    let text = fs::read_to_string(path)?;
+    let mut values = Vec::new();
+    let mut width = 0;
+    let mut height = 0;
+
+    for (line_index, line) in text.lines().enumerate() {
+        let row = line
+            .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .filter(|value| !value.is_empty())
+            .map(|value| {
+                value.parse::<f32>().with_context(|| {
+                    format!("Invalid value '{}' in line {}", value, line_index + 1)
+                })
+            })
+            .collect::<Result<Vec<f32>>>()?;
+
+        if row.is_empty() {
+            continue;
+        } else if height == 0 {
+            width = row.len();
+        } else if row.len() != width {
+            bail!(
+                "Line {} has {} values instead of {}!",
+                line_index + 1,
+                row.len(),
+                width
+            );
+        }
+
+        values.extend(row);
+        height += 1;
+    }
+
+    Ok((Size2d::new(width as u32, height)?, values, RANGE_8_BIT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::format::ExportFormat;
+
+    #[test]
+    fn test_import_png() {
+        let attribute = Attribute::new("test", Size2d::unchecked(3, 1), vec![0, 1, 255]).unwrap();
+        let path = get_path("png");
+
+        ExportFormat::Png16.export(&attribute, None, &path).unwrap();
+
+        assert_eq!(import(Png, &path, None), attribute);
+    }
+
+    #[test]
+    fn test_import_raw_r16() {
+        let path = get_path("r16");
+        fs::write(&path, [0, 0, 1, 1, 255, 255, 0, 128]).unwrap();
+        let size = Size2d::unchecked(2, 2);
+
+        let attribute = import(RawR16(size), &path, None);
+
+        assert_eq!(attribute.size(), &size);
+        assert_eq!(attribute.get_all(), &vec![0, 1, 255, 128]);
+    }
+
+    #[test]
+    fn test_import_raw_with_wrong_size() {
+        let path = get_path("r32");
+        fs::write(&path, [0, 0, 0, 0]).unwrap();
+        let format = RawR32(Size2d::unchecked(2, 1));
+
+        assert!(format.import("test", &path, None).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_import_csv() {
+        let path = get_path("csv");
+        fs::write(&path, "-10, 0, 10\n\n20;30 40\n").unwrap();
+        let range = ValueRange::new(-10.0, 41.0).unwrap();
+
+        let attribute = import(Csv, &path, Some(&range));
+
+        assert_eq!(attribute.size(), &Size2d::unchecked(3, 2));
+        assert_eq!(attribute.get_all(), &vec![0, 50, 100, 150, 200, 250]);
+    }
+
+    #[test]
+    fn test_import_csv_with_different_rows() {
+        let path = get_path("invalid.csv");
+        fs::write(&path, "1, 2\n3\n").unwrap();
+
+        assert!(Csv.import("test", &path, None).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    fn get_path(extension: &str) -> String {
+        let path = std::env::temp_dir().join(format!("omg_import_test.{}", extension));
+        path.to_str().unwrap().to_string()
+    }
+
+    fn import(format: ImportFormat, path: &str, range: Option<&ValueRange>) -> Attribute {
+        let attribute = format.import("test", path, range).unwrap();
+        fs::remove_file(path).unwrap();
+        attribute
+    }
+}
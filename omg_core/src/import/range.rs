@@ -0,0 +1,69 @@
+use anyhow::{bail, Result};
+
+/// The full range of an unsigned 8 bit integer.
+pub const RANGE_8_BIT: ValueRange = ValueRange {
+    min: 0.0,
+    max: u8::MAX as f32,
+};
+
+/// The full range of an unsigned 16 bit integer.
+pub const RANGE_16_BIT: ValueRange = ValueRange {
+    min: 0.0,
+    max: u16::MAX as f32,
+};
+
+/// Maps a range of imported values linearly to the full range of an [`Attribute`](crate::data::map::attribute::Attribute).
+/// E.g. from meters to elevation values.
+///
+/// Values outside the range are limited to it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ValueRange {
+    min: f32,
+    max: f32,
+}
+
+impl ValueRange {
+    /// Creates a range, unless it is empty:
+    ///
+    /// ```
+    ///# use omg_core::import::range::ValueRange;
+    /// assert!(ValueRange::new(100.0, 100.0).is_err());
+    /// assert!(ValueRange::new(200.0, 100.0).is_err());
+    /// ```
+    pub fn new(min: f32, max: f32) -> Result<ValueRange> {
+        if min >= max {
+            bail!(
+                "The range's minimum {} must be below its maximum {}!",
+                min,
+                max
+            );
+        }
+
+        Ok(ValueRange { min, max })
+    }
+
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Converts an imported value.
+    ///
+    /// ```
+    ///# use omg_core::import::range::ValueRange;
+    /// let range = ValueRange::new(-100.0, 410.0).unwrap();
+    ///
+    /// assert_eq!(range.convert(-200.0), 0);
+    /// assert_eq!(range.convert(-100.0), 0);
+    /// assert_eq!(range.convert(0.0), 50);
+    /// assert_eq!(range.convert(410.0), 255);
+    /// assert_eq!(range.convert(1000.0), 255);
+    /// ```
+    pub fn convert(&self, value: f32) -> u8 {
+        let factor = (value - self.min) / (self.max - self.min);
+        (factor * u8::MAX as f32).round().clamp(0.0, u8::MAX as f32) as u8
+    }
+}
@@ -0,0 +1,10 @@
+use crate::data::layout::ViewLayout;
+use anyhow::Result;
+
+/// This is an interface to save & load the view layouts of a map.
+/// It keeps additional dependencies like [serde](https://serde.rs) out of the core repo.
+pub trait LayoutStorage {
+    fn read(&self, path: &str) -> Result<Vec<ViewLayout>>;
+
+    fn write(&self, layouts: &[ViewLayout], path: &str) -> Result<()>;
+}
@@ -6,6 +6,8 @@ extern crate log;
 extern crate unwrap;
 
 pub mod data;
+pub mod export;
 pub mod generation;
+pub mod import;
 pub mod interface;
 pub mod logging;
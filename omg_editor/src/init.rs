@@ -1,8 +1,13 @@
+use anyhow::Result;
+use omg_core::data::layout::ViewLayout;
 use omg_core::data::map::Map2d;
 use omg_core::data::math::selector::ColorSelector;
+use omg_core::interface::layout::LayoutStorage;
 use omg_core::interface::selector::SelectorStorage;
+use omg_serde::interface::layout::LayoutStorageWithSerde;
 use omg_serde::interface::selector::SelectorStorageWithSerde;
 use std::collections::HashMap;
+use std::path::Path;
 
 pub fn init_selectors(map: &Map2d) -> HashMap<usize, ColorSelector> {
     let selector_storage = SelectorStorageWithSerde::new();
@@ -21,3 +26,12 @@ pub fn init_selectors(map: &Map2d) -> HashMap<usize, ColorSelector> {
         })
         .collect()
 }
+
+/// Reads the layouts, but starts without any if the file doesn't exist yet.
+pub fn init_layouts(path: &str) -> Result<Vec<ViewLayout>> {
+    if !Path::new(path).exists() {
+        return Ok(Vec::new());
+    }
+
+    LayoutStorageWithSerde::new().read(path)
+}
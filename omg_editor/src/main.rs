@@ -4,23 +4,53 @@ extern crate rocket;
 
 pub mod init;
 
-use crate::init::init_selectors;
+use crate::init::{init_layouts, init_selectors};
 use anyhow::Result;
 use image::ColorType;
+use omg_core::data::layout::{LayoutType, ViewLayout};
 use omg_core::data::map::attribute::Attribute;
+use omg_core::data::map::statistics::Histogram;
 use omg_core::data::map::Map2d;
+use omg_core::data::math::predicate::Predicate;
 use omg_core::data::math::selector::ColorSelector;
+use omg_core::generation::MapGenerator;
+use omg_core::interface::layout::LayoutStorage;
 use omg_core::interface::map::MapStorage;
 use omg_core::logging::init_logging;
+use omg_serde::data::statistics::PERCENTILES;
+use omg_serde::interface::layout::LayoutStorageWithSerde;
 use omg_serde::interface::map::MapStorageWithSerde;
+use rocket::form::Form;
 use rocket::fs::NamedFile;
+use rocket::response::status::BadRequest;
+use rocket::response::Redirect;
 use rocket::{routes, State};
 use rocket_dyn_templates::{context, Template};
 use std::collections::HashMap;
+use std::fs;
+use std::sync::RwLock;
+
+const RECIPE: &str = "biome";
+const EXPORT_DIR: &str = "../temp";
+const HISTOGRAM_BINS: usize = 64;
+const HISTOGRAM_HEIGHT: usize = 200;
+const MAX_CATEGORIES: usize = 32;
 
 struct EditorData {
+    map_generation: MapGenerator,
     map: Map2d,
     selectors: HashMap<usize, ColorSelector>,
+    layouts: RwLock<Vec<ViewLayout>>,
+    layout_path: String,
+}
+
+#[derive(FromForm)]
+struct LayoutForm {
+    name: String,
+    layout_type: String,
+    columns: u32,
+    opacity: u8,
+    attributes: Vec<usize>,
 }
 
 #[get("/")]
@@ -34,10 +64,22 @@ fn home(data: &State<EditorData>) -> Template {
             width: data.map.size().width(),
             height: data.map.size().height(),
             attributes: get_attributes(&data.map),
+            layouts: get_layouts(data),
+            exports: get_exports(data),
         },
     )
 }
 
+#[post("/export")]
+async fn export(data: &State<EditorData>) -> Result<Redirect, BadRequest<String>> {
+    fs::create_dir_all(EXPORT_DIR).map_err(|e| BadRequest(format!("{:?}", e)))?;
+    data.map_generation
+        .export(&data.map, EXPORT_DIR)
+        .map_err(|e| BadRequest(format!("{:?}", e)))?;
+
+    Ok(Redirect::to(uri!(home)))
+}
+
 #[get("/view/<attribute_id>")]
 async fn view_attribute(data: &State<EditorData>, attribute_id: usize) -> Template {
     Template::render(
@@ -46,30 +88,191 @@ async fn view_attribute(data: &State<EditorData>, attribute_id: usize) -> Templa
             attribute_id: attribute_id,
             attribute_name: get_attribute_name(&data.map, attribute_id),
             attributes: get_attributes(&data.map),
+            layouts: get_layouts(data),
         },
     )
 }
 
-#[get("/view/<id0>/<id1>/<id2>/<id3>")]
-async fn view_quad(
+#[get("/statistics/<attribute_id>?<mask>&<min>&<max>")]
+async fn view_statistics(
     data: &State<EditorData>,
-    id0: usize,
-    id1: usize,
-    id2: usize,
-    id3: usize,
-) -> Template {
+    attribute_id: usize,
+    mask: Option<usize>,
+    min: Option<u8>,
+    max: Option<u8>,
+) -> Result<Template, BadRequest<String>> {
+    let attribute = data
+        .map
+        .get_attribute(attribute_id)
+        .ok_or_else(|| BadRequest(format!("Unknown attribute {}!", attribute_id)))?;
+    let mask_attribute = mask
+        .map(|id| {
+            data.map
+                .get_attribute(id)
+                .ok_or_else(|| BadRequest(format!("Unknown mask {}!", id)))
+        })
+        .transpose()?;
+    let min = min.unwrap_or(u8::MIN);
+    let max = max.unwrap_or(u8::MAX);
+
+    let histogram = if let Some(mask_attribute) = mask_attribute {
+        let predicate =
+            Predicate::new_in_range(min, max).map_err(|e| BadRequest(format!("{:?}", e)))?;
+        Histogram::with_mask(attribute, mask_attribute, &predicate)
+            .map_err(|e| BadRequest(format!("{:?}", e)))?
+    } else {
+        Histogram::new(attribute)
+    };
+
+    let bins = histogram.bins(HISTOGRAM_BINS);
+    let highest_bin = bins.iter().copied().max().unwrap_or(0).max(1);
+    let bars: Vec<(usize, usize, usize)> = bins
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            (
+                i * 256 / HISTOGRAM_BINS,
+                count,
+                count * HISTOGRAM_HEIGHT / highest_bin,
+            )
+        })
+        .collect();
+    let percentiles: Vec<(f32, u8)> = PERCENTILES
+        .iter()
+        .filter_map(|p| histogram.percentile(*p).map(|value| (*p, value)))
+        .collect();
+    let shares = histogram.shares();
+    let shares: Vec<(u8, String, u32)> = if shares.len() <= MAX_CATEGORIES {
+        shares
+            .into_iter()
+            .map(|(value, share)| {
+                let percentage = share * 100.0;
+                (value, format!("{:.2}", percentage), percentage as u32 * 2)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Ok(Template::render(
+        "statistics",
+        context! {
+            attribute_id: attribute_id,
+            attribute_name: attribute.name(),
+            mask: mask.filter(|id| data.map.get_attribute(*id).is_some()),
+            min: min,
+            max: max,
+            count: histogram.total(),
+            minimum: histogram.min(),
+            maximum: histogram.max(),
+            mean: histogram.mean().map(|mean| format!("{:.2}", mean)),
+            stddev: histogram.stddev().map(|stddev| format!("{:.2}", stddev)),
+            percentiles: percentiles,
+            shares: shares,
+            bars: bars,
+            bar_width: 256 / HISTOGRAM_BINS,
+            chart_height: HISTOGRAM_HEIGHT,
+            attributes: get_attributes(&data.map),
+            layouts: get_layouts(data),
+        },
+    ))
+}
+
+#[get("/layout/<index>")]
+async fn view_layout(data: &State<EditorData>, index: usize) -> Option<Template> {
+    let (layout_name, columns, opacity, panels) = {
+        let layouts = data.layouts.read().unwrap();
+        let layout = layouts.get(index)?;
+        let panels: Vec<(usize, String)> = layout
+            .attributes()
+            .iter()
+            .map(|name| data.map.get_attribute_id(name).map(|id| (id, name.clone())))
+            .collect::<Option<_>>()?;
+        let opacity = match layout.layout_type() {
+            LayoutType::Overlay { opacity } => Some(opacity),
+            _ => None,
+        };
+
+        (layout.name().to_string(), layout.columns(), opacity, panels)
+    };
+
+    Some(Template::render(
+        "view_layout",
+        context! {
+            index: index,
+            layout_name: layout_name,
+            columns: columns,
+            opacity: opacity,
+            panels: panels,
+            width: data.map.size().width(),
+            height: data.map.size().height(),
+            attributes: get_attributes(&data.map),
+            layouts: get_layouts(data),
+        },
+    ))
+}
+
+#[get("/layout/new")]
+async fn new_layout(data: &State<EditorData>) -> Template {
     Template::render(
-        "view_quad",
+        "new_layout",
         context! {
-            attribute0: (id0, get_attribute_name(&data.map, id0)),
-            attribute1: (id1, get_attribute_name(&data.map, id1)),
-            attribute2: (id2, get_attribute_name(&data.map, id2)),
-            attribute3: (id3, get_attribute_name(&data.map, id3)),
             attributes: get_attributes(&data.map),
+            layouts: get_layouts(data),
         },
     )
 }
 
+#[post("/layout/new", data = "<form>")]
+async fn create_layout(
+    data: &State<EditorData>,
+    form: Form<LayoutForm>,
+) -> Result<Redirect, BadRequest<String>> {
+    let layout_type = match form.layout_type.as_str() {
+        "Grid" => LayoutType::Grid {
+            columns: form.columns,
+        },
+        "SideBySide" => LayoutType::SideBySide,
+        "Overlay" => LayoutType::Overlay {
+            opacity: form.opacity,
+        },
+        unknown => return Err(BadRequest(format!("Unknown layout '{}'!", unknown))),
+    };
+    let attributes = form
+        .attributes
+        .iter()
+        .map(|id| {
+            data.map
+                .get_attribute(*id)
+                .map(|attribute| attribute.name().to_string())
+                .ok_or_else(|| BadRequest(format!("Unknown attribute {}!", id)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let layout = ViewLayout::new(form.name.clone(), layout_type, attributes)
+        .map_err(|e| BadRequest(format!("{:?}", e)))?;
+
+    let mut layouts = data.layouts.write().unwrap();
+    layouts.push(layout);
+    save_layouts(data, &layouts)?;
+
+    Ok(Redirect::to(uri!(view_layout(layouts.len() - 1))))
+}
+
+#[post("/layout/<index>/delete")]
+async fn delete_layout(
+    data: &State<EditorData>,
+    index: usize,
+) -> Result<Redirect, BadRequest<String>> {
+    let mut layouts = data.layouts.write().unwrap();
+
+    if index < layouts.len() {
+        layouts.remove(index);
+        save_layouts(data, &layouts)?;
+    }
+
+    Ok(Redirect::to(uri!(home)))
+}
+
 #[get("/map/<attribute_id>")]
 async fn get_map(data: &State<EditorData>, attribute_id: usize) -> Option<NamedFile> {
     let map = &data.map;
@@ -104,16 +307,36 @@ async fn main() -> Result<()> {
 
     let map_storage = MapStorageWithSerde {};
 
-    let map_generation = map_storage.read("../resources/map_generation/biome.yaml")?;
+    let map_generation =
+        map_storage.read(&format!("../resources/map_generation/{}.yaml", RECIPE))?;
     let map = map_generation.generate();
 
     let selectors = init_selectors(&map);
+    let layout_path = format!("../resources/view_layout/{}.yaml", RECIPE);
+    let layouts = RwLock::new(init_layouts(&layout_path)?);
 
     if let Err(e) = rocket::build()
-        .manage(EditorData { map, selectors })
+        .manage(EditorData {
+            map_generation,
+            map,
+            selectors,
+            layouts,
+            layout_path,
+        })
         .mount(
             "/",
-            routes![home, view_attribute, view_quad, get_map, get_color_map],
+            routes![
+                home,
+                export,
+                view_attribute,
+                view_statistics,
+                view_layout,
+                new_layout,
+                create_layout,
+                delete_layout,
+                get_map,
+                get_color_map
+            ],
         )
         .attach(Template::fairing())
         .launch()
@@ -170,3 +393,33 @@ fn get_attribute_name(map: &Map2d, attribute_id: usize) -> &str {
         .map(|a| a.name())
         .unwrap_or("Unknown")
 }
+
+fn get_exports(data: &EditorData) -> Vec<(&str, String, &str)> {
+    data.map_generation
+        .exports()
+        .iter()
+        .map(|preset| {
+            (
+                get_attribute_name(&data.map, preset.attribute_id()),
+                format!("{:?}", preset.format()),
+                preset.path(),
+            )
+        })
+        .collect()
+}
+
+fn get_layouts(data: &EditorData) -> Vec<(usize, String)> {
+    data.layouts
+        .read()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .map(|(i, l)| (i, l.name().to_string()))
+        .collect()
+}
+
+fn save_layouts(data: &EditorData, layouts: &[ViewLayout]) -> Result<(), BadRequest<String>> {
+    LayoutStorageWithSerde::new()
+        .write(layouts, &data.layout_path)
+        .map_err(|e| BadRequest(format!("{:?}", e)))
+}
@@ -0,0 +1,46 @@
+use omg_core::data::map::components::Connectivity;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum ConnectivitySerde {
+    Four,
+    Eight,
+}
+
+type S = ConnectivitySerde;
+type R = Connectivity;
+
+impl From<&ConnectivitySerde> for Connectivity {
+    fn from(connectivity: &ConnectivitySerde) -> Self {
+        match connectivity {
+            S::Four => R::Four,
+            S::Eight => R::Eight,
+        }
+    }
+}
+
+impl From<&Connectivity> for ConnectivitySerde {
+    fn from(connectivity: &Connectivity) -> Self {
+        match connectivity {
+            R::Four => S::Four,
+            R::Eight => S::Eight,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        assert_eq(R::Four);
+        assert_eq(R::Eight);
+    }
+
+    fn assert_eq(start: Connectivity) {
+        let serde: ConnectivitySerde = (&start).into();
+
+        assert_eq!(Connectivity::from(&serde), start)
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::{Context, Result};
+use omg_core::data::layout::{LayoutType, ViewLayout};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum LayoutTypeSerde {
+    Grid { columns: u32 },
+    SideBySide,
+    Overlay { opacity: u8 },
+}
+
+type S = LayoutTypeSerde;
+type R = LayoutType;
+
+impl From<&LayoutTypeSerde> for LayoutType {
+    fn from(layout_type: &LayoutTypeSerde) -> Self {
+        match layout_type {
+            S::Grid { columns } => R::Grid { columns: *columns },
+            S::SideBySide => R::SideBySide,
+            S::Overlay { opacity } => R::Overlay { opacity: *opacity },
+        }
+    }
+}
+
+impl From<LayoutType> for LayoutTypeSerde {
+    fn from(layout_type: LayoutType) -> Self {
+        match layout_type {
+            R::Grid { columns } => S::Grid { columns },
+            R::SideBySide => S::SideBySide,
+            R::Overlay { opacity } => S::Overlay { opacity },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ViewLayoutSerde {
+    name: String,
+    layout_type: LayoutTypeSerde,
+    attributes: Vec<String>,
+}
+
+impl ViewLayoutSerde {
+    pub fn try_convert(self) -> Result<ViewLayout> {
+        let name = self.name.clone();
+        ViewLayout::new(self.name, (&self.layout_type).into(), self.attributes)
+            .with_context(|| format!("Failed to convert layout '{}'!", name))
+    }
+}
+
+impl From<&ViewLayout> for ViewLayoutSerde {
+    fn from(layout: &ViewLayout) -> Self {
+        ViewLayoutSerde {
+            name: layout.name().to_string(),
+            layout_type: layout.layout_type().into(),
+            attributes: layout.attributes().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_grid() {
+        assert_eq(LayoutType::Grid { columns: 2 }, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_convert_side_by_side() {
+        assert_eq(LayoutType::SideBySide, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_convert_overlay() {
+        assert_eq(LayoutType::Overlay { opacity: 40 }, vec!["a", "b"]);
+    }
+
+    fn assert_eq(layout_type: LayoutType, attributes: Vec<&str>) {
+        let attributes = attributes.into_iter().map(|a| a.to_string()).collect();
+        let start = ViewLayout::new("layout", layout_type, attributes).unwrap();
+        let serde: ViewLayoutSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
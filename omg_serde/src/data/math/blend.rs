@@ -0,0 +1,70 @@
+use omg_core::data::math::blend::BlendMode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum BlendModeSerde {
+    Add,
+    Sub,
+    Multiply,
+    Screen,
+    Min,
+    Max,
+    Overwrite,
+    Average,
+}
+
+type S = BlendModeSerde;
+type R = BlendMode;
+
+impl From<&BlendModeSerde> for BlendMode {
+    fn from(mode: &BlendModeSerde) -> Self {
+        match mode {
+            S::Add => R::Add,
+            S::Sub => R::Sub,
+            S::Multiply => R::Multiply,
+            S::Screen => R::Screen,
+            S::Min => R::Min,
+            S::Max => R::Max,
+            S::Overwrite => R::Overwrite,
+            S::Average => R::Average,
+        }
+    }
+}
+
+impl From<&BlendMode> for BlendModeSerde {
+    fn from(mode: &BlendMode) -> Self {
+        match mode {
+            R::Add => S::Add,
+            R::Sub => S::Sub,
+            R::Multiply => S::Multiply,
+            R::Screen => S::Screen,
+            R::Min => S::Min,
+            R::Max => S::Max,
+            R::Overwrite => S::Overwrite,
+            R::Average => S::Average,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        assert_eq(R::Add);
+        assert_eq(R::Sub);
+        assert_eq(R::Multiply);
+        assert_eq(R::Screen);
+        assert_eq(R::Min);
+        assert_eq(R::Max);
+        assert_eq(R::Overwrite);
+        assert_eq(R::Average);
+    }
+
+    fn assert_eq(start: BlendMode) {
+        let serde: BlendModeSerde = (&start).into();
+
+        assert_eq!(BlendMode::from(&serde), start)
+    }
+}
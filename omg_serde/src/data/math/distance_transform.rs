@@ -0,0 +1,46 @@
+use omg_core::data::math::distance_transform::DistanceMetric;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum DistanceMetricSerde {
+    Euclidean,
+    Chamfer,
+}
+
+type S = DistanceMetricSerde;
+type R = DistanceMetric;
+
+impl From<&DistanceMetricSerde> for DistanceMetric {
+    fn from(metric: &DistanceMetricSerde) -> Self {
+        match metric {
+            S::Euclidean => R::Euclidean,
+            S::Chamfer => R::Chamfer,
+        }
+    }
+}
+
+impl From<&DistanceMetric> for DistanceMetricSerde {
+    fn from(metric: &DistanceMetric) -> Self {
+        match metric {
+            R::Euclidean => S::Euclidean,
+            R::Chamfer => S::Chamfer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        assert_eq(R::Euclidean);
+        assert_eq(R::Chamfer);
+    }
+
+    fn assert_eq(start: DistanceMetric) {
+        let serde: DistanceMetricSerde = (&start).into();
+
+        assert_eq!(DistanceMetric::from(&serde), start)
+    }
+}
@@ -0,0 +1,50 @@
+use omg_core::data::math::edge::EdgeMode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum EdgeModeSerde {
+    Clamp,
+    Wrap,
+    Mirror,
+}
+
+type S = EdgeModeSerde;
+type R = EdgeMode;
+
+impl From<&EdgeModeSerde> for EdgeMode {
+    fn from(mode: &EdgeModeSerde) -> Self {
+        match mode {
+            S::Clamp => R::Clamp,
+            S::Wrap => R::Wrap,
+            S::Mirror => R::Mirror,
+        }
+    }
+}
+
+impl From<&EdgeMode> for EdgeModeSerde {
+    fn from(mode: &EdgeMode) -> Self {
+        match mode {
+            R::Clamp => S::Clamp,
+            R::Wrap => S::Wrap,
+            R::Mirror => S::Mirror,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        assert_eq(R::Clamp);
+        assert_eq(R::Wrap);
+        assert_eq(R::Mirror);
+    }
+
+    fn assert_eq(start: EdgeMode) {
+        let serde: EdgeModeSerde = (&start).into();
+
+        assert_eq!(EdgeMode::from(&serde), start)
+    }
+}
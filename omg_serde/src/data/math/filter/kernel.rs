@@ -0,0 +1,42 @@
+use crate::data::math::size2d::Size2dSerde;
+use anyhow::{Context, Result};
+use omg_core::data::math::filter::kernel::Kernel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KernelSerde {
+    size: Size2dSerde,
+    weights: Vec<f32>,
+}
+
+impl KernelSerde {
+    pub fn try_convert(self) -> Result<Kernel> {
+        let size = self
+            .size
+            .try_convert()
+            .context("Failed to convert the size of the kernel!")?;
+        Kernel::new(size, self.weights)
+    }
+}
+
+impl From<&Kernel> for KernelSerde {
+    fn from(kernel: &Kernel) -> Self {
+        KernelSerde {
+            size: (&kernel.size()).into(),
+            weights: kernel.weights().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        let start = Kernel::new_sharpen();
+        let serde: KernelSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
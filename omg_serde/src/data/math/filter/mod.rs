@@ -0,0 +1,80 @@
+use crate::data::math::filter::kernel::KernelSerde;
+use anyhow::{Context, Result};
+use omg_core::data::math::filter::Filter;
+use serde::{Deserialize, Serialize};
+
+pub mod kernel;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FilterSerde {
+    BoxBlur(u32),
+    GaussianBlur(u32),
+    Median(u32),
+    Dilate(u32),
+    Erode(u32),
+    Open(u32),
+    Close(u32),
+    Convolve(KernelSerde),
+}
+
+type S = FilterSerde;
+type R = Filter;
+
+impl FilterSerde {
+    pub fn try_convert(self) -> Result<Filter> {
+        match self {
+            S::BoxBlur(radius) => Ok(R::BoxBlur(radius)),
+            S::GaussianBlur(radius) => Ok(R::GaussianBlur(radius)),
+            S::Median(radius) => Ok(R::Median(radius)),
+            S::Dilate(radius) => Ok(R::Dilate(radius)),
+            S::Erode(radius) => Ok(R::Erode(radius)),
+            S::Open(radius) => Ok(R::Open(radius)),
+            S::Close(radius) => Ok(R::Close(radius)),
+            S::Convolve(kernel) => {
+                let kernel = kernel
+                    .try_convert()
+                    .context("Failed to convert to Filter::Convolve!")?;
+                Ok(R::Convolve(kernel))
+            }
+        }
+    }
+}
+
+impl From<&Filter> for FilterSerde {
+    fn from(filter: &Filter) -> Self {
+        match filter {
+            R::BoxBlur(radius) => S::BoxBlur(*radius),
+            R::GaussianBlur(radius) => S::GaussianBlur(*radius),
+            R::Median(radius) => S::Median(*radius),
+            R::Dilate(radius) => S::Dilate(*radius),
+            R::Erode(radius) => S::Erode(*radius),
+            R::Open(radius) => S::Open(*radius),
+            R::Close(radius) => S::Close(*radius),
+            R::Convolve(kernel) => S::Convolve(kernel.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omg_core::data::math::filter::kernel::Kernel;
+
+    #[test]
+    fn test_conversion() {
+        assert_eq(R::BoxBlur(1));
+        assert_eq(R::GaussianBlur(2));
+        assert_eq(R::Median(3));
+        assert_eq(R::Dilate(4));
+        assert_eq(R::Erode(5));
+        assert_eq(R::Open(6));
+        assert_eq(R::Close(7));
+        assert_eq(R::Convolve(Kernel::new_sharpen()));
+    }
+
+    fn assert_eq(start: Filter) {
+        let serde: FilterSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::data::math::region::RegionSerde;
+use anyhow::{Context, Result};
+use omg_core::data::math::generator::blobs::Blobs;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BlobsSerde {
+    seed: u32,
+    number: u32,
+    region: RegionSerde,
+    min_radius: u32,
+    max_radius: u32,
+}
+
+impl BlobsSerde {
+    pub fn try_convert(&self) -> Result<Blobs> {
+        let region = self
+            .region
+            .try_convert()
+            .context("Failed to convert the region of the blobs!")?;
+        Blobs::new(
+            self.seed,
+            self.number,
+            region,
+            self.min_radius,
+            self.max_radius,
+        )
+    }
+}
+
+impl From<&Blobs> for BlobsSerde {
+    fn from(blobs: &Blobs) -> Self {
+        BlobsSerde {
+            seed: blobs.seed(),
+            number: blobs.number(),
+            region: blobs.region().into(),
+            min_radius: blobs.min_radius(),
+            max_radius: blobs.max_radius(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omg_core::data::math::region::Region;
+    use omg_core::data::math::size2d::Size2d;
+
+    #[test]
+    fn test_conversion() {
+        let region = Region::new(1, 2, Size2d::unchecked(30, 40));
+        let start = Blobs::new(42, 5, region, 3, 6).unwrap();
+        let serde: BlobsSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
@@ -1,5 +1,8 @@
+use crate::data::math::generator::blobs::BlobsSerde;
 use crate::data::math::generator::generator1d::Generator1dSerde;
 use crate::data::math::generator::noise::NoiseSerde;
+use crate::data::math::generator::shape::ShapeSerde;
+use crate::data::math::generator::voronoi::VoronoiSerde;
 use crate::data::math::size2d::Size2dSerde;
 use anyhow::{Context, Result};
 use omg_core::data::math::generator::generator1d::Generator1d;
@@ -17,6 +20,49 @@ pub enum Generator2dSerde {
     },
     IndexGenerator(Size2dSerde),
     Noise2d(NoiseSerde),
+    ApplyToShape {
+        generator: Generator1dSerde,
+        shape: ShapeSerde,
+    },
+    VoronoiCells(VoronoiSerde),
+    VoronoiBorder {
+        generator: Generator1dSerde,
+        voronoi: VoronoiSerde,
+    },
+    RandomBlobs {
+        generator: Generator1dSerde,
+        blobs: BlobsSerde,
+    },
+    Sum(Vec<Generator2dSerde>),
+    Product(Vec<Generator2dSerde>),
+    Min(Vec<Generator2dSerde>),
+    Max(Vec<Generator2dSerde>),
+    Lerp {
+        start: Box<Generator2dSerde>,
+        end: Box<Generator2dSerde>,
+        factor: Box<Generator2dSerde>,
+    },
+    Scale {
+        generator: Box<Generator2dSerde>,
+        factor: f32,
+    },
+    Offset {
+        generator: Box<Generator2dSerde>,
+        offset: f32,
+    },
+    Clamp {
+        generator: Box<Generator2dSerde>,
+        min: u8,
+        max: u8,
+    },
+    Invert(Box<Generator2dSerde>),
+    Warp {
+        generator: Box<Generator2dSerde>,
+        warp_x: Box<Generator2dSerde>,
+        warp_y: Box<Generator2dSerde>,
+        max_offset_x: u32,
+        max_offset_y: u32,
+    },
 }
 
 type S = Generator2dSerde;
@@ -61,10 +107,141 @@ impl Generator2dSerde {
                     .context("Failed to convert to Generator2d::Noise1d!")?;
                 Ok(R::Noise2d(noise))
             }
+            S::ApplyToShape { generator, shape } => {
+                let generator: Generator1d = generator
+                    .try_convert()
+                    .context("Failed to convert the generator of Generator2d::ApplyToShape!")?;
+                let shape = shape
+                    .try_convert()
+                    .context("Failed to convert the shape of Generator2d::ApplyToShape!")?;
+                Ok(Generator2d::new_apply_to_shape(generator, shape))
+            }
+            S::VoronoiCells(voronoi) => {
+                let voronoi = voronoi
+                    .try_convert()
+                    .context("Failed to convert to Generator2d::VoronoiCells!")?;
+                Ok(R::VoronoiCells(voronoi))
+            }
+            S::VoronoiBorder { generator, voronoi } => {
+                let generator = generator
+                    .try_convert()
+                    .context("Failed to convert the generator of Generator2d::VoronoiBorder!")?;
+                let voronoi = voronoi
+                    .try_convert()
+                    .context("Failed to convert the voronoi of Generator2d::VoronoiBorder!")?;
+                Ok(R::VoronoiBorder { generator, voronoi })
+            }
+            S::RandomBlobs { generator, blobs } => {
+                let generator = generator
+                    .try_convert()
+                    .context("Failed to convert the generator of Generator2d::RandomBlobs!")?;
+                let blobs = blobs
+                    .try_convert()
+                    .context("Failed to convert the blobs of Generator2d::RandomBlobs!")?;
+                Ok(R::RandomBlobs { generator, blobs })
+            }
+            S::Sum(generators) => {
+                let generators = convert_all(generators)
+                    .context("Failed to convert the generators of Generator2d::Sum!")?;
+                Generator2d::new_sum(generators)
+            }
+            S::Product(generators) => {
+                let generators = convert_all(generators)
+                    .context("Failed to convert the generators of Generator2d::Product!")?;
+                Generator2d::new_product(generators)
+            }
+            S::Min(generators) => {
+                let generators = convert_all(generators)
+                    .context("Failed to convert the generators of Generator2d::Min!")?;
+                Generator2d::new_min(generators)
+            }
+            S::Max(generators) => {
+                let generators = convert_all(generators)
+                    .context("Failed to convert the generators of Generator2d::Max!")?;
+                Generator2d::new_max(generators)
+            }
+            S::Lerp { start, end, factor } => {
+                let start = start
+                    .try_convert()
+                    .context("Failed to convert the start of Generator2d::Lerp!")?;
+                let end = end
+                    .try_convert()
+                    .context("Failed to convert the end of Generator2d::Lerp!")?;
+                let factor = factor
+                    .try_convert()
+                    .context("Failed to convert the factor of Generator2d::Lerp!")?;
+                Ok(Generator2d::new_lerp(start, end, factor))
+            }
+            S::Scale { generator, factor } => {
+                let generator = generator
+                    .try_convert()
+                    .context("Failed to convert to Generator2d::Scale!")?;
+                Ok(Generator2d::new_scale(generator, factor))
+            }
+            S::Offset { generator, offset } => {
+                let generator = generator
+                    .try_convert()
+                    .context("Failed to convert to Generator2d::Offset!")?;
+                Ok(Generator2d::new_offset(generator, offset))
+            }
+            S::Clamp {
+                generator,
+                min,
+                max,
+            } => {
+                let generator = generator
+                    .try_convert()
+                    .context("Failed to convert to Generator2d::Clamp!")?;
+                Generator2d::new_clamp(generator, min, max)
+            }
+            S::Invert(generator) => {
+                let generator = generator
+                    .try_convert()
+                    .context("Failed to convert to Generator2d::Invert!")?;
+                Ok(Generator2d::new_invert(generator))
+            }
+            S::Warp {
+                generator,
+                warp_x,
+                warp_y,
+                max_offset_x,
+                max_offset_y,
+            } => {
+                let generator = generator
+                    .try_convert()
+                    .context("Failed to convert the generator of Generator2d::Warp!")?;
+                let warp_x = warp_x
+                    .try_convert()
+                    .context("Failed to convert the warp_x of Generator2d::Warp!")?;
+                let warp_y = warp_y
+                    .try_convert()
+                    .context("Failed to convert the warp_y of Generator2d::Warp!")?;
+                Ok(Generator2d::new_warp(
+                    generator,
+                    warp_x,
+                    warp_y,
+                    max_offset_x,
+                    max_offset_y,
+                ))
+            }
         }
     }
 }
 
+fn convert_all(generators: Vec<Generator2dSerde>) -> Result<Vec<Generator2d>> {
+    generators
+        .into_iter()
+        .map(|generator| generator.try_convert())
+        .collect()
+}
+
+fn from_all(generators: &[Generator2d]) -> Vec<Generator2dSerde> {
+    generators
+        .iter()
+        .map(|generator| generator.into())
+        .collect()
+}
+
 impl From<&Generator2d> for Generator2dSerde {
     fn from(generator: &Generator2d) -> Self {
         match generator {
@@ -81,6 +258,59 @@ impl From<&Generator2d> for Generator2dSerde {
             },
             R::IndexGenerator(size) => S::IndexGenerator(size.into()),
             R::Noise2d(noise) => S::Noise2d(noise.into()),
+            R::ApplyToShape { generator, shape } => S::ApplyToShape {
+                generator: generator.into(),
+                shape: shape.into(),
+            },
+            R::VoronoiCells(voronoi) => S::VoronoiCells(voronoi.into()),
+            R::VoronoiBorder { generator, voronoi } => S::VoronoiBorder {
+                generator: generator.into(),
+                voronoi: voronoi.into(),
+            },
+            R::RandomBlobs { generator, blobs } => S::RandomBlobs {
+                generator: generator.into(),
+                blobs: blobs.into(),
+            },
+            R::Sum(generators) => S::Sum(from_all(generators)),
+            R::Product(generators) => S::Product(from_all(generators)),
+            R::Min(generators) => S::Min(from_all(generators)),
+            R::Max(generators) => S::Max(from_all(generators)),
+            R::Lerp { start, end, factor } => S::Lerp {
+                start: Box::new(start.as_ref().into()),
+                end: Box::new(end.as_ref().into()),
+                factor: Box::new(factor.as_ref().into()),
+            },
+            R::Scale { generator, factor } => S::Scale {
+                generator: Box::new(generator.as_ref().into()),
+                factor: *factor,
+            },
+            R::Offset { generator, offset } => S::Offset {
+                generator: Box::new(generator.as_ref().into()),
+                offset: *offset,
+            },
+            R::Clamp {
+                generator,
+                min,
+                max,
+            } => S::Clamp {
+                generator: Box::new(generator.as_ref().into()),
+                min: *min,
+                max: *max,
+            },
+            R::Invert(generator) => S::Invert(Box::new(generator.as_ref().into())),
+            R::Warp {
+                generator,
+                warp_x,
+                warp_y,
+                max_offset_x,
+                max_offset_y,
+            } => S::Warp {
+                generator: Box::new(generator.as_ref().into()),
+                warp_x: Box::new(warp_x.as_ref().into()),
+                warp_y: Box::new(warp_y.as_ref().into()),
+                max_offset_x: *max_offset_x,
+                max_offset_y: *max_offset_y,
+            },
         }
     }
 }
@@ -88,6 +318,10 @@ impl From<&Generator2d> for Generator2dSerde {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use omg_core::data::math::generator::blobs::Blobs;
+    use omg_core::data::math::generator::shape::Shape;
+    use omg_core::data::math::generator::voronoi::Voronoi;
+    use omg_core::data::math::region::Region;
     use omg_core::data::math::size2d::Size2d;
 
     #[test]
@@ -114,6 +348,100 @@ mod tests {
         assert_eq(Generator2d::IndexGenerator(Size2d::unchecked(1, 2)))
     }
 
+    #[test]
+    fn test_convert_apply_to_shape() {
+        let shape = Shape::new_rectangle(1, 2, 3, 4).unwrap();
+        assert_eq(Generator2d::new_apply_to_shape(
+            Generator1d::InputAsOutput,
+            shape,
+        ))
+    }
+
+    #[test]
+    fn test_convert_voronoi_cells() {
+        assert_eq(Generator2d::VoronoiCells(Voronoi::new(42, 10).unwrap()))
+    }
+
+    #[test]
+    fn test_convert_voronoi_border() {
+        assert_eq(Generator2d::VoronoiBorder {
+            generator: Generator1d::InputAsOutput,
+            voronoi: Voronoi::new(42, 10).unwrap(),
+        })
+    }
+
+    #[test]
+    fn test_convert_random_blobs() {
+        let region = Region::new(1, 2, Size2d::unchecked(30, 40));
+        assert_eq(Generator2d::RandomBlobs {
+            generator: Generator1d::InputAsOutput,
+            blobs: Blobs::new(42, 5, region, 3, 6).unwrap(),
+        })
+    }
+
+    #[test]
+    fn test_convert_sum() {
+        assert_eq(Generator2d::new_sum(vec![x(), y()]).unwrap())
+    }
+
+    #[test]
+    fn test_convert_product() {
+        assert_eq(Generator2d::new_product(vec![x(), y()]).unwrap())
+    }
+
+    #[test]
+    fn test_convert_min() {
+        assert_eq(Generator2d::new_min(vec![x(), y()]).unwrap())
+    }
+
+    #[test]
+    fn test_convert_max() {
+        assert_eq(Generator2d::new_max(vec![x(), y()]).unwrap())
+    }
+
+    #[test]
+    fn test_convert_lerp() {
+        assert_eq(Generator2d::new_lerp(x(), y(), x()))
+    }
+
+    #[test]
+    fn test_convert_scale() {
+        assert_eq(Generator2d::new_scale(x(), 1.5))
+    }
+
+    #[test]
+    fn test_convert_offset() {
+        assert_eq(Generator2d::new_offset(x(), -10.0))
+    }
+
+    #[test]
+    fn test_convert_clamp() {
+        assert_eq(Generator2d::new_clamp(x(), 10, 20).unwrap())
+    }
+
+    #[test]
+    fn test_convert_invert() {
+        assert_eq(Generator2d::new_invert(x()))
+    }
+
+    #[test]
+    fn test_convert_warp() {
+        assert_eq(Generator2d::new_warp(x(), y(), x(), 10, 20))
+    }
+
+    #[test]
+    fn test_failed_conversion_of_empty_sum() {
+        assert!(Generator2dSerde::Sum(Vec::new()).try_convert().is_err())
+    }
+
+    fn x() -> Generator2d {
+        Generator2d::ApplyToX(Generator1d::InputAsOutput)
+    }
+
+    fn y() -> Generator2d {
+        Generator2d::ApplyToY(Generator1d::InputAsOutput)
+    }
+
     fn assert_eq(start: Generator2d) {
         let serde: Generator2dSerde = (&start).into();
 
@@ -0,0 +1,169 @@
+use anyhow::Result;
+use omg_core::data::math::generator::shape::Shape;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ShapeSerde {
+    Circle {
+        center_x: u32,
+        center_y: u32,
+        radius: u32,
+    },
+    Ellipse {
+        center_x: u32,
+        center_y: u32,
+        radius_x: u32,
+        radius_y: u32,
+    },
+    Rectangle {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    },
+    Segment {
+        start_x: u32,
+        start_y: u32,
+        end_x: u32,
+        end_y: u32,
+    },
+    Polygon(Vec<[u32; 2]>),
+}
+
+type S = ShapeSerde;
+type R = Shape;
+
+impl ShapeSerde {
+    pub fn try_convert(self) -> Result<Shape> {
+        match self {
+            S::Circle {
+                center_x,
+                center_y,
+                radius,
+            } => Ok(R::Circle {
+                center_x,
+                center_y,
+                radius,
+            }),
+            S::Ellipse {
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+            } => Shape::new_ellipse(center_x, center_y, radius_x, radius_y),
+            S::Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } => Shape::new_rectangle(x, y, width, height),
+            S::Segment {
+                start_x,
+                start_y,
+                end_x,
+                end_y,
+            } => Ok(R::Segment {
+                start_x,
+                start_y,
+                end_x,
+                end_y,
+            }),
+            S::Polygon(corners) => Shape::new_polygon(corners),
+        }
+    }
+}
+
+impl From<&Shape> for ShapeSerde {
+    fn from(shape: &Shape) -> Self {
+        match shape {
+            R::Circle {
+                center_x,
+                center_y,
+                radius,
+            } => S::Circle {
+                center_x: *center_x,
+                center_y: *center_y,
+                radius: *radius,
+            },
+            R::Ellipse {
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+            } => S::Ellipse {
+                center_x: *center_x,
+                center_y: *center_y,
+                radius_x: *radius_x,
+                radius_y: *radius_y,
+            },
+            R::Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } => S::Rectangle {
+                x: *x,
+                y: *y,
+                width: *width,
+                height: *height,
+            },
+            R::Segment {
+                start_x,
+                start_y,
+                end_x,
+                end_y,
+            } => S::Segment {
+                start_x: *start_x,
+                start_y: *start_y,
+                end_x: *end_x,
+                end_y: *end_y,
+            },
+            R::Polygon(corners) => S::Polygon(corners.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_circle() {
+        assert_eq(Shape::Circle {
+            center_x: 1,
+            center_y: 2,
+            radius: 3,
+        })
+    }
+
+    #[test]
+    fn test_convert_ellipse() {
+        assert_eq(Shape::new_ellipse(1, 2, 3, 4).unwrap())
+    }
+
+    #[test]
+    fn test_convert_rectangle() {
+        assert_eq(Shape::new_rectangle(1, 2, 3, 4).unwrap())
+    }
+
+    #[test]
+    fn test_convert_segment() {
+        assert_eq(Shape::Segment {
+            start_x: 1,
+            start_y: 2,
+            end_x: 3,
+            end_y: 4,
+        })
+    }
+
+    #[test]
+    fn test_convert_polygon() {
+        assert_eq(Shape::new_polygon(vec![[0, 0], [10, 0], [0, 10]]).unwrap())
+    }
+
+    fn assert_eq(start: Shape) {
+        let serde: ShapeSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
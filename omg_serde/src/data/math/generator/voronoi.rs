@@ -0,0 +1,37 @@
+use anyhow::Result;
+use omg_core::data::math::generator::voronoi::Voronoi;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct VoronoiSerde {
+    seed: u32,
+    cell_size: u32,
+}
+
+impl VoronoiSerde {
+    pub fn try_convert(&self) -> Result<Voronoi> {
+        Voronoi::new(self.seed, self.cell_size)
+    }
+}
+
+impl From<&Voronoi> for VoronoiSerde {
+    fn from(voronoi: &Voronoi) -> Self {
+        VoronoiSerde {
+            seed: voronoi.seed(),
+            cell_size: voronoi.cell_size(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        let start = Voronoi::new(42, 10).unwrap();
+        let serde: VoronoiSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
@@ -1,5 +1,12 @@
+pub mod blend;
+pub mod distance_transform;
+pub mod edge;
+pub mod filter;
 pub mod generator;
 pub mod interpolation;
+pub mod predicate;
+pub mod region;
+pub mod resampling;
 pub mod selector;
 pub mod size2d;
 pub mod transformer;
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use omg_core::data::math::predicate::Predicate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum PredicateSerde {
+    Below(u8),
+    Above(u8),
+    Equal(u8),
+    InRange { min: u8, max: u8 },
+    OneOf(Vec<u8>),
+}
+
+type S = PredicateSerde;
+type R = Predicate;
+
+impl PredicateSerde {
+    pub fn try_convert(self) -> Result<Predicate> {
+        match self {
+            S::Below(threshold) => Ok(R::Below(threshold)),
+            S::Above(threshold) => Ok(R::Above(threshold)),
+            S::Equal(value) => Ok(R::Equal(value)),
+            S::InRange { min, max } => R::new_in_range(min, max),
+            S::OneOf(values) => R::new_one_of(values),
+        }
+    }
+}
+
+impl From<&Predicate> for PredicateSerde {
+    fn from(predicate: &Predicate) -> Self {
+        match predicate {
+            R::Below(threshold) => S::Below(*threshold),
+            R::Above(threshold) => S::Above(*threshold),
+            R::Equal(value) => S::Equal(*value),
+            R::InRange { min, max } => S::InRange {
+                min: *min,
+                max: *max,
+            },
+            R::OneOf(values) => S::OneOf(values.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        assert_eq(R::Below(10));
+        assert_eq(R::Above(20));
+        assert_eq(R::Equal(30));
+        assert_eq(R::new_in_range(40, 50).unwrap());
+        assert_eq(R::new_one_of(vec![60, 70]).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        assert!(S::InRange { min: 50, max: 40 }.try_convert().is_err());
+    }
+
+    fn assert_eq(start: Predicate) {
+        let serde: PredicateSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::data::math::size2d::Size2dSerde;
+use anyhow::{Context, Result};
+use omg_core::data::math::region::Region;
+use serde::{Deserialize, Serialize};
+
+#[derive(new, Debug, Serialize, Deserialize)]
+pub struct RegionSerde {
+    x: u32,
+    y: u32,
+    size: Size2dSerde,
+}
+
+impl RegionSerde {
+    pub fn try_convert(&self) -> Result<Region> {
+        let size = self
+            .size
+            .try_convert()
+            .context("Failed to convert the size of the region!")?;
+        Ok(Region::new(self.x, self.y, size))
+    }
+}
+
+impl From<&Region> for RegionSerde {
+    fn from(region: &Region) -> Self {
+        RegionSerde {
+            x: region.x(),
+            y: region.y(),
+            size: (&region.size()).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omg_core::data::math::size2d::Size2d;
+
+    #[test]
+    fn test_conversion() {
+        let start = Region::new(1, 2, Size2d::unchecked(3, 4));
+        let serde: RegionSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
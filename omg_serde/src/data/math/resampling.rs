@@ -0,0 +1,50 @@
+use omg_core::data::math::resampling::ResamplingFilter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum ResamplingFilterSerde {
+    Nearest,
+    Bilinear,
+    Area,
+}
+
+type S = ResamplingFilterSerde;
+type R = ResamplingFilter;
+
+impl From<&ResamplingFilterSerde> for ResamplingFilter {
+    fn from(filter: &ResamplingFilterSerde) -> Self {
+        match filter {
+            S::Nearest => R::Nearest,
+            S::Bilinear => R::Bilinear,
+            S::Area => R::Area,
+        }
+    }
+}
+
+impl From<&ResamplingFilter> for ResamplingFilterSerde {
+    fn from(filter: &ResamplingFilter) -> Self {
+        match filter {
+            R::Nearest => S::Nearest,
+            R::Bilinear => S::Bilinear,
+            R::Area => S::Area,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        assert_eq(R::Nearest);
+        assert_eq(R::Bilinear);
+        assert_eq(R::Area);
+    }
+
+    fn assert_eq(start: ResamplingFilter) {
+        let serde: ResamplingFilterSerde = (&start).into();
+
+        assert_eq!(ResamplingFilter::from(&serde), start)
+    }
+}
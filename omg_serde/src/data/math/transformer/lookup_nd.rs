@@ -0,0 +1,37 @@
+use anyhow::Result;
+use omg_core::data::math::transformer::lookup_nd::LookupTableNd;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LookupTableNdSerde {
+    axes: Vec<Vec<u8>>,
+    values: Vec<u8>,
+}
+
+impl LookupTableNdSerde {
+    pub fn try_convert(self) -> Result<LookupTableNd> {
+        LookupTableNd::new(self.axes, self.values)
+    }
+}
+
+impl From<&LookupTableNd> for LookupTableNdSerde {
+    fn from(lookup: &LookupTableNd) -> Self {
+        LookupTableNdSerde {
+            axes: lookup.axes().to_vec(),
+            values: lookup.values().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        let start = LookupTableNd::new(vec![vec![10, 20], vec![5]], (0..6).collect()).unwrap();
+        let serde: LookupTableNdSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
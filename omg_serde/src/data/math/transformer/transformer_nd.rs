@@ -0,0 +1,197 @@
+use crate::data::math::generator::shape::ShapeSerde;
+use crate::data::math::predicate::PredicateSerde;
+use crate::data::math::transformer::lookup_nd::LookupTableNdSerde;
+use crate::generation::step::get_attribute_id;
+use anyhow::{Context, Result};
+use omg_core::data::math::transformer::transformer_nd::{Rule, RuleCondition, TransformerNd};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum RuleConditionSerde {
+    Value {
+        attribute: String,
+        predicate: PredicateSerde,
+    },
+    InShape {
+        x: String,
+        y: String,
+        shape: ShapeSerde,
+    },
+}
+
+impl RuleConditionSerde {
+    /// Converts the condition with the names of the inputs.
+    pub fn try_convert(self, inputs: &[String]) -> Result<RuleCondition> {
+        match self {
+            RuleConditionSerde::Value {
+                attribute,
+                predicate,
+            } => Ok(RuleCondition::Value {
+                input: get_attribute_id(&attribute, inputs)?,
+                predicate: predicate.try_convert()?,
+            }),
+            RuleConditionSerde::InShape { x, y, shape } => Ok(RuleCondition::InShape {
+                x: get_attribute_id(&x, inputs)?,
+                y: get_attribute_id(&y, inputs)?,
+                shape: shape.try_convert()?,
+            }),
+        }
+    }
+
+    /// Converts the condition with the names of the inputs.
+    pub fn convert(condition: &RuleCondition, inputs: &[String]) -> RuleConditionSerde {
+        match condition {
+            RuleCondition::Value { input, predicate } => RuleConditionSerde::Value {
+                attribute: inputs[*input].clone(),
+                predicate: predicate.into(),
+            },
+            RuleCondition::InShape { x, y, shape } => RuleConditionSerde::InShape {
+                x: inputs[*x].clone(),
+                y: inputs[*y].clone(),
+                shape: shape.into(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RuleSerde {
+    conditions: Vec<RuleConditionSerde>,
+    value: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum TransformerNdSerde {
+    Lookup(LookupTableNdSerde),
+    Rules { rules: Vec<RuleSerde>, default: u8 },
+}
+
+type S = TransformerNdSerde;
+type R = TransformerNd;
+
+impl TransformerNdSerde {
+    /// Converts the transformer with the names of the sources.
+    pub fn try_convert(self, sources: &[String]) -> Result<TransformerNd> {
+        match self {
+            S::Lookup(lookup) => {
+                let table = lookup
+                    .try_convert()
+                    .context("Failed to convert to TransformerNd::Lookup!")?;
+                Ok(R::Lookup(table))
+            }
+            S::Rules { rules, default } => {
+                let rules = rules
+                    .into_iter()
+                    .map(|rule| convert_rule(rule, sources))
+                    .collect::<Result<Vec<_>>>()
+                    .context("Failed to convert to TransformerNd::Rules!")?;
+                R::new_rules(rules, default)
+            }
+        }
+    }
+
+    /// Converts the transformer with the names of the sources.
+    pub fn convert(transformer: &TransformerNd, sources: &[String]) -> TransformerNdSerde {
+        match transformer {
+            R::Lookup(table) => S::Lookup(table.into()),
+            R::Rules { rules, default } => S::Rules {
+                rules: rules
+                    .iter()
+                    .map(|rule| RuleSerde {
+                        conditions: rule
+                            .conditions()
+                            .iter()
+                            .map(|condition| RuleConditionSerde::convert(condition, sources))
+                            .collect(),
+                        value: rule.value(),
+                    })
+                    .collect(),
+                default: *default,
+            },
+        }
+    }
+}
+
+fn convert_rule(rule: RuleSerde, sources: &[String]) -> Result<Rule> {
+    let conditions = rule
+        .conditions
+        .into_iter()
+        .map(|condition| condition.try_convert(sources))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Rule::new(conditions, rule.value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omg_core::data::math::generator::shape::Shape;
+    use omg_core::data::math::predicate::Predicate::*;
+    use omg_core::data::math::transformer::lookup_nd::LookupTableNd;
+    use omg_core::data::math::transformer::transformer_nd::RuleCondition::{InShape, Value};
+
+    #[test]
+    fn test_convert_lookup() {
+        let table = LookupTableNd::new(vec![vec![10, 20], vec![5]], (0..6).collect()).unwrap();
+
+        assert_eq(R::Lookup(table));
+    }
+
+    #[test]
+    fn test_convert_rules() {
+        let ocean = Rule::new(
+            vec![Value {
+                input: 0,
+                predicate: Below(76),
+            }],
+            12,
+        );
+        let desert = Rule::new(
+            vec![
+                Value {
+                    input: 1,
+                    predicate: Above(200),
+                },
+                Value {
+                    input: 2,
+                    predicate: Below(50),
+                },
+            ],
+            9,
+        );
+        let shape = Shape::new_polygon(vec![[0, 0], [100, 0], [0, 100]]).unwrap();
+        let tundra = Rule::new(vec![InShape { x: 1, y: 2, shape }], 3);
+
+        assert_eq(R::new_rules(vec![ocean, desert, tundra], 7).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_source() {
+        let serde = S::Rules {
+            rules: vec![RuleSerde {
+                conditions: vec![RuleConditionSerde::Value {
+                    attribute: "unknown".to_string(),
+                    predicate: PredicateSerde::Below(10),
+                }],
+                value: 3,
+            }],
+            default: 0,
+        };
+
+        assert!(serde.try_convert(&sources()).is_err());
+    }
+
+    fn sources() -> Vec<String> {
+        vec![
+            "elevation".to_string(),
+            "temperature".to_string(),
+            "rainfall".to_string(),
+        ]
+    }
+
+    fn assert_eq(start: TransformerNd) {
+        let serde = TransformerNdSerde::convert(&start, &sources());
+
+        assert_eq!(serde.try_convert(&sources()).unwrap(), start)
+    }
+}
@@ -1,2 +1,5 @@
 pub mod color;
+pub mod components;
+pub mod layout;
 pub mod math;
+pub mod statistics;
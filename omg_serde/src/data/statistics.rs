@@ -0,0 +1,90 @@
+use omg_core::data::map::statistics::Histogram;
+use serde::Serialize;
+
+/// The percentiles listed in [`AttributeStatisticsSerde`].
+pub const PERCENTILES: [f32; 7] = [1.0, 5.0, 25.0, 50.0, 75.0, 95.0, 99.0];
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct PercentileSerde {
+    percentage: f32,
+    value: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ShareSerde {
+    value: u8,
+    share: f32,
+}
+
+/// The statistics of an attribute, e.g. for reports in JSON.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AttributeStatisticsSerde {
+    attribute: String,
+    count: usize,
+    min: Option<u8>,
+    max: Option<u8>,
+    mean: Option<f32>,
+    stddev: Option<f32>,
+    percentiles: Vec<PercentileSerde>,
+    shares: Vec<ShareSerde>,
+    histogram: Vec<usize>,
+}
+
+impl AttributeStatisticsSerde {
+    pub fn new<S: Into<String>>(attribute: S, histogram: &Histogram) -> Self {
+        AttributeStatisticsSerde {
+            attribute: attribute.into(),
+            count: histogram.total(),
+            min: histogram.min(),
+            max: histogram.max(),
+            mean: histogram.mean(),
+            stddev: histogram.stddev(),
+            percentiles: PERCENTILES
+                .iter()
+                .filter_map(|percentage| {
+                    histogram
+                        .percentile(*percentage)
+                        .map(|value| PercentileSerde {
+                            percentage: *percentage,
+                            value,
+                        })
+                })
+                .collect(),
+            shares: histogram
+                .shares()
+                .into_iter()
+                .map(|(value, share)| ShareSerde { value, share })
+                .collect(),
+            histogram: histogram.counts().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omg_core::data::map::attribute::Attribute;
+    use omg_core::data::math::size2d::Size2d;
+
+    #[test]
+    fn test_conversion() {
+        let attribute = Attribute::new("test", Size2d::unchecked(1, 2), vec![3, 3]).unwrap();
+        let histogram = Histogram::new(&attribute);
+        let serde = AttributeStatisticsSerde::new("test", &histogram);
+
+        assert_eq!(serde.count, 2);
+        assert_eq!(serde.min, Some(3));
+        assert_eq!(serde.max, Some(3));
+        assert_eq!(serde.mean, Some(3.0));
+        assert_eq!(serde.stddev, Some(0.0));
+        assert_eq!(serde.percentiles.len(), PERCENTILES.len());
+        assert_eq!(
+            serde.shares,
+            vec![ShareSerde {
+                value: 3,
+                share: 1.0
+            }]
+        );
+        assert_eq!(serde.histogram.len(), 256);
+    }
+}
@@ -0,0 +1,90 @@
+use omg_core::export::format::{ExportFormat, GeoReference};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct GeoReferenceSerde {
+    longitude: f64,
+    latitude: f64,
+    pixel_size: f64,
+}
+
+impl From<&GeoReferenceSerde> for GeoReference {
+    fn from(reference: &GeoReferenceSerde) -> Self {
+        GeoReference::new(
+            reference.longitude,
+            reference.latitude,
+            reference.pixel_size,
+        )
+    }
+}
+
+impl From<&GeoReference> for GeoReferenceSerde {
+    fn from(reference: &GeoReference) -> Self {
+        GeoReferenceSerde {
+            longitude: reference.longitude(),
+            latitude: reference.latitude(),
+            pixel_size: reference.pixel_size(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum ExportFormatSerde {
+    Png8,
+    Png16,
+    RawR16,
+    RawR32,
+    TiffFloat(Option<GeoReferenceSerde>),
+}
+
+type S = ExportFormatSerde;
+type R = ExportFormat;
+
+impl From<&ExportFormatSerde> for ExportFormat {
+    fn from(format: &ExportFormatSerde) -> Self {
+        match format {
+            S::Png8 => R::Png8,
+            S::Png16 => R::Png16,
+            S::RawR16 => R::RawR16,
+            S::RawR32 => R::RawR32,
+            S::TiffFloat(reference) => R::TiffFloat(reference.as_ref().map(|r| r.into())),
+        }
+    }
+}
+
+impl From<&ExportFormat> for ExportFormatSerde {
+    fn from(format: &ExportFormat) -> Self {
+        match format {
+            R::Png8 => S::Png8,
+            R::Png16 => S::Png16,
+            R::RawR16 => S::RawR16,
+            R::RawR32 => S::RawR32,
+            R::TiffFloat(reference) => S::TiffFloat(reference.as_ref().map(|r| r.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_simple_formats() {
+        assert_eq(R::Png8);
+        assert_eq(R::Png16);
+        assert_eq(R::RawR16);
+        assert_eq(R::RawR32);
+    }
+
+    #[test]
+    fn test_convert_tiff() {
+        assert_eq(R::TiffFloat(None));
+        assert_eq(R::TiffFloat(Some(GeoReference::new(10.0, 50.0, 0.01))));
+    }
+
+    fn assert_eq(start: ExportFormat) {
+        let serde: ExportFormatSerde = (&start).into();
+
+        assert_eq!(ExportFormat::from(&serde), start)
+    }
+}
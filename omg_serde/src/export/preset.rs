@@ -0,0 +1,64 @@
+use crate::export::format::ExportFormatSerde;
+use crate::export::rescaling::RescalingSerde;
+use crate::generation::step::get_attribute_id;
+use anyhow::{Context, Result};
+use omg_core::export::preset::ExportPreset;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportPresetSerde {
+    attribute: String,
+    format: ExportFormatSerde,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rescaling: Option<RescalingSerde>,
+    path: String,
+}
+
+impl ExportPresetSerde {
+    pub fn try_convert(self, attributes: &[String]) -> Result<ExportPreset> {
+        let attribute_id = get_attribute_id(&self.attribute, attributes)
+            .context("Failed to convert attribute of ExportPreset!")?;
+        let rescaling = self
+            .rescaling
+            .map(|rescaling| rescaling.try_convert())
+            .transpose()
+            .context("Failed to convert rescaling of ExportPreset!")?;
+
+        ExportPreset::new(attribute_id, (&self.format).into(), rescaling, self.path)
+    }
+
+    pub fn convert(preset: &ExportPreset, attributes: &[String]) -> ExportPresetSerde {
+        ExportPresetSerde {
+            attribute: attributes[preset.attribute_id()].clone(),
+            format: preset.format().into(),
+            rescaling: preset.rescaling().map(|rescaling| rescaling.into()),
+            path: preset.path().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omg_core::export::format::ExportFormat;
+    use omg_core::export::rescaling::Rescaling;
+
+    #[test]
+    fn test_conversion() {
+        let attributes = vec!["a".to_string(), "b".to_string()];
+        let rescaling = Rescaling::new(0, 200, 0.0, 1.0).unwrap();
+        let start = ExportPreset::new(1, ExportFormat::RawR32, Some(rescaling), "b.r32").unwrap();
+        let serde = ExportPresetSerde::convert(&start, &attributes);
+
+        assert_eq!(serde.try_convert(&attributes).unwrap(), start)
+    }
+
+    #[test]
+    fn test_conversion_unknown_attribute() {
+        let attributes = vec!["a".to_string()];
+        let start = ExportPreset::new(0, ExportFormat::Png16, None, "a.png").unwrap();
+        let serde = ExportPresetSerde::convert(&start, &attributes);
+
+        assert!(serde.try_convert(&["b".to_string()]).is_err())
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use omg_core::export::rescaling::Rescaling;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct RescalingSerde {
+    input_min: u8,
+    input_max: u8,
+    output_min: f32,
+    output_max: f32,
+}
+
+impl RescalingSerde {
+    pub fn try_convert(&self) -> Result<Rescaling> {
+        Rescaling::new(
+            self.input_min,
+            self.input_max,
+            self.output_min,
+            self.output_max,
+        )
+    }
+}
+
+impl From<&Rescaling> for RescalingSerde {
+    fn from(rescaling: &Rescaling) -> Self {
+        RescalingSerde {
+            input_min: rescaling.input_min(),
+            input_max: rescaling.input_max(),
+            output_min: rescaling.output_min(),
+            output_max: rescaling.output_max(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        let start = Rescaling::new(76, 255, -10.0, 8000.0).unwrap();
+        let serde: RescalingSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
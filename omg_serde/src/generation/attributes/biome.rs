@@ -0,0 +1,125 @@
+use crate::data::math::transformer::transformer_nd::RuleConditionSerde;
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::biome::{Biome, ClassifyBiomesStep};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BiomeSerde {
+    name: String,
+    value: u8,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    conditions: Vec<RuleConditionSerde>,
+}
+
+impl BiomeSerde {
+    fn try_convert(self, attributes: &[String]) -> Result<Biome> {
+        let conditions = self
+            .conditions
+            .into_iter()
+            .map(|condition| condition.try_convert(attributes))
+            .collect::<Result<Vec<_>>>()
+            .with_context(|| format!("Failed to convert conditions of biome '{}'!", self.name))?;
+
+        Biome::new(self.name, self.value, conditions)
+    }
+
+    fn convert(biome: &Biome, attributes: &[String]) -> BiomeSerde {
+        BiomeSerde {
+            name: biome.name().to_string(),
+            value: biome.value(),
+            conditions: biome
+                .conditions()
+                .iter()
+                .map(|condition| RuleConditionSerde::convert(condition, attributes))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClassifyBiomesStepSerde {
+    target: String,
+    biomes: Vec<BiomeSerde>,
+}
+
+impl ToStep<ClassifyBiomesStep> for ClassifyBiomesStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<ClassifyBiomesStep> {
+        let target_id = get_attribute_id(&self.target, attributes)
+            .context("Failed to convert target of ClassifyBiomesStep!")?;
+        let biomes = self
+            .biomes
+            .into_iter()
+            .map(|biome| biome.try_convert(attributes))
+            .collect::<Result<Vec<_>>>()
+            .context("Failed to convert biomes of ClassifyBiomesStep!")?;
+
+        ClassifyBiomesStep::new(target_id, biomes)
+            .context("Failed to convert to ClassifyBiomesStep!")
+    }
+}
+
+impl FromStep<ClassifyBiomesStepSerde> for ClassifyBiomesStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> ClassifyBiomesStepSerde {
+        ClassifyBiomesStepSerde {
+            target: attributes[self.target_id()].clone(),
+            biomes: self
+                .biomes()
+                .iter()
+                .map(|biome| BiomeSerde::convert(biome, attributes))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::predicate::PredicateSerde;
+    use crate::generation::step::assert_eq;
+    use omg_core::data::math::generator::shape::Shape;
+    use omg_core::data::math::predicate::Predicate;
+    use omg_core::data::math::transformer::transformer_nd::RuleCondition;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec![
+            "elevation".to_string(),
+            "temperature".to_string(),
+            "rainfall".to_string(),
+            "biome".to_string(),
+        ];
+        let ocean = RuleCondition::Value {
+            input: 0,
+            predicate: Predicate::Below(76),
+        };
+        let shape = Shape::new_polygon(vec![[0, 0], [100, 0], [0, 100]]).unwrap();
+        let tundra = RuleCondition::InShape { x: 1, y: 2, shape };
+        let biomes = vec![
+            Biome::new("ocean", 12, vec![ocean]).unwrap(),
+            Biome::new("tundra", 3, vec![tundra]).unwrap(),
+            Biome::new("grassland", 11, Vec::new()).unwrap(),
+        ];
+        let step = ClassifyBiomesStep::new(3, biomes).unwrap();
+
+        assert_eq(step, &mut attributes);
+    }
+
+    #[test]
+    fn test_unknown_attribute_in_condition() {
+        let mut attributes = vec!["biome".to_string()];
+        let serde = ClassifyBiomesStepSerde {
+            target: "biome".to_string(),
+            biomes: vec![BiomeSerde {
+                name: "ocean".to_string(),
+                value: 12,
+                conditions: vec![RuleConditionSerde::Value {
+                    attribute: "elevation".to_string(),
+                    predicate: PredicateSerde::Below(76),
+                }],
+            }],
+        };
+
+        assert!(serde.try_convert(&mut attributes).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::data::components::ConnectivitySerde;
+use crate::data::math::predicate::PredicateSerde;
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::components::LabelComponentsStep;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LabelComponentsStepSerde {
+    source: String,
+    target: String,
+    predicate: PredicateSerde,
+    connectivity: ConnectivitySerde,
+    #[serde(default)]
+    min_area: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fill: Option<u8>,
+}
+
+impl ToStep<LabelComponentsStep> for LabelComponentsStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<LabelComponentsStep> {
+        let source_id = get_attribute_id(&self.source, attributes)
+            .context("Failed to convert source of LabelComponentsStep!")?;
+        let target_id = get_attribute_id(&self.target, attributes)
+            .context("Failed to convert target of LabelComponentsStep!")?;
+        let predicate = self
+            .predicate
+            .try_convert()
+            .context("Failed to convert predicate of LabelComponentsStep!")?;
+
+        Ok(LabelComponentsStep::new(
+            source_id,
+            target_id,
+            predicate,
+            (&self.connectivity).into(),
+            self.min_area,
+        )
+        .with_fill(self.fill))
+    }
+}
+
+impl FromStep<LabelComponentsStepSerde> for LabelComponentsStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> LabelComponentsStepSerde {
+        LabelComponentsStepSerde {
+            source: attributes[self.source_id()].clone(),
+            target: attributes[self.target_id()].clone(),
+            predicate: self.predicate().into(),
+            connectivity: (&self.connectivity()).into(),
+            min_area: self.min_area(),
+            fill: self.fill(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+    use omg_core::data::map::components::Connectivity::*;
+    use omg_core::data::math::predicate::Predicate;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["source".to_string(), "target".to_string()];
+        let predicate = Predicate::new_in_range(100, 200).unwrap();
+        let step = LabelComponentsStep::new(0, 1, predicate, Eight, 20).with_fill(Some(50));
+
+        assert_eq(step, &mut attributes);
+    }
+
+    #[test]
+    fn test_conversion_without_fill() {
+        let mut attributes = vec!["source".to_string(), "target".to_string()];
+        let step = LabelComponentsStep::new(0, 1, Predicate::Below(10), Four, 0);
+
+        assert_eq(step, &mut attributes);
+    }
+}
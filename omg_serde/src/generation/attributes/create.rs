@@ -37,7 +37,7 @@ mod tests {
     fn test_conversion() {
         let mut attributes = Vec::new();
         let step = CreateAttributeStep::new("create", 66).unwrap();
-        let serde: CreateAttributeStepSerde = (&step).convert(&mut attributes);
+        let serde: CreateAttributeStepSerde = step.convert(&mut attributes);
 
         assert_eq!(attributes, vec!["create".to_string()]);
         attributes.clear();
@@ -0,0 +1,86 @@
+use crate::data::math::distance_transform::DistanceMetricSerde;
+use crate::data::math::predicate::PredicateSerde;
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::data::math::distance_transform::DistanceMetric;
+use omg_core::generation::attributes::distance::DistanceTransformStep;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DistanceTransformStepSerde {
+    source: String,
+    target: String,
+    predicate: PredicateSerde,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metric: Option<DistanceMetricSerde>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scale: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_value: Option<u8>,
+}
+
+impl ToStep<DistanceTransformStep> for DistanceTransformStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<DistanceTransformStep> {
+        let source_id = get_attribute_id(&self.source, attributes)
+            .context("Failed to convert source of DistanceTransformStep!")?;
+        let target_id = get_attribute_id(&self.target, attributes)
+            .context("Failed to convert target of DistanceTransformStep!")?;
+        let predicate = self
+            .predicate
+            .try_convert()
+            .context("Failed to convert predicate of DistanceTransformStep!")?;
+        let metric = self
+            .metric
+            .map(|m| (&m).into())
+            .unwrap_or(DistanceMetric::Euclidean);
+
+        DistanceTransformStep::new(
+            source_id,
+            target_id,
+            predicate,
+            metric,
+            self.scale.unwrap_or(1.0),
+            self.max_value.unwrap_or(255),
+        )
+        .context("Failed to convert to DistanceTransformStep!")
+    }
+}
+
+impl FromStep<DistanceTransformStepSerde> for DistanceTransformStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> DistanceTransformStepSerde {
+        DistanceTransformStepSerde {
+            source: attributes[self.source_id()].clone(),
+            target: attributes[self.target_id()].clone(),
+            predicate: self.predicate().into(),
+            metric: Some(self.metric())
+                .filter(|m| *m != DistanceMetric::Euclidean)
+                .map(|m| (&m).into()),
+            scale: Some(self.scale()).filter(|s| *s != 1.0),
+            max_value: Some(self.max_value()).filter(|m| *m != 255),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+    use omg_core::data::math::distance_transform::DistanceMetric::*;
+    use omg_core::data::math::predicate::Predicate::Below;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["source".to_string(), "target".to_string()];
+        let step = DistanceTransformStep::new(0, 1, Below(50), Chamfer, 2.5, 100).unwrap();
+
+        assert_eq(step, &mut attributes);
+    }
+
+    #[test]
+    fn test_conversion_with_defaults() {
+        let mut attributes = vec!["source".to_string(), "target".to_string()];
+        let step = DistanceTransformStep::new(1, 0, Below(50), Euclidean, 1.0, 255).unwrap();
+
+        assert_eq(step, &mut attributes);
+    }
+}
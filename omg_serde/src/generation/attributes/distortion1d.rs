@@ -1,6 +1,8 @@
+use crate::data::math::edge::EdgeModeSerde;
 use crate::data::math::generator::generator1d::Generator1dSerde;
 use crate::generation::step::{get_attribute_id, FromStep, ToStep};
 use anyhow::{Context, Result};
+use omg_core::data::math::edge::EdgeMode;
 use omg_core::generation::attributes::distortion1d::Distortion1dStep;
 use serde::{Deserialize, Serialize};
 
@@ -8,13 +10,26 @@ use serde::{Deserialize, Serialize};
 pub struct Distortion1dStepSerde {
     attribute: String,
     generator: Generator1dSerde,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    center: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    edge_mode: Option<EdgeModeSerde>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bilinear: Option<bool>,
 }
 
 impl Distortion1dStepSerde {
     fn inner_convert(self, attributes: &[String]) -> Result<Distortion1dStep> {
         let id = get_attribute_id(&self.attribute, attributes)?;
         let generator = self.generator.try_convert()?;
-        Ok(Distortion1dStep::new(id, generator))
+        let edge_mode = self
+            .edge_mode
+            .map(|m| (&m).into())
+            .unwrap_or(EdgeMode::Clamp);
+        Ok(Distortion1dStep::new(id, generator)
+            .with_center(self.center.unwrap_or(0))
+            .with_edge_mode(edge_mode)
+            .with_bilinear(self.bilinear.unwrap_or(false)))
     }
 }
 
@@ -31,6 +46,11 @@ impl FromStep<Distortion1dStepSerde> for Distortion1dStep {
         Distortion1dStepSerde {
             attribute,
             generator: self.generator().into(),
+            center: Some(self.center()).filter(|c| *c != 0),
+            edge_mode: Some(self.edge_mode())
+                .filter(|m| *m != EdgeMode::Clamp)
+                .map(|m| (&m).into()),
+            bilinear: Some(self.bilinear()).filter(|b| *b),
         }
     }
 }
@@ -48,4 +68,15 @@ mod tests {
 
         assert_eq(step, &mut attributes);
     }
+
+    #[test]
+    fn test_conversion_with_options() {
+        let mut attributes = vec!["test".to_string()];
+        let step = Distortion1dStep::new(0, Generator1d::InputAsOutput)
+            .with_center(128)
+            .with_edge_mode(EdgeMode::Mirror)
+            .with_bilinear(true);
+
+        assert_eq(step, &mut attributes);
+    }
 }
@@ -1,6 +1,8 @@
+use crate::data::math::edge::EdgeModeSerde;
 use crate::data::math::generator::generator2d::Generator2dSerde;
 use crate::generation::step::{get_attribute_id, FromStep, ToStep};
 use anyhow::{Context, Result};
+use omg_core::data::math::edge::EdgeMode;
 use omg_core::generation::attributes::distortion2d::Distortion2dStep;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +11,12 @@ pub struct Distortion2dStepSerde {
     attribute: String,
     generator_x: Generator2dSerde,
     generator_y: Generator2dSerde,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    center: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    edge_mode: Option<EdgeModeSerde>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bilinear: Option<bool>,
 }
 
 impl ToStep<Distortion2dStep> for Distortion2dStepSerde {
@@ -24,7 +32,15 @@ impl ToStep<Distortion2dStep> for Distortion2dStepSerde {
             .try_convert()
             .context("Failed to convert generator_y of Distortion2dStep!")?;
 
-        Ok(Distortion2dStep::new(id, generator_x, generator_y))
+        let edge_mode = self
+            .edge_mode
+            .map(|m| (&m).into())
+            .unwrap_or(EdgeMode::Clamp);
+
+        Ok(Distortion2dStep::new(id, generator_x, generator_y)
+            .with_center(self.center.unwrap_or(0))
+            .with_edge_mode(edge_mode)
+            .with_bilinear(self.bilinear.unwrap_or(false)))
     }
 }
 
@@ -35,6 +51,11 @@ impl FromStep<Distortion2dStepSerde> for Distortion2dStep {
             attribute,
             generator_x: self.generator_x().into(),
             generator_y: self.generator_y().into(),
+            center: Some(self.center()).filter(|c| *c != 0),
+            edge_mode: Some(self.edge_mode())
+                .filter(|m| *m != EdgeMode::Clamp)
+                .map(|m| (&m).into()),
+            bilinear: Some(self.bilinear()).filter(|b| *b),
         }
     }
 }
@@ -55,4 +76,17 @@ mod tests {
 
         assert_eq(step, &mut attributes);
     }
+
+    #[test]
+    fn test_conversion_with_options() {
+        let mut attributes = vec!["test".to_string()];
+        let generator_x = IndexGenerator(Size2d::unchecked(1, 2));
+        let generator_y = IndexGenerator(Size2d::unchecked(3, 4));
+        let step = Distortion2dStep::new(0, generator_x, generator_y)
+            .with_center(128)
+            .with_edge_mode(EdgeMode::Wrap)
+            .with_bilinear(true);
+
+        assert_eq(step, &mut attributes);
+    }
 }
@@ -0,0 +1,75 @@
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::expression::ExpressionStep;
+use serde::{Deserialize, Serialize};
+
+/// The factors of the coordinates default to 1 & are only set by resizing.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExpressionStepSerde {
+    target: String,
+    formula: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    factor_x: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    factor_y: Option<f32>,
+}
+
+impl ToStep<ExpressionStep> for ExpressionStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<ExpressionStep> {
+        let target_id = get_attribute_id(&self.target, attributes)
+            .context("Failed to convert target of ExpressionStep!")?;
+
+        let step = ExpressionStep::new(self.formula, target_id, attributes)
+            .context("Failed to convert to ExpressionStep!")?;
+
+        Ok(step.with_factors(self.factor_x.unwrap_or(1.0), self.factor_y.unwrap_or(1.0)))
+    }
+}
+
+impl FromStep<ExpressionStepSerde> for ExpressionStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> ExpressionStepSerde {
+        ExpressionStepSerde {
+            target: attributes[self.target_id()].clone(),
+            formula: self.formula().to_string(),
+            factor_x: Some(self.factor_x()).filter(|f| *f != 1.0),
+            factor_y: Some(self.factor_y()).filter(|f| *f != 1.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["elevation".to_string(), "rainfall".to_string()];
+        let formula = "clamp(elevation * 0.8 + noise(x, y, seed=3) * 40 - rainfall / 4, 0, 255)";
+        let step = ExpressionStep::new(formula, 0, &attributes).unwrap();
+
+        assert_eq(step.clone(), &mut attributes);
+        assert_eq(step.resize(2.0, 0.5), &mut attributes);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let mut attributes = vec!["elevation".to_string()];
+        let serde = ExpressionStepSerde {
+            target: "elevation".to_string(),
+            formula: "elevation * rainfall".to_string(),
+            factor_x: None,
+            factor_y: None,
+        };
+        let error = serde.try_convert(&mut attributes).unwrap_err();
+        let messages: Vec<String> = error.chain().map(|e| e.to_string()).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Failed to convert to ExpressionStep!",
+                "Unknown attribute 'rainfall' at position 12 of formula 'elevation * rainfall'!",
+            ]
+        );
+    }
+}
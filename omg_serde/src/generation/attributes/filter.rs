@@ -0,0 +1,52 @@
+use crate::data::math::filter::FilterSerde;
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::filter::FilterStep;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FilterStepSerde {
+    source: String,
+    target: String,
+    filter: FilterSerde,
+}
+
+impl ToStep<FilterStep> for FilterStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<FilterStep> {
+        let source_id = get_attribute_id(&self.source, attributes)
+            .context("Failed to convert source of FilterStep!")?;
+        let target_id = get_attribute_id(&self.target, attributes)
+            .context("Failed to convert target of FilterStep!")?;
+        let filter = self
+            .filter
+            .try_convert()
+            .context("Failed to convert filter of FilterStep!")?;
+
+        Ok(FilterStep::new(source_id, target_id, filter))
+    }
+}
+
+impl FromStep<FilterStepSerde> for FilterStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> FilterStepSerde {
+        FilterStepSerde {
+            source: attributes[self.source_id()].clone(),
+            target: attributes[self.target_id()].clone(),
+            filter: self.filter().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+    use omg_core::data::math::filter::Filter;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["source".to_string(), "target".to_string()];
+        let step = FilterStep::new(0, 1, Filter::GaussianBlur(3));
+
+        assert_eq(step, &mut attributes);
+    }
+}
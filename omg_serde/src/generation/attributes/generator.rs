@@ -1,7 +1,8 @@
+use crate::data::math::blend::BlendModeSerde;
 use crate::data::math::generator::generator2d::Generator2dSerde;
 use crate::generation::step::{get_attribute_id, FromStep, ToStep};
 use anyhow::{Context, Result};
-use omg_core::generation::attributes::generator::GeneratorStep;
+use omg_core::generation::attributes::generator::{ApplyGeneratorStep, GeneratorStep};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,10 +38,55 @@ impl FromStep<GeneratorStepSerde> for GeneratorStep {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApplyGeneratorStepSerde {
+    name: String,
+    attribute: String,
+    generator: Generator2dSerde,
+    mode: BlendModeSerde,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weight: Option<f32>,
+}
+
+impl ApplyGeneratorStepSerde {
+    fn inner_convert(self, attributes: &[String]) -> Result<ApplyGeneratorStep> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        let generator = self.generator.try_convert()?;
+        ApplyGeneratorStep::new(
+            self.name,
+            id,
+            generator,
+            (&self.mode).into(),
+            self.weight.unwrap_or(1.0),
+        )
+    }
+}
+
+impl ToStep<ApplyGeneratorStep> for ApplyGeneratorStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<ApplyGeneratorStep> {
+        self.inner_convert(attributes)
+            .context("Failed to convert to ApplyGeneratorStep!")
+    }
+}
+
+impl FromStep<ApplyGeneratorStepSerde> for ApplyGeneratorStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> ApplyGeneratorStepSerde {
+        let attribute = attributes[self.attribute_id()].clone();
+        ApplyGeneratorStepSerde {
+            name: self.name().to_string(),
+            attribute,
+            generator: self.generator().into(),
+            mode: (&self.mode()).into(),
+            weight: Some(self.weight()).filter(|w| *w != 1.0),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::generation::step::assert_eq;
+    use omg_core::data::math::blend::BlendMode;
     use omg_core::data::math::generator::generator2d::Generator2d;
     use omg_core::data::math::size2d::Size2d;
 
@@ -52,4 +98,18 @@ mod tests {
 
         assert_eq(step, &mut attributes);
     }
+
+    #[test]
+    fn test_apply_conversion() {
+        let mut attributes = vec!["test".to_string()];
+        let generator = Generator2d::IndexGenerator(Size2d::unchecked(1, 2));
+
+        for weight in [0.3, 1.0] {
+            let step =
+                ApplyGeneratorStep::new("step", 0, generator.clone(), BlendMode::Screen, weight)
+                    .unwrap();
+
+            assert_eq(step, &mut attributes);
+        }
+    }
 }
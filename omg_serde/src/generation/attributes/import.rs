@@ -0,0 +1,125 @@
+use crate::data::math::resampling::ResamplingFilterSerde;
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use crate::import::format::ImportFormatSerde;
+use crate::import::range::ValueRangeSerde;
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::import::ImportAttributeStep;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportAttributeStepSerde {
+    attribute: String,
+    path: String,
+    format: ImportFormatSerde,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    range: Option<ValueRangeSerde>,
+    filter: ResamplingFilterSerde,
+}
+
+impl ImportAttributeStepSerde {
+    fn inner_convert(self, attribute_id: usize) -> Result<ImportAttributeStep> {
+        let format = self.format.try_convert()?;
+        let range = self.range.map(|r| r.try_convert()).transpose()?;
+        ImportAttributeStep::new(
+            self.attribute,
+            attribute_id,
+            self.path,
+            format,
+            range,
+            (&self.filter).into(),
+        )
+    }
+}
+
+impl ToStep<ImportAttributeStep> for ImportAttributeStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<ImportAttributeStep> {
+        let attribute_id = get_or_add_attribute_id(&self.attribute, attributes);
+
+        self.inner_convert(attribute_id)
+            .context("Failed to convert to ImportAttributeStep!")
+    }
+}
+
+impl FromStep<ImportAttributeStepSerde> for ImportAttributeStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> ImportAttributeStepSerde {
+        let attribute = attributes
+            .get(self.attribute_id())
+            .cloned()
+            .unwrap_or_else(|| self.attribute().to_string());
+
+        get_or_add_attribute_id(&attribute, attributes);
+
+        ImportAttributeStepSerde {
+            attribute,
+            path: self.path().to_string(),
+            format: self.format().into(),
+            range: self.range().map(|r| r.into()),
+            filter: (&self.filter()).into(),
+        }
+    }
+}
+
+fn get_or_add_attribute_id(attribute: &str, attributes: &mut Vec<String>) -> usize {
+    get_attribute_id(attribute, attributes).unwrap_or_else(|_| {
+        attributes.push(attribute.to_string());
+        attributes.len() - 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omg_core::data::math::resampling::ResamplingFilter::Bilinear;
+    use omg_core::import::format::ImportFormat::Csv;
+    use omg_core::import::range::ValueRange;
+    use std::fs;
+
+    #[test]
+    fn test_conversion() {
+        let path = std::env::temp_dir().join("omg_serde_import_step.csv");
+        let path = path.to_str().unwrap();
+        fs::write(path, "0, 100").unwrap();
+        let range = Some(ValueRange::new(0.0, 100.0).unwrap());
+        let step = ImportAttributeStep::new("elevation", 0, path, Csv, range, Bilinear).unwrap();
+
+        let mut attributes = vec!["elevation".to_string()];
+        let serde: ImportAttributeStepSerde = step.convert(&mut attributes);
+        assert_eq!(attributes, vec!["elevation".to_string()]);
+
+        let mut attributes = Vec::new();
+        assert_eq!(serde.try_convert(&mut attributes).unwrap(), step);
+        assert_eq!(attributes, vec!["elevation".to_string()]);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_csv() {
+        let path = std::env::temp_dir().join("omg_serde_import_step_invalid.csv");
+        let path = path.to_str().unwrap();
+        fs::write(path, "0, a").unwrap();
+        let serde = ImportAttributeStepSerde {
+            attribute: "elevation".to_string(),
+            path: path.to_string(),
+            format: ImportFormatSerde::Csv,
+            range: None,
+            filter: ResamplingFilterSerde::Nearest,
+        };
+
+        assert!(serde.try_convert(&mut Vec::new()).is_err());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_unknown_file() {
+        let serde = ImportAttributeStepSerde {
+            attribute: "elevation".to_string(),
+            path: "unknown.png".to_string(),
+            format: ImportFormatSerde::Png,
+            range: None,
+            filter: ResamplingFilterSerde::Nearest,
+        };
+
+        assert!(serde.try_convert(&mut Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::data::math::predicate::PredicateSerde;
+use crate::generation::step::{get_attribute_id, FromStep, GenerationStepSerde, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::mask::MaskedStep;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MaskedStepSerde {
+    mask: String,
+    predicate: PredicateSerde,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    soft: Option<bool>,
+    step: Box<GenerationStepSerde>,
+}
+
+impl ToStep<MaskedStep> for MaskedStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<MaskedStep> {
+        let mask_id = get_attribute_id(&self.mask, attributes)
+            .context("Failed to convert mask of MaskedStep!")?;
+        let predicate = self
+            .predicate
+            .try_convert()
+            .context("Failed to convert predicate of MaskedStep!")?;
+        let step = self
+            .step
+            .try_convert(attributes)
+            .context("Failed to convert step of MaskedStep!")?;
+
+        Ok(MaskedStep::new(mask_id, predicate, step).with_soft(self.soft.unwrap_or(false)))
+    }
+}
+
+impl FromStep<MaskedStepSerde> for MaskedStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> MaskedStepSerde {
+        MaskedStepSerde {
+            mask: attributes[self.mask_id()].clone(),
+            predicate: self.predicate().into(),
+            soft: Some(self.soft()).filter(|s| *s),
+            step: Box::new(self.step().convert(attributes)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+    use omg_core::data::math::generator::generator2d::Generator2d;
+    use omg_core::data::math::generator::noise::Noise;
+    use omg_core::data::math::predicate::Predicate;
+    use omg_core::generation::attributes::generator::GeneratorStep;
+    use omg_core::generation::step::GenerationStep;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["elevation".to_string()];
+        let noise = Generator2d::Noise2d(Noise::new(1, 20, 0, 60).unwrap());
+        let add = GeneratorStep::new("mountains", 0, noise).unwrap();
+        let step = MaskedStep::new(0, Predicate::Above(76), GenerationStep::GeneratorAdd(add));
+
+        assert_eq(step.clone(), &mut attributes);
+        assert_eq(step.with_soft(true), &mut attributes);
+    }
+}
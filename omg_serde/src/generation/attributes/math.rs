@@ -0,0 +1,190 @@
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::math::{AttributeMathStep, MathOperation, Overflow};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum MathOperationSerde {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Min,
+    Max,
+    Difference,
+    WeightedAverage(Vec<f32>),
+}
+
+type S = MathOperationSerde;
+type R = MathOperation;
+
+impl MathOperationSerde {
+    fn try_convert(self) -> Result<MathOperation> {
+        match self {
+            S::Add => Ok(R::Add),
+            S::Sub => Ok(R::Sub),
+            S::Mul => Ok(R::Mul),
+            S::Div => Ok(R::Div),
+            S::Min => Ok(R::Min),
+            S::Max => Ok(R::Max),
+            S::Difference => Ok(R::Difference),
+            S::WeightedAverage(weights) => R::new_weighted_average(weights),
+        }
+    }
+}
+
+impl From<&MathOperation> for MathOperationSerde {
+    fn from(operation: &MathOperation) -> Self {
+        match operation {
+            R::Add => S::Add,
+            R::Sub => S::Sub,
+            R::Mul => S::Mul,
+            R::Div => S::Div,
+            R::Min => S::Min,
+            R::Max => S::Max,
+            R::Difference => S::Difference,
+            R::WeightedAverage(weights) => S::WeightedAverage(weights.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum OverflowSerde {
+    Saturate,
+    Wrap,
+}
+
+impl From<OverflowSerde> for Overflow {
+    fn from(overflow: OverflowSerde) -> Self {
+        match overflow {
+            OverflowSerde::Saturate => Overflow::Saturate,
+            OverflowSerde::Wrap => Overflow::Wrap,
+        }
+    }
+}
+
+impl From<Overflow> for OverflowSerde {
+    fn from(overflow: Overflow) -> Self {
+        match overflow {
+            Overflow::Saturate => OverflowSerde::Saturate,
+            Overflow::Wrap => OverflowSerde::Wrap,
+        }
+    }
+}
+
+/// The target defaults to the first source & the overflow to saturation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttributeMathStepSerde {
+    sources: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    operation: MathOperationSerde,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    overflow: Option<OverflowSerde>,
+}
+
+impl ToStep<AttributeMathStep> for AttributeMathStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<AttributeMathStep> {
+        let source_ids = self
+            .sources
+            .iter()
+            .map(|source| get_attribute_id(source, attributes))
+            .collect::<Result<Vec<_>>>()
+            .context("Failed to convert sources of AttributeMathStep!")?;
+        let target = match self.target {
+            Some(target) => target,
+            None => self
+                .sources
+                .first()
+                .cloned()
+                .context("Failed to convert sources of AttributeMathStep!")?,
+        };
+        let operation = self
+            .operation
+            .try_convert()
+            .context("Failed to convert operation of AttributeMathStep!")?;
+        let overflow = self.overflow.unwrap_or(OverflowSerde::Saturate).into();
+
+        if !attributes.contains(&target) {
+            attributes.push(target.clone());
+        }
+
+        AttributeMathStep::new(source_ids, target, operation, overflow)
+            .context("Failed to convert AttributeMathStep!")
+    }
+}
+
+impl FromStep<AttributeMathStepSerde> for AttributeMathStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> AttributeMathStepSerde {
+        let target = self.target().to_string();
+        let in_place = self
+            .source_ids()
+            .first()
+            .map(|id| attributes[*id] == target)
+            .unwrap_or(false);
+
+        if !attributes.contains(&target) {
+            attributes.push(target.clone());
+        }
+
+        AttributeMathStepSerde {
+            sources: self
+                .source_ids()
+                .iter()
+                .map(|id| attributes[*id].clone())
+                .collect(),
+            target: Some(target).filter(|_| !in_place),
+            operation: self.operation().into(),
+            overflow: Some(self.overflow().into())
+                .filter(|o| !matches!(o, OverflowSerde::Saturate)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        for (operation, target, overflow) in [
+            (MathOperation::Add, "a", Overflow::Saturate),
+            (MathOperation::Difference, "c", Overflow::Wrap),
+            (
+                MathOperation::new_weighted_average(vec![1.0, 2.0]).unwrap(),
+                "b",
+                Overflow::Saturate,
+            ),
+        ] {
+            let step = AttributeMathStep::new(vec![0, 1], target, operation, overflow).unwrap();
+
+            assert_eq(step, &mut attributes);
+        }
+    }
+
+    #[test]
+    fn test_new_target() {
+        let mut attributes = vec!["a".to_string(), "b".to_string()];
+        let step =
+            AttributeMathStep::new(vec![0, 1], "sum", MathOperation::Add, Overflow::Wrap).unwrap();
+
+        assert_eq(step, &mut attributes);
+        assert_eq!(attributes, vec!["a", "b", "sum"]);
+    }
+
+    #[test]
+    fn test_weights_must_match_sources() {
+        let mut attributes = vec!["a".to_string(), "b".to_string()];
+        let serde = AttributeMathStepSerde {
+            sources: attributes.clone(),
+            target: None,
+            operation: MathOperationSerde::WeightedAverage(vec![1.0]),
+            overflow: None,
+        };
+
+        assert!(serde.try_convert(&mut attributes).is_err());
+    }
+}
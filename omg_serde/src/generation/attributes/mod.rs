@@ -1,6 +1,19 @@
+pub mod biome;
+pub mod components;
 pub mod create;
+pub mod distance;
 pub mod distortion1d;
 pub mod distortion2d;
+pub mod expression;
+pub mod filter;
 pub mod generator;
+pub mod import;
+pub mod mask;
+pub mod math;
 pub mod modify;
+pub mod normalise;
+pub mod rainfall;
+pub mod tectonics;
+pub mod temperature;
+pub mod terrain;
 pub mod transformer;
@@ -0,0 +1,90 @@
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::normalise::{Normalisation, NormaliseStep};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum NormalisationSerde {
+    ToPercentile { percentage: f32, threshold: u8 },
+    Equalise,
+    RemapToRange { min: u8, max: u8 },
+}
+
+type S = NormalisationSerde;
+type R = Normalisation;
+
+impl NormalisationSerde {
+    fn try_convert(self) -> Result<Normalisation> {
+        match self {
+            S::ToPercentile {
+                percentage,
+                threshold,
+            } => R::new_to_percentile(percentage, threshold),
+            S::Equalise => Ok(R::Equalise),
+            S::RemapToRange { min, max } => R::new_remap_to_range(min, max),
+        }
+    }
+}
+
+impl From<Normalisation> for NormalisationSerde {
+    fn from(normalisation: Normalisation) -> Self {
+        match normalisation {
+            R::ToPercentile {
+                percentage,
+                threshold,
+            } => S::ToPercentile {
+                percentage,
+                threshold,
+            },
+            R::Equalise => S::Equalise,
+            R::RemapToRange { min, max } => S::RemapToRange { min, max },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NormaliseStepSerde {
+    attribute: String,
+    normalisation: NormalisationSerde,
+}
+
+impl ToStep<NormaliseStep> for NormaliseStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<NormaliseStep> {
+        let attribute_id = get_attribute_id(&self.attribute, attributes)
+            .context("Failed to convert attribute of NormaliseStep!")?;
+        let normalisation = self
+            .normalisation
+            .try_convert()
+            .context("Failed to convert normalisation of NormaliseStep!")?;
+
+        Ok(NormaliseStep::new(attribute_id, normalisation))
+    }
+}
+
+impl FromStep<NormaliseStepSerde> for NormaliseStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> NormaliseStepSerde {
+        NormaliseStepSerde {
+            attribute: attributes[self.attribute_id()].clone(),
+            normalisation: self.normalisation().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["elevation".to_string()];
+
+        for normalisation in [
+            Normalisation::new_to_percentile(70.0, 76).unwrap(),
+            Normalisation::Equalise,
+            Normalisation::new_remap_to_range(10, 200).unwrap(),
+        ] {
+            assert_eq(NormaliseStep::new(0, normalisation), &mut attributes);
+        }
+    }
+}
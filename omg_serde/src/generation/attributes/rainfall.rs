@@ -0,0 +1,121 @@
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::rainfall::{RainfallSimulationStep, Wind};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum WindSerde {
+    Constant(f32),
+    Attribute(String),
+}
+
+impl WindSerde {
+    fn try_convert(self, attributes: &[String]) -> Result<Wind> {
+        match self {
+            WindSerde::Constant(degrees) => Ok(Wind::Constant(degrees)),
+            WindSerde::Attribute(attribute) => {
+                Ok(Wind::Attribute(get_attribute_id(&attribute, attributes)?))
+            }
+        }
+    }
+
+    fn convert(wind: Wind, attributes: &[String]) -> WindSerde {
+        match wind {
+            Wind::Constant(degrees) => WindSerde::Constant(degrees),
+            Wind::Attribute(id) => WindSerde::Attribute(attributes[id].clone()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RainfallSimulationStepSerde {
+    elevation: String,
+    rainfall: String,
+    wind: WindSerde,
+    sea_level: u8,
+    loss: f32,
+    orographic: f32,
+    max_distance: u32,
+}
+
+impl ToStep<RainfallSimulationStep> for RainfallSimulationStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<RainfallSimulationStep> {
+        let elevation_id = get_attribute_id(&self.elevation, attributes)
+            .context("Failed to convert elevation of RainfallSimulationStep!")?;
+        let rainfall_id = get_attribute_id(&self.rainfall, attributes)
+            .context("Failed to convert rainfall of RainfallSimulationStep!")?;
+        let wind = self
+            .wind
+            .try_convert(attributes)
+            .context("Failed to convert wind of RainfallSimulationStep!")?;
+
+        RainfallSimulationStep::new(
+            elevation_id,
+            rainfall_id,
+            wind,
+            self.sea_level,
+            self.loss,
+            self.orographic,
+            self.max_distance,
+        )
+        .context("Failed to convert to RainfallSimulationStep!")
+    }
+}
+
+impl FromStep<RainfallSimulationStepSerde> for RainfallSimulationStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> RainfallSimulationStepSerde {
+        RainfallSimulationStepSerde {
+            elevation: attributes[self.elevation_id()].clone(),
+            rainfall: attributes[self.rainfall_id()].clone(),
+            wind: WindSerde::convert(self.wind(), attributes),
+            sea_level: self.sea_level(),
+            loss: self.loss(),
+            orographic: self.orographic(),
+            max_distance: self.max_distance(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+
+    #[test]
+    fn test_conversion_with_constant_wind() {
+        let mut attributes = vec!["elevation".to_string(), "rainfall".to_string()];
+        let step =
+            RainfallSimulationStep::new(0, 1, Wind::Constant(90.0), 76, 0.02, 0.01, 200).unwrap();
+
+        assert_eq(step, &mut attributes);
+    }
+
+    #[test]
+    fn test_conversion_with_wind_attribute() {
+        let mut attributes = vec![
+            "elevation".to_string(),
+            "rainfall".to_string(),
+            "wind".to_string(),
+        ];
+        let step =
+            RainfallSimulationStep::new(0, 1, Wind::Attribute(2), 76, 0.02, 0.01, 200).unwrap();
+
+        assert_eq(step, &mut attributes);
+    }
+
+    #[test]
+    fn test_unknown_wind_attribute() {
+        let mut attributes = vec!["elevation".to_string(), "rainfall".to_string()];
+        let serde = RainfallSimulationStepSerde {
+            elevation: "elevation".to_string(),
+            rainfall: "rainfall".to_string(),
+            wind: WindSerde::Attribute("wind".to_string()),
+            sea_level: 76,
+            loss: 0.02,
+            orographic: 0.01,
+            max_distance: 200,
+        };
+
+        assert!(serde.try_convert(&mut attributes).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::tectonics::{PlateElevation, PlateTectonicsStep};
+use serde::{Deserialize, Serialize};
+
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct PlateElevationSerde {
+    oceanic: u8,
+    continental: u8,
+    mountain: u8,
+    rift: u8,
+}
+
+impl From<PlateElevationSerde> for PlateElevation {
+    fn from(elevation: PlateElevationSerde) -> Self {
+        PlateElevation::new(
+            elevation.oceanic,
+            elevation.continental,
+            elevation.mountain,
+            elevation.rift,
+        )
+    }
+}
+
+impl From<PlateElevation> for PlateElevationSerde {
+    fn from(elevation: PlateElevation) -> Self {
+        PlateElevationSerde::new(
+            elevation.oceanic(),
+            elevation.continental(),
+            elevation.mountain(),
+            elevation.rift(),
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlateTectonicsStepSerde {
+    plate: String,
+    elevation: String,
+    seed: u32,
+    number_of_plates: u32,
+    continental_ratio: f32,
+    plate_elevation: PlateElevationSerde,
+    boundary_width: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wrap_x: Option<bool>,
+}
+
+impl ToStep<PlateTectonicsStep> for PlateTectonicsStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<PlateTectonicsStep> {
+        let plate_id = get_attribute_id(&self.plate, attributes)
+            .context("Failed to convert plate of PlateTectonicsStep!")?;
+        let elevation_id = get_attribute_id(&self.elevation, attributes)
+            .context("Failed to convert elevation of PlateTectonicsStep!")?;
+
+        Ok(PlateTectonicsStep::new(
+            plate_id,
+            elevation_id,
+            self.seed,
+            self.number_of_plates,
+            self.continental_ratio,
+            self.plate_elevation.into(),
+            self.boundary_width,
+        )
+        .context("Failed to convert to PlateTectonicsStep!")?
+        .with_wrap_x(self.wrap_x.unwrap_or(false)))
+    }
+}
+
+impl FromStep<PlateTectonicsStepSerde> for PlateTectonicsStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> PlateTectonicsStepSerde {
+        PlateTectonicsStepSerde {
+            plate: attributes[self.plate_id()].clone(),
+            elevation: attributes[self.elevation_id()].clone(),
+            seed: self.seed(),
+            number_of_plates: self.number_of_plates(),
+            continental_ratio: self.continental_ratio(),
+            plate_elevation: self.elevation().into(),
+            boundary_width: self.boundary_width(),
+            wrap_x: Some(self.wrap_x()).filter(|w| *w),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["plate".to_string(), "elevation".to_string()];
+        let elevation = PlateElevation::new(20, 100, 100, 30);
+        let step = PlateTectonicsStep::new(0, 1, 42, 12, 0.4, elevation, 25).unwrap();
+
+        assert_eq(step.clone(), &mut attributes);
+        assert_eq(step.with_wrap_x(true), &mut attributes);
+    }
+}
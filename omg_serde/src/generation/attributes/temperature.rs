@@ -0,0 +1,77 @@
+use crate::data::math::generator::gradient::GradientSerde;
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::temperature::TemperatureModelStep;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TemperatureModelStepSerde {
+    elevation: String,
+    temperature: String,
+    latitude: GradientSerde,
+    lapse_rate: f32,
+    sea_level: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ocean_moderation: Option<f32>,
+}
+
+impl ToStep<TemperatureModelStep> for TemperatureModelStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<TemperatureModelStep> {
+        let elevation_id = get_attribute_id(&self.elevation, attributes)
+            .context("Failed to convert elevation of TemperatureModelStep!")?;
+        let temperature_id = get_attribute_id(&self.temperature, attributes)
+            .context("Failed to convert temperature of TemperatureModelStep!")?;
+        let latitude = self
+            .latitude
+            .try_convert()
+            .context("Failed to convert latitude of TemperatureModelStep!")?;
+
+        TemperatureModelStep::new(
+            elevation_id,
+            temperature_id,
+            latitude,
+            self.lapse_rate,
+            self.sea_level,
+            self.ocean_moderation.unwrap_or(0.0),
+        )
+        .context("Failed to convert to TemperatureModelStep!")
+    }
+}
+
+impl FromStep<TemperatureModelStepSerde> for TemperatureModelStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> TemperatureModelStepSerde {
+        TemperatureModelStepSerde {
+            elevation: attributes[self.elevation_id()].clone(),
+            temperature: attributes[self.temperature_id()].clone(),
+            latitude: self.latitude().into(),
+            lapse_rate: self.lapse_rate(),
+            sea_level: self.sea_level(),
+            ocean_moderation: Some(self.ocean_moderation()).filter(|m| *m != 0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+    use omg_core::data::math::generator::gradient::Gradient;
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["elevation".to_string(), "temperature".to_string()];
+        let latitude = Gradient::new(150, 150, 255, 50).unwrap();
+        let step = TemperatureModelStep::new(0, 1, latitude, 1.15, 76, 0.3).unwrap();
+
+        assert_eq(step, &mut attributes);
+    }
+
+    #[test]
+    fn test_conversion_without_ocean_moderation() {
+        let mut attributes = vec!["elevation".to_string(), "temperature".to_string()];
+        let latitude = Gradient::new(0, 300, 255, 50).unwrap();
+        let step = TemperatureModelStep::new(0, 1, latitude, 1.0, 76, 0.0).unwrap();
+
+        assert_eq(step, &mut attributes);
+    }
+}
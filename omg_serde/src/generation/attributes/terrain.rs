@@ -0,0 +1,114 @@
+use crate::generation::step::{get_attribute_id, FromStep, ToStep};
+use anyhow::{Context, Result};
+use omg_core::generation::attributes::terrain::{DeriveTerrainStep, TerrainProperty};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum TerrainPropertySerde {
+    Slope,
+    Aspect,
+    ProfileCurvature(f32),
+    PlanCurvature(f32),
+}
+
+type S = TerrainPropertySerde;
+type R = TerrainProperty;
+
+impl From<&TerrainPropertySerde> for TerrainProperty {
+    fn from(property: &TerrainPropertySerde) -> Self {
+        match property {
+            S::Slope => R::Slope,
+            S::Aspect => R::Aspect,
+            S::ProfileCurvature(factor) => R::ProfileCurvature(*factor),
+            S::PlanCurvature(factor) => R::PlanCurvature(*factor),
+        }
+    }
+}
+
+impl From<&TerrainProperty> for TerrainPropertySerde {
+    fn from(property: &TerrainProperty) -> Self {
+        match property {
+            R::Slope => S::Slope,
+            R::Aspect => S::Aspect,
+            R::ProfileCurvature(factor) => S::ProfileCurvature(*factor),
+            R::PlanCurvature(factor) => S::PlanCurvature(*factor),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeriveTerrainStepSerde {
+    attribute: String,
+    elevation: String,
+    property: TerrainPropertySerde,
+    height_scale: f32,
+}
+
+impl ToStep<DeriveTerrainStep> for DeriveTerrainStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<DeriveTerrainStep> {
+        let elevation_id = get_attribute_id(&self.elevation, attributes)
+            .context("Failed to convert elevation of DeriveTerrainStep!")?;
+
+        if !attributes.contains(&self.attribute) {
+            attributes.push(self.attribute.clone());
+        }
+
+        DeriveTerrainStep::new(
+            self.attribute,
+            elevation_id,
+            (&self.property).into(),
+            self.height_scale,
+        )
+        .context("Failed to convert to DeriveTerrainStep!")
+    }
+}
+
+impl FromStep<DeriveTerrainStepSerde> for DeriveTerrainStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> DeriveTerrainStepSerde {
+        let attribute = self.attribute().to_string();
+        let elevation = attributes[self.elevation_id()].clone();
+
+        if !attributes.contains(&attribute) {
+            attributes.push(attribute.clone());
+        }
+
+        DeriveTerrainStepSerde {
+            attribute,
+            elevation,
+            property: (&self.property()).into(),
+            height_scale: self.height_scale(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::step::assert_eq;
+
+    #[test]
+    fn test_convert_property() {
+        assert_eq_property(R::Slope);
+        assert_eq_property(R::Aspect);
+        assert_eq_property(R::ProfileCurvature(10.0));
+        assert_eq_property(R::PlanCurvature(20.0));
+    }
+
+    #[test]
+    fn test_conversion() {
+        let mut attributes = vec!["elevation".to_string()];
+        let step = DeriveTerrainStep::new("slope", 0, R::Slope, 0.5).unwrap();
+
+        assert_eq(step, &mut attributes);
+        assert_eq!(
+            attributes,
+            vec!["elevation".to_string(), "slope".to_string()]
+        );
+    }
+
+    fn assert_eq_property(start: TerrainProperty) {
+        let serde: TerrainPropertySerde = (&start).into();
+
+        assert_eq!(TerrainProperty::from(&serde), start)
+    }
+}
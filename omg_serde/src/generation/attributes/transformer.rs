@@ -1,7 +1,10 @@
 use crate::data::math::transformer::transformer2d::Transformer2dSerde;
+use crate::data::math::transformer::transformer_nd::TransformerNdSerde;
 use crate::generation::step::{get_attribute_id, FromStep, ToStep};
 use anyhow::{Context, Result};
-use omg_core::generation::attributes::transformer::TransformAttribute2dStep;
+use omg_core::generation::attributes::transformer::{
+    TransformAttribute2dStep, TransformAttributeNdStep,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -46,11 +49,59 @@ impl FromStep<TransformAttribute2dStepSerde> for TransformAttribute2dStep {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransformAttributeNdStepSerde {
+    name: String,
+    sources: Vec<String>,
+    target: String,
+    transformer: TransformerNdSerde,
+}
+
+impl ToStep<TransformAttributeNdStep> for TransformAttributeNdStepSerde {
+    fn try_convert(self, attributes: &mut Vec<String>) -> Result<TransformAttributeNdStep> {
+        let source_ids = self
+            .sources
+            .iter()
+            .map(|source| get_attribute_id(source, attributes))
+            .collect::<Result<Vec<_>>>()
+            .context("Failed to convert sources of TransformAttributeNdStep!")?;
+        let target_id = get_attribute_id(&self.target, attributes)
+            .context("Failed to convert target of TransformAttributeNdStep!")?;
+        let transformer = self
+            .transformer
+            .try_convert(&self.sources)
+            .context("Failed to convert transformer of TransformAttributeNdStep!")?;
+
+        TransformAttributeNdStep::new(self.name, source_ids, target_id, transformer)
+            .context("Failed to convert to TransformAttributeNdStep!")
+    }
+}
+
+impl FromStep<TransformAttributeNdStepSerde> for TransformAttributeNdStep {
+    fn convert(&self, attributes: &mut Vec<String>) -> TransformAttributeNdStepSerde {
+        let sources: Vec<String> = self
+            .source_ids()
+            .iter()
+            .map(|id| attributes[*id].clone())
+            .collect();
+        let transformer = TransformerNdSerde::convert(self.transformer(), &sources);
+        TransformAttributeNdStepSerde {
+            name: self.name().to_string(),
+            sources,
+            target: attributes[self.target_id()].clone(),
+            transformer,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::generation::step::assert_eq;
+    use omg_core::data::math::predicate::Predicate::*;
     use omg_core::data::math::transformer::transformer2d::Transformer2d;
+    use omg_core::data::math::transformer::transformer_nd::RuleCondition::Value;
+    use omg_core::data::math::transformer::transformer_nd::{Rule, TransformerNd};
 
     #[test]
     fn test_conversion() {
@@ -60,4 +111,31 @@ mod tests {
 
         assert_eq(step, &mut attributes);
     }
+
+    #[test]
+    fn test_conversion_nd() {
+        let mut attributes = vec![
+            "elevation".to_string(),
+            "temperature".to_string(),
+            "rainfall".to_string(),
+            "biome".to_string(),
+        ];
+        let desert = Rule::new(
+            vec![
+                Value {
+                    input: 0,
+                    predicate: Above(200),
+                },
+                Value {
+                    input: 1,
+                    predicate: Below(50),
+                },
+            ],
+            9,
+        );
+        let transformer = TransformerNd::new_rules(vec![desert], 7).unwrap();
+        let step = TransformAttributeNdStep::new("biomes", vec![1, 2], 3, transformer).unwrap();
+
+        assert_eq(step, &mut attributes);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::data::math::size2d::Size2dSerde;
+use crate::export::preset::ExportPresetSerde;
 use crate::generation::step::{FromStep, GenerationStepSerde, ToStep};
 use anyhow::{Context, Result};
 use omg_core::generation::MapGenerator;
@@ -11,7 +12,13 @@ pub mod step;
 pub struct MapGenerationSerde {
     name: String,
     size: Size2dSerde,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reference_size: Option<Size2dSerde>,
     steps: Vec<GenerationStepSerde>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    exports: Vec<ExportPresetSerde>,
 }
 
 impl MapGenerationSerde {
@@ -28,8 +35,24 @@ impl MapGenerationSerde {
             .collect();
         let steps = steps?;
         let size = self.size.try_convert()?;
+        let reference_size = match self.reference_size {
+            Some(reference_size) => reference_size.try_convert()?,
+            None => size,
+        };
+        let exports: Result<Vec<_>> = self
+            .exports
+            .into_iter()
+            .enumerate()
+            .map(|(index, preset)| {
+                preset
+                    .try_convert(&attributes)
+                    .with_context(|| format!("Failed to convert the {}.export!", index + 1))
+            })
+            .collect();
 
-        MapGenerator::new(self.name, size, steps)
+        Ok(MapGenerator::new(self.name, size, steps)?
+            .with_reference_size(reference_size)
+            .with_exports(exports?))
     }
 }
 
@@ -41,10 +64,20 @@ impl From<&MapGenerator> for MapGenerationSerde {
             .iter()
             .map(|data| data.convert(&mut attributes))
             .collect();
+        let exports = map_generation
+            .exports()
+            .iter()
+            .map(|preset| ExportPresetSerde::convert(preset, &attributes))
+            .collect();
+        let reference_size = Some(map_generation.reference_size())
+            .filter(|size| *size != map_generation.size())
+            .map(|size| size.into());
         MapGenerationSerde {
             name: map_generation.name().to_string(),
             size: map_generation.size().into(),
+            reference_size,
             steps,
+            exports,
         }
     }
 }
@@ -54,6 +87,8 @@ mod tests {
     use super::*;
     use crate::generation::attributes::modify::ModifyWithAttributeStepSerde;
     use omg_core::data::math::size2d::Size2d;
+    use omg_core::export::format::ExportFormat;
+    use omg_core::export::preset::ExportPreset;
     use omg_core::generation::attributes::create::CreateAttributeStep;
     use omg_core::generation::attributes::modify::ModifyWithAttributeStep;
     use omg_core::generation::step::GenerationStep;
@@ -67,7 +102,11 @@ mod tests {
         let modify = ModifyWithAttributeStep::new(0, 1, 100, 10);
         let modify = GenerationStep::ModifyWithAttribute(modify);
         let steps = vec![create0, create1, modify];
-        let generation = MapGenerator::new("map", Size2d::unchecked(4, 5), steps).unwrap();
+        let export = ExportPreset::new(1, ExportFormat::Png16, None, "target.png").unwrap();
+        let generation = MapGenerator::new("map", Size2d::unchecked(4, 5), steps)
+            .unwrap()
+            .with_reference_size(Size2d::unchecked(2, 3))
+            .with_exports(vec![export]);
 
         let serde: MapGenerationSerde = (&generation).into();
 
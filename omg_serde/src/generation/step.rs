@@ -1,9 +1,24 @@
+use crate::generation::attributes::biome::ClassifyBiomesStepSerde;
+use crate::generation::attributes::components::LabelComponentsStepSerde;
 use crate::generation::attributes::create::CreateAttributeStepSerde;
+use crate::generation::attributes::distance::DistanceTransformStepSerde;
 use crate::generation::attributes::distortion1d::Distortion1dStepSerde;
 use crate::generation::attributes::distortion2d::Distortion2dStepSerde;
-use crate::generation::attributes::generator::GeneratorStepSerde;
+use crate::generation::attributes::expression::ExpressionStepSerde;
+use crate::generation::attributes::filter::FilterStepSerde;
+use crate::generation::attributes::generator::{ApplyGeneratorStepSerde, GeneratorStepSerde};
+use crate::generation::attributes::import::ImportAttributeStepSerde;
+use crate::generation::attributes::mask::MaskedStepSerde;
+use crate::generation::attributes::math::AttributeMathStepSerde;
 use crate::generation::attributes::modify::ModifyWithAttributeStepSerde;
-use crate::generation::attributes::transformer::TransformAttribute2dStepSerde;
+use crate::generation::attributes::normalise::NormaliseStepSerde;
+use crate::generation::attributes::rainfall::RainfallSimulationStepSerde;
+use crate::generation::attributes::tectonics::PlateTectonicsStepSerde;
+use crate::generation::attributes::temperature::TemperatureModelStepSerde;
+use crate::generation::attributes::terrain::DeriveTerrainStepSerde;
+use crate::generation::attributes::transformer::{
+    TransformAttribute2dStepSerde, TransformAttributeNdStepSerde,
+};
 use anyhow::{Context, Result};
 use omg_core::generation::step::GenerationStep;
 use serde::{Deserialize, Serialize};
@@ -28,22 +43,37 @@ pub fn assert_eq<R: FromStep<S> + PartialEq + Debug, S: ToStep<R>>(
     step: R,
     attributes: &mut Vec<String>,
 ) {
-    let serde: S = (&step).convert(attributes);
+    let serde: S = step.convert(attributes);
 
     assert_eq!(serde.try_convert(attributes).unwrap(), step)
 }
 
 #[derive(new, Debug, Serialize, Deserialize)]
 pub enum GenerationStepSerde {
+    ApplyGenerator(ApplyGeneratorStepSerde),
+    AttributeMath(AttributeMathStepSerde),
+    ClassifyBiomes(ClassifyBiomesStepSerde),
     CreateAttribute(CreateAttributeStepSerde),
     Debug(String),
+    DeriveTerrain(DeriveTerrainStepSerde),
     DistortAlongX(Distortion1dStepSerde),
     DistortAlongY(Distortion1dStepSerde),
     Distortion2d(Distortion2dStepSerde),
+    DistanceTransform(DistanceTransformStepSerde),
+    Expression(ExpressionStepSerde),
+    Filter(FilterStepSerde),
     GeneratorAdd(GeneratorStepSerde),
     GeneratorSub(GeneratorStepSerde),
+    ImportAttribute(ImportAttributeStepSerde),
+    LabelComponents(LabelComponentsStepSerde),
+    Masked(MaskedStepSerde),
     ModifyWithAttribute(ModifyWithAttributeStepSerde),
+    Normalise(NormaliseStepSerde),
+    PlateTectonics(PlateTectonicsStepSerde),
+    RainfallSimulation(RainfallSimulationStepSerde),
+    TemperatureModel(TemperatureModelStepSerde),
     TransformAttribute2d(TransformAttribute2dStepSerde),
+    TransformAttributeNd(TransformAttributeNdStepSerde),
 }
 
 type S = GenerationStepSerde;
@@ -52,19 +82,36 @@ type R = GenerationStep;
 impl ToStep<GenerationStep> for GenerationStepSerde {
     fn try_convert(self, attributes: &mut Vec<String>) -> Result<GenerationStep> {
         match self {
+            S::ApplyGenerator(step) => Ok(R::ApplyGenerator(step.try_convert(attributes)?)),
+            S::AttributeMath(step) => Ok(R::AttributeMath(step.try_convert(attributes)?)),
+            S::ClassifyBiomes(step) => Ok(R::ClassifyBiomes(step.try_convert(attributes)?)),
             S::CreateAttribute(step) => Ok(R::CreateAttribute(step.try_convert(attributes)?)),
             S::Debug(text) => Ok(R::Debug(text)),
+            S::DeriveTerrain(step) => Ok(R::DeriveTerrain(step.try_convert(attributes)?)),
             S::DistortAlongX(step) => Ok(R::DistortAlongX(step.try_convert(attributes)?)),
             S::DistortAlongY(step) => Ok(R::DistortAlongY(step.try_convert(attributes)?)),
             S::Distortion2d(step) => Ok(R::Distortion2d(step.try_convert(attributes)?)),
+            S::DistanceTransform(step) => Ok(R::DistanceTransform(step.try_convert(attributes)?)),
+            S::Expression(step) => Ok(R::Expression(step.try_convert(attributes)?)),
+            S::Filter(step) => Ok(R::Filter(step.try_convert(attributes)?)),
             S::GeneratorAdd(step) => Ok(R::GeneratorAdd(step.try_convert(attributes)?)),
             S::GeneratorSub(step) => Ok(R::GeneratorSub(step.try_convert(attributes)?)),
+            S::ImportAttribute(step) => Ok(R::ImportAttribute(step.try_convert(attributes)?)),
+            S::LabelComponents(step) => Ok(R::LabelComponents(step.try_convert(attributes)?)),
+            S::Masked(step) => Ok(R::Masked(step.try_convert(attributes)?)),
             S::ModifyWithAttribute(step) => {
                 Ok(R::ModifyWithAttribute(step.try_convert(attributes)?))
             }
+            S::Normalise(step) => Ok(R::Normalise(step.try_convert(attributes)?)),
+            S::PlateTectonics(step) => Ok(R::PlateTectonics(step.try_convert(attributes)?)),
+            S::RainfallSimulation(step) => Ok(R::RainfallSimulation(step.try_convert(attributes)?)),
+            S::TemperatureModel(step) => Ok(R::TemperatureModel(step.try_convert(attributes)?)),
             S::TransformAttribute2d(step) => {
                 Ok(R::TransformAttribute2d(step.try_convert(attributes)?))
             }
+            S::TransformAttributeNd(step) => {
+                Ok(R::TransformAttributeNd(step.try_convert(attributes)?))
+            }
         }
     }
 }
@@ -72,15 +119,30 @@ impl ToStep<GenerationStep> for GenerationStepSerde {
 impl FromStep<GenerationStepSerde> for GenerationStep {
     fn convert(&self, attributes: &mut Vec<String>) -> GenerationStepSerde {
         match self {
+            R::ApplyGenerator(data) => S::ApplyGenerator(data.convert(attributes)),
+            R::AttributeMath(data) => S::AttributeMath(data.convert(attributes)),
+            R::ClassifyBiomes(data) => S::ClassifyBiomes(data.convert(attributes)),
             R::CreateAttribute(data) => S::CreateAttribute(data.convert(attributes)),
             R::Debug(text) => S::Debug(text.clone()),
+            R::DeriveTerrain(data) => S::DeriveTerrain(data.convert(attributes)),
             R::DistortAlongX(data) => S::DistortAlongX(data.convert(attributes)),
             R::DistortAlongY(data) => S::DistortAlongY(data.convert(attributes)),
             R::Distortion2d(data) => S::Distortion2d(data.convert(attributes)),
+            R::DistanceTransform(data) => S::DistanceTransform(data.convert(attributes)),
+            R::Expression(data) => S::Expression(data.convert(attributes)),
+            R::Filter(data) => S::Filter(data.convert(attributes)),
             R::GeneratorAdd(data) => S::GeneratorAdd(data.convert(attributes)),
             R::GeneratorSub(data) => S::GeneratorSub(data.convert(attributes)),
+            R::ImportAttribute(data) => S::ImportAttribute(data.convert(attributes)),
+            R::LabelComponents(data) => S::LabelComponents(data.convert(attributes)),
+            R::Masked(data) => S::Masked(data.convert(attributes)),
             R::ModifyWithAttribute(data) => S::ModifyWithAttribute(data.convert(attributes)),
+            R::Normalise(data) => S::Normalise(data.convert(attributes)),
+            R::PlateTectonics(data) => S::PlateTectonics(data.convert(attributes)),
+            R::RainfallSimulation(data) => S::RainfallSimulation(data.convert(attributes)),
+            R::TemperatureModel(data) => S::TemperatureModel(data.convert(attributes)),
             R::TransformAttribute2d(data) => S::TransformAttribute2d(data.convert(attributes)),
+            R::TransformAttributeNd(data) => S::TransformAttributeNd(data.convert(attributes)),
         }
     }
 }
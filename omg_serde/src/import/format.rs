@@ -0,0 +1,59 @@
+use crate::data::math::size2d::Size2dSerde;
+use anyhow::Result;
+use omg_core::import::format::ImportFormat;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ImportFormatSerde {
+    Png,
+    RawR16(Size2dSerde),
+    RawR32(Size2dSerde),
+    Csv,
+}
+
+type S = ImportFormatSerde;
+type R = ImportFormat;
+
+impl ImportFormatSerde {
+    pub fn try_convert(&self) -> Result<ImportFormat> {
+        match self {
+            S::Png => Ok(R::Png),
+            S::RawR16(size) => Ok(R::RawR16(size.try_convert()?)),
+            S::RawR32(size) => Ok(R::RawR32(size.try_convert()?)),
+            S::Csv => Ok(R::Csv),
+        }
+    }
+}
+
+impl From<&ImportFormat> for ImportFormatSerde {
+    fn from(format: &ImportFormat) -> Self {
+        match format {
+            R::Png => S::Png,
+            R::RawR16(size) => S::RawR16(size.into()),
+            R::RawR32(size) => S::RawR32(size.into()),
+            R::Csv => S::Csv,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omg_core::data::math::size2d::Size2d;
+
+    #[test]
+    fn test_conversion() {
+        let size = Size2d::unchecked(2, 3);
+
+        assert_eq(R::Png);
+        assert_eq(R::RawR16(size));
+        assert_eq(R::RawR32(size));
+        assert_eq(R::Csv);
+    }
+
+    fn assert_eq(start: ImportFormat) {
+        let serde: ImportFormatSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
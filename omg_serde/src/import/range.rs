@@ -0,0 +1,37 @@
+use anyhow::Result;
+use omg_core::import::range::ValueRange;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct ValueRangeSerde {
+    min: f32,
+    max: f32,
+}
+
+impl ValueRangeSerde {
+    pub fn try_convert(&self) -> Result<ValueRange> {
+        ValueRange::new(self.min, self.max)
+    }
+}
+
+impl From<&ValueRange> for ValueRangeSerde {
+    fn from(range: &ValueRange) -> Self {
+        ValueRangeSerde {
+            min: range.min(),
+            max: range.max(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        let start = ValueRange::new(-100.0, 8000.0).unwrap();
+        let serde: ValueRangeSerde = (&start).into();
+
+        assert_eq!(serde.try_convert().unwrap(), start)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::data::layout::ViewLayoutSerde;
+use anyhow::{Context, Result};
+use omg_core::data::layout::ViewLayout;
+use omg_core::interface::layout::LayoutStorage;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+
+#[derive(new)]
+pub struct LayoutStorageWithSerde;
+
+impl LayoutStorageWithSerde {
+    pub fn inner_read(&self, path: &str) -> Result<Vec<ViewLayout>> {
+        let string = fs::read_to_string(path)?;
+        let data: Vec<ViewLayoutSerde> = serde_yaml::from_str(&string)?;
+        data.into_iter()
+            .map(|layout| layout.try_convert())
+            .collect()
+    }
+
+    pub fn inner_write(&self, layouts: &[ViewLayout], path: &str) -> Result<()> {
+        let mut file = File::create(path)?;
+
+        let data: Vec<ViewLayoutSerde> = layouts.iter().map(|layout| layout.into()).collect();
+        let s = serde_yaml::to_string(&data)?;
+
+        file.write_all(s.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+impl LayoutStorage for LayoutStorageWithSerde {
+    fn read(&self, path: &str) -> Result<Vec<ViewLayout>> {
+        self.inner_read(path)
+            .with_context(|| format!("Failed to read layouts from '{}'", path))
+    }
+
+    fn write(&self, layouts: &[ViewLayout], path: &str) -> Result<()> {
+        self.inner_write(layouts, path)
+            .with_context(|| format!("Failed to write layouts to '{}'", path))
+    }
+}
@@ -2,5 +2,7 @@
 extern crate derive_new;
 
 pub mod data;
+pub mod export;
 pub mod generation;
+pub mod import;
 pub mod interface;
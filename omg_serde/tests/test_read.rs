@@ -1,10 +1,13 @@
 use omg_core::data::color::*;
+use omg_core::data::layout::{LayoutType, ViewLayout};
 use omg_core::data::math::selector::ColorSelector;
 use omg_core::data::math::size2d::Size2d;
 use omg_core::generation::step::GenerationStep;
 use omg_core::generation::MapGenerator;
+use omg_core::interface::layout::LayoutStorage;
 use omg_core::interface::map::MapStorage;
 use omg_core::interface::selector::SelectorStorage;
+use omg_serde::interface::layout::LayoutStorageWithSerde;
 use omg_serde::interface::map::MapStorageWithSerde;
 use omg_serde::interface::selector::SelectorStorageWithSerde;
 
@@ -47,3 +50,22 @@ fn test_color_selector_storage() {
 
     assert_eq!(result, selector);
 }
+
+#[test]
+fn test_layout_storage() {
+    let storage = LayoutStorageWithSerde::new();
+
+    let attributes = vec!["elevation".to_string(), "biome".to_string()];
+    let layouts = vec![
+        ViewLayout::new("grid", LayoutType::Grid { columns: 1 }, attributes.clone()).unwrap(),
+        ViewLayout::new("overlay", LayoutType::Overlay { opacity: 30 }, attributes).unwrap(),
+    ];
+
+    let path = "layouts.yaml";
+
+    storage.write(&layouts, path).unwrap();
+
+    let result = storage.read(path).unwrap();
+
+    assert_eq!(result, layouts);
+}